@@ -0,0 +1,139 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{
+		clone_repo,
+		cred::BasicAuthCredential,
+		remotes::push::{AsyncProgress, ProgressNotification},
+		CloneRequest,
+	},
+	AsyncGitNotification, RemoteProgress,
+};
+use crossbeam_channel::unbounded;
+use std::{
+	path::PathBuf,
+	sync::{Arc, Mutex},
+	thread,
+};
+
+enum JobState {
+	Request(CloneRequest, Option<BasicAuthCredential>),
+	Response(Result<PathBuf>),
+}
+
+///
+#[derive(Clone)]
+pub struct AsyncCloneJob {
+	state: Arc<Mutex<Option<JobState>>>,
+}
+
+impl AsyncCloneJob {
+	///
+	pub fn new(
+		request: CloneRequest,
+		basic_credential: Option<BasicAuthCredential>,
+	) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(Some(JobState::Request(
+				request,
+				basic_credential,
+			)))),
+		}
+	}
+
+	/// path of the new repository once the clone finished
+	pub fn result(&self) -> Option<Result<PathBuf>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(..) => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+
+	fn clone_with_progress(
+		request: &CloneRequest,
+		basic_credential: Option<&BasicAuthCredential>,
+		params: &RunParams<
+			AsyncGitNotification,
+			Option<RemoteProgress>,
+		>,
+	) -> Result<()> {
+		let (progress_sender, receiver) =
+			unbounded::<ProgressNotification>();
+
+		thread::scope(|scope| {
+			scope.spawn(|| {
+				for progress in &receiver {
+					if progress.is_done() {
+						break;
+					}
+
+					if let Err(e) = Self::report(params, progress) {
+						log::error!("clone progress error: {e}");
+					}
+				}
+			});
+
+			let result = clone_repo(
+				request,
+				&basic_credential.cloned(),
+				&Some(progress_sender.clone()),
+			);
+
+			progress_sender.send(ProgressNotification::Done)?;
+
+			result
+		})
+	}
+
+	fn report(
+		params: &RunParams<
+			AsyncGitNotification,
+			Option<RemoteProgress>,
+		>,
+		progress: ProgressNotification,
+	) -> Result<()> {
+		if params.set_progress(Some(progress.into()))? {
+			params.send(AsyncGitNotification::Clone)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl AsyncJob for AsyncCloneJob {
+	type Notification = AsyncGitNotification;
+	type Progress = Option<RemoteProgress>;
+
+	fn run(
+		&mut self,
+		params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(request, basic_credential) => {
+					let result = Self::clone_with_progress(
+						&request,
+						basic_credential.as_ref(),
+						&params,
+					)
+					.map(|()| request.path);
+
+					JobState::Response(result)
+				}
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::Clone)
+	}
+}
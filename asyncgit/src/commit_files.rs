@@ -1,6 +1,9 @@
 use crate::{
 	error::Result,
-	sync::{self, commit_files::OldNew, CommitId, RepoPath},
+	sync::{
+		self, commit_files::OldNew, diff::DiffOptions, CommitId,
+		RepoPath,
+	},
 	AsyncGitNotification, StatusItem,
 };
 use crossbeam_channel::Sender;
@@ -19,11 +22,28 @@ pub struct CommitFilesParams {
 	pub id: CommitId,
 	///
 	pub other: Option<CommitId>,
+	/// rename detection used to list the files
+	pub options: Option<DiffOptions>,
+}
+
+impl CommitFilesParams {
+	///
+	#[must_use]
+	pub const fn with_options(self, options: DiffOptions) -> Self {
+		Self {
+			options: Some(options),
+			..self
+		}
+	}
 }
 
 impl From<CommitId> for CommitFilesParams {
 	fn from(id: CommitId) -> Self {
-		Self { id, other: None }
+		Self {
+			id,
+			other: None,
+			options: None,
+		}
 	}
 }
 
@@ -32,6 +52,7 @@ impl From<(CommitId, CommitId)> for CommitFilesParams {
 		Self {
 			id,
 			other: Some(other),
+			options: None,
 		}
 	}
 }
@@ -41,6 +62,7 @@ impl From<OldNew<CommitId>> for CommitFilesParams {
 		Self {
 			id: old_new.new,
 			other: Some(old_new.old),
+			options: None,
 		}
 	}
 }
@@ -132,6 +154,7 @@ impl AsyncCommitFiles {
 			repo_path,
 			params.id,
 			params.other,
+			params.options,
 		)?;
 
 		log::trace!("get_commit_files: {:?} ({})", params, res.len());
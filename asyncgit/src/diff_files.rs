@@ -0,0 +1,90 @@
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{self, diff::DiffOptions, CommitId, RepoPath},
+	AsyncGitNotification, FileDiff,
+};
+use std::sync::{Arc, Mutex};
+
+/// commit whose files to diff, see `sync::diff::get_diff_files`
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub struct DiffFilesParams {
+	///
+	pub id: CommitId,
+	/// diff against this commit instead of the first parent
+	pub compare: Option<CommitId>,
+	///
+	pub options: DiffOptions,
+}
+
+enum JobState {
+	Request(RepoPath),
+	Response(Result<Vec<(String, FileDiff)>>),
+}
+
+/// diffs every file of a commit or a comparison of two commits
+#[derive(Clone)]
+pub struct AsyncDiffFilesJob {
+	params: DiffFilesParams,
+	state: Arc<Mutex<Option<JobState>>>,
+}
+
+///
+impl AsyncDiffFilesJob {
+	///
+	pub fn new(repo: RepoPath, params: DiffFilesParams) -> Self {
+		Self {
+			params,
+			state: Arc::new(Mutex::new(Some(JobState::Request(
+				repo,
+			)))),
+		}
+	}
+
+	///
+	pub const fn params(&self) -> DiffFilesParams {
+		self.params
+	}
+
+	///
+	pub fn result(&self) -> Option<Result<Vec<(String, FileDiff)>>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(_) => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncDiffFilesJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(repo) => {
+					JobState::Response(sync::diff::get_diff_files(
+						&repo,
+						self.params.id,
+						self.params.compare,
+						Some(self.params.options),
+					))
+				}
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::DiffFiles)
+	}
+}
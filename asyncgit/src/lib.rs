@@ -47,8 +47,10 @@ pub mod asyncjob;
 mod blame;
 mod branches;
 pub mod cached;
+mod clone;
 mod commit_files;
 mod diff;
+mod diff_files;
 mod error;
 mod fetch_job;
 mod filter_commits;
@@ -59,6 +61,7 @@ mod push_tags;
 pub mod remote_progress;
 pub mod remote_tags;
 mod revlog;
+mod signatures;
 mod status;
 pub mod sync;
 mod tags;
@@ -67,8 +70,10 @@ mod treefiles;
 pub use crate::{
 	blame::{AsyncBlame, BlameParams},
 	branches::AsyncBranchesJob,
+	clone::AsyncCloneJob,
 	commit_files::{AsyncCommitFiles, CommitFilesParams},
 	diff::{AsyncDiff, DiffParams, DiffType},
+	diff_files::{AsyncDiffFilesJob, DiffFilesParams},
 	error::{Error, Result},
 	fetch_job::AsyncFetchJob,
 	filter_commits::{AsyncCommitFilterJob, CommitFilterResult},
@@ -78,6 +83,7 @@ pub use crate::{
 	push_tags::{AsyncPushTags, PushTagsRequest},
 	remote_progress::{RemoteProgress, RemoteProgressState},
 	revlog::{AsyncLog, FetchStatus},
+	signatures::AsyncSignaturesJob,
 	status::{AsyncStatus, StatusParams},
 	sync::{
 		diff::{DiffLine, DiffLineType, FileDiff},
@@ -128,6 +134,12 @@ pub enum AsyncGitNotification {
 	TreeFiles,
 	///
 	CommitFilter,
+	///
+	Signatures,
+	///
+	DiffFiles,
+	///
+	Clone,
 }
 
 /// helper function to calculate the hash of an arbitrary type that implements the `Hash` trait
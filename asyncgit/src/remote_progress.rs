@@ -13,8 +13,8 @@ use std::{
 	thread::{self, JoinHandle},
 };
 
-/// used for push/pull
-#[derive(Clone, Debug)]
+/// used for push/pull/clone
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RemoteProgressState {
 	///
 	PackingAddingObject,
@@ -29,7 +29,7 @@ pub enum RemoteProgressState {
 }
 
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RemoteProgress {
 	///
 	pub state: RemoteProgressState,
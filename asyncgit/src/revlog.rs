@@ -1,7 +1,8 @@
 use crate::{
 	error::Result,
 	sync::{
-		gix_repo, repo, CommitId, LogWalker, LogWalkerWithoutFilter,
+		gix_repo, log_tips, repo, CommitId, GraphRow, LogTips,
+		LogWalker, LogWalkerOptions, LogWalkerWithoutFilter,
 		RepoPath, SharedCommitFilterFn,
 	},
 	AsyncGitNotification, Error,
@@ -32,19 +33,23 @@ pub enum FetchStatus {
 pub struct AsyncLogResult {
 	///
 	pub commits: Vec<CommitId>,
+	/// graph lanes, one row per entry in `commits` (empty when filtering)
+	pub graph: Vec<GraphRow>,
 	///
 	pub duration: Duration,
 }
 ///
 pub struct AsyncLog {
 	current: Arc<Mutex<AsyncLogResult>>,
-	current_head: Arc<Mutex<Option<CommitId>>>,
+	current_tips: Arc<Mutex<Option<(LogTips, LogWalkerOptions)>>>,
 	sender: Sender<AsyncGitNotification>,
 	pending: Arc<AtomicBool>,
 	background: Arc<AtomicBool>,
 	filter: Option<SharedCommitFilterFn>,
 	partial_extract: AtomicBool,
 	repo: RepoPath,
+	revisions: Option<String>,
+	options: LogWalkerOptions,
 }
 
 static LIMIT_COUNT: usize = 3000;
@@ -62,17 +67,41 @@ impl AsyncLog {
 			repo,
 			current: Arc::new(Mutex::new(AsyncLogResult {
 				commits: Vec::new(),
+				graph: Vec::new(),
 				duration: Duration::default(),
 			})),
-			current_head: Arc::new(Mutex::new(None)),
+			current_tips: Arc::new(Mutex::new(None)),
 			sender: sender.clone(),
 			pending: Arc::new(AtomicBool::new(false)),
 			background: Arc::new(AtomicBool::new(false)),
 			filter,
 			partial_extract: AtomicBool::new(false),
+			revisions: None,
+			options: LogWalkerOptions::default(),
 		}
 	}
 
+	/// walk these revisions instead of `HEAD`, see [`log_tips`].
+	/// takes effect with the next `fetch`.
+	pub fn set_revisions(&mut self, revisions: Option<String>) {
+		self.revisions = revisions;
+	}
+
+	///
+	pub fn revisions(&self) -> Option<&str> {
+		self.revisions.as_deref()
+	}
+
+	/// takes effect with the next `fetch`
+	pub const fn set_options(&mut self, options: LogWalkerOptions) {
+		self.options = options;
+	}
+
+	///
+	pub const fn options(&self) -> LogWalkerOptions {
+		self.options
+	}
+
 	///
 	pub fn count(&self) -> Result<usize> {
 		Ok(self.current.lock()?.commits.len())
@@ -115,6 +144,12 @@ impl AsyncLog {
 		Ok(result)
 	}
 
+	/// graph rows matching the items handed out by `extract_items`.
+	/// only available when walking without a filter.
+	pub fn extract_graph(&self) -> Result<Vec<GraphRow>> {
+		Ok(std::mem::take(&mut self.current.lock()?.graph))
+	}
+
 	///
 	pub fn get_last_duration(&self) -> Result<Duration> {
 		Ok(self.current.lock()?.duration)
@@ -131,18 +166,17 @@ impl AsyncLog {
 	}
 
 	///
-	fn current_head(&self) -> Result<Option<CommitId>> {
-		Ok(*self.current_head.lock()?)
+	fn tips(&self) -> Result<LogTips> {
+		log_tips(
+			&self.repo,
+			self.revisions.as_deref().unwrap_or_default(),
+		)
 	}
 
 	///
-	fn head_changed(&self) -> Result<bool> {
-		if let Ok(head) = repo(&self.repo)?.head() {
-			return Ok(
-				head.target() != self.current_head()?.map(Into::into)
-			);
-		}
-		Ok(false)
+	fn tips_changed(&self, tips: &LogTips) -> Result<bool> {
+		Ok(self.current_tips.lock()?.as_ref()
+			!= Some(&(tips.clone(), self.options)))
 	}
 
 	///
@@ -153,7 +187,12 @@ impl AsyncLog {
 			return Ok(FetchStatus::Pending);
 		}
 
-		if !self.head_changed()? {
+		// an unborn `HEAD` or a revision that went away
+		let Ok(tips) = self.tips() else {
+			return Ok(FetchStatus::NoChange);
+		};
+
+		if !self.tips_changed(&tips)? {
 			return Ok(FetchStatus::NoChange);
 		}
 
@@ -167,17 +206,17 @@ impl AsyncLog {
 		let arc_background = Arc::clone(&self.background);
 		let filter = self.filter.clone();
 		let repo_path = self.repo.clone();
+		let options = self.options;
 
-		if let Ok(head) = repo(&self.repo)?.head() {
-			*self.current_head.lock()? =
-				head.target().map(CommitId::new);
-		}
+		*self.current_tips.lock()? = Some((tips.clone(), options));
 
 		rayon_core::spawn(move || {
 			scope_time!("async::revlog");
 
 			Self::fetch_helper(
 				&repo_path,
+				&tips,
+				options,
 				&arc_current,
 				&arc_background,
 				&sender,
@@ -195,6 +234,8 @@ impl AsyncLog {
 
 	fn fetch_helper(
 		repo_path: &RepoPath,
+		tips: &LogTips,
+		options: LogWalkerOptions,
 		arc_current: &Arc<Mutex<AsyncLogResult>>,
 		arc_background: &Arc<AtomicBool>,
 		sender: &Sender<AsyncGitNotification>,
@@ -204,6 +245,8 @@ impl AsyncLog {
 			|| {
 				Self::fetch_helper_without_filter(
 					repo_path,
+					tips,
+					options,
 					arc_current,
 					arc_background,
 					sender,
@@ -212,6 +255,8 @@ impl AsyncLog {
 			|filter| {
 				Self::fetch_helper_with_filter(
 					repo_path,
+					tips,
+					options,
 					arc_current,
 					arc_background,
 					sender,
@@ -223,6 +268,8 @@ impl AsyncLog {
 
 	fn fetch_helper_with_filter(
 		repo_path: &RepoPath,
+		tips: &LogTips,
+		options: LogWalkerOptions,
 		arc_current: &Arc<Mutex<AsyncLogResult>>,
 		arc_background: &Arc<AtomicBool>,
 		sender: &Sender<AsyncGitNotification>,
@@ -235,7 +282,8 @@ impl AsyncLog {
 
 		let r = repo(repo_path)?;
 		let mut walker =
-			LogWalker::new(&r, LIMIT_COUNT)?.filter(Some(filter));
+			LogWalker::with_tips(&r, LIMIT_COUNT, tips, options)?
+				.filter(Some(filter));
 
 		loop {
 			entries.clear();
@@ -267,6 +315,8 @@ impl AsyncLog {
 
 	fn fetch_helper_without_filter(
 		repo_path: &RepoPath,
+		tips: &LogTips,
+		options: LogWalkerOptions,
 		arc_current: &Arc<Mutex<AsyncLogResult>>,
 		arc_background: &Arc<AtomicBool>,
 		sender: &Sender<AsyncGitNotification>,
@@ -276,16 +326,25 @@ impl AsyncLog {
 		let mut entries = vec![CommitId::default(); LIMIT_COUNT];
 		entries.resize(0, CommitId::default());
 
+		let mut graph = Vec::with_capacity(LIMIT_COUNT);
+
 		let mut repo: gix::Repository = gix_repo(repo_path)?;
-		let mut walker =
-			LogWalkerWithoutFilter::new(&mut repo, LIMIT_COUNT)?;
+		let mut walker = LogWalkerWithoutFilter::with_tips(
+			&mut repo,
+			LIMIT_COUNT,
+			tips,
+			options,
+		)?
+		.with_graph();
 
 		loop {
 			entries.clear();
-			let read = walker.read(&mut entries)?;
+			let read =
+				walker.read_with_graph(&mut entries, &mut graph)?;
 
 			let mut current = arc_current.lock()?;
 			current.commits.extend(entries.iter());
+			current.graph.append(&mut graph);
 			current.duration = start_time.elapsed();
 
 			if read == 0 {
@@ -309,8 +368,12 @@ impl AsyncLog {
 	}
 
 	fn clear(&self) -> Result<()> {
-		self.current.lock()?.commits.clear();
-		*self.current_head.lock()? = None;
+		{
+			let mut current = self.current.lock()?;
+			current.commits.clear();
+			current.graph.clear();
+		}
+		*self.current_tips.lock()? = None;
 		self.partial_extract.store(false, Ordering::Relaxed);
 		Ok(())
 	}
@@ -333,11 +396,23 @@ mod tests {
 	use tempfile::TempDir;
 
 	use crate::sync::tests::{debug_cmd_print, repo_init};
-	use crate::sync::RepoPath;
+	use crate::sync::{
+		CommitId, LogTips, LogWalkerOptions, RepoPath,
+	};
 	use crate::AsyncLog;
 
 	use super::AsyncLogResult;
 
+	fn head_tips(repo: &git2::Repository) -> LogTips {
+		let head: CommitId =
+			repo.head().unwrap().target().unwrap().into();
+
+		LogTips {
+			include: vec![head],
+			exclude: Vec::new(),
+		}
+	}
+
 	#[test]
 	#[serial]
 	fn test_smoke_in_subdir() {
@@ -356,12 +431,15 @@ mod tests {
 
 		let arc_current = Arc::new(Mutex::new(AsyncLogResult {
 			commits: Vec::new(),
+			graph: Vec::new(),
 			duration: Duration::default(),
 		}));
 		let arc_background = Arc::new(AtomicBool::new(false));
 
 		let result = AsyncLog::fetch_helper_without_filter(
 			&subdir_path,
+			&head_tips(&repo),
+			LogWalkerOptions::default(),
 			&arc_current,
 			&arc_background,
 			&tx_git,
@@ -384,6 +462,7 @@ mod tests {
 
 		let arc_current = Arc::new(Mutex::new(AsyncLogResult {
 			commits: Vec::new(),
+			graph: Vec::new(),
 			duration: Duration::default(),
 		}));
 		let arc_background = Arc::new(AtomicBool::new(false));
@@ -393,6 +472,8 @@ mod tests {
 		let result = AsyncLog::fetch_helper_without_filter(
 			// We pass an empty path, thus testing whether `GIT_DIR`, set above, is taken into account.
 			&empty_path,
+			&head_tips(&repo),
+			LogWalkerOptions::default(),
 			&arc_current,
 			&arc_background,
 			&tx_git,
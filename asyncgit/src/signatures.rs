@@ -0,0 +1,88 @@
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{
+		verify_commit_signature, CommitId, RepoPath, SignatureStatus,
+	},
+	AsyncGitNotification,
+};
+use std::sync::{Arc, Mutex};
+
+enum JobState {
+	Request {
+		commits: Vec<CommitId>,
+		repo: RepoPath,
+	},
+	Response(Vec<(CommitId, SignatureStatus)>),
+}
+
+/// verifies the signatures of a batch of commits
+#[derive(Clone, Default)]
+pub struct AsyncSignaturesJob {
+	state: Arc<Mutex<Option<JobState>>>,
+}
+
+///
+impl AsyncSignaturesJob {
+	///
+	pub fn new(repo: RepoPath, commits: Vec<CommitId>) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(Some(JobState::Request {
+				commits,
+				repo,
+			}))),
+		}
+	}
+
+	///
+	pub fn result(&self) -> Option<Vec<(CommitId, SignatureStatus)>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request { .. } => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncSignaturesJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request { commits, repo } => {
+					let signatures = commits
+						.into_iter()
+						.map(|id| {
+							let status =
+								verify_commit_signature(&repo, id)
+									.unwrap_or_else(|e| {
+										SignatureStatus::Unknown(
+											e.to_string(),
+										)
+									});
+
+							(id, status)
+						})
+						.collect();
+
+					JobState::Response(signatures)
+				}
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::Signatures)
+	}
+}
@@ -0,0 +1,379 @@
+//! summaries of binary files and git lfs pointers, which are not
+//! diffed line by line
+
+/// pointer files are at most this large, see
+/// <https://github.com/git-lfs/git-lfs/blob/main/docs/spec.md>
+pub const LFS_POINTER_MAX_SIZE: u64 = 1024;
+
+const LFS_VERSIONS: [&str; 2] = [
+	"version https://git-lfs.github.com/spec/v1",
+	"version https://hawser.github.com/spec/v1",
+];
+
+/// bytes per row of a `HexDiff`
+pub const HEX_ROW_LEN: usize = 16;
+const HEX_ROWS: usize = 4;
+
+/// what one side of a binary diff contains
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub enum BlobSummary {
+	/// git lfs pointer to content stored outside of the repository
+	LfsPointer {
+		/// `sha256:` prefixed hash of the content
+		oid: String,
+		/// size of the content in bytes
+		size: u64,
+	},
+	///
+	Image {
+		///
+		format: &'static str,
+		///
+		width: u32,
+		///
+		height: u32,
+	},
+	/// any other binary content
+	Binary,
+}
+
+/// bytes around the first difference of two binary contents
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub struct HexDiff {
+	/// offset of the first byte that differs
+	pub first_change: usize,
+	/// offset of the first byte in `old` and `new`, a multiple of
+	/// `HEX_ROW_LEN`
+	pub offset: usize,
+	///
+	pub old: Vec<u8>,
+	///
+	pub new: Vec<u8>,
+}
+
+/// summary of a binary diff, a side is `None` if the file was added
+/// or deleted
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub struct BinaryDiff {
+	///
+	pub old: Option<BlobSummary>,
+	///
+	pub new: Option<BlobSummary>,
+	/// set unless one side is an image or lfs pointer
+	pub hex: Option<HexDiff>,
+}
+
+impl BinaryDiff {
+	/// summarizes the `old` and `new` content of a file, returns
+	/// `None` for text files unless they are lfs pointers
+	pub fn new(
+		old: Option<&[u8]>,
+		new: Option<&[u8]>,
+		is_text: bool,
+	) -> Option<Self> {
+		let lfs = |data: Option<&[u8]>| data.and_then(lfs_pointer);
+		let (old_lfs, new_lfs) = (lfs(old), lfs(new));
+
+		if old_lfs.is_some() || new_lfs.is_some() {
+			return Some(Self {
+				old: old_lfs.or_else(|| old.map(summarize)),
+				new: new_lfs.or_else(|| new.map(summarize)),
+				hex: None,
+			});
+		}
+
+		if is_text {
+			return None;
+		}
+
+		let old_summary = old.map(summarize);
+		let new_summary = new.map(summarize);
+
+		let is_binary = |summary: &Option<BlobSummary>| {
+			summary
+				.as_ref()
+				.is_none_or(|summary| *summary == BlobSummary::Binary)
+		};
+		let hex =
+			if is_binary(&old_summary) && is_binary(&new_summary) {
+				HexDiff::new(
+					old.unwrap_or_default(),
+					new.unwrap_or_default(),
+				)
+			} else {
+				None
+			};
+
+		Some(Self {
+			old: old_summary,
+			new: new_summary,
+			hex,
+		})
+	}
+}
+
+impl HexDiff {
+	/// `None` if both are equal
+	fn new(old: &[u8], new: &[u8]) -> Option<Self> {
+		let first_change = old
+			.iter()
+			.zip(new)
+			.position(|(old, new)| old != new)
+			.or_else(|| {
+				(old.len() != new.len())
+					.then(|| old.len().min(new.len()))
+			})?;
+
+		let offset = first_change - first_change % HEX_ROW_LEN;
+		let window = |data: &[u8]| {
+			data.iter()
+				.skip(offset)
+				.take(HEX_ROWS * HEX_ROW_LEN)
+				.copied()
+				.collect()
+		};
+
+		Some(Self {
+			first_change,
+			offset,
+			old: window(old),
+			new: window(new),
+		})
+	}
+}
+
+fn summarize(data: &[u8]) -> BlobSummary {
+	image_dimensions(data).map_or(
+		BlobSummary::Binary,
+		|(format, width, height)| BlobSummary::Image {
+			format,
+			width,
+			height,
+		},
+	)
+}
+
+/// parses a git lfs pointer file
+fn lfs_pointer(data: &[u8]) -> Option<BlobSummary> {
+	if data.len() as u64 > LFS_POINTER_MAX_SIZE {
+		return None;
+	}
+
+	let text = std::str::from_utf8(data).ok()?;
+	let mut lines = text.lines();
+
+	if !LFS_VERSIONS.contains(&lines.next()?) {
+		return None;
+	}
+
+	let mut oid = None;
+	let mut size = None;
+	for line in lines {
+		if let Some(value) = line.strip_prefix("oid ") {
+			oid = Some(value.to_string());
+		} else if let Some(value) = line.strip_prefix("size ") {
+			size = value.parse().ok();
+		}
+	}
+
+	Some(BlobSummary::LfsPointer {
+		oid: oid?,
+		size: size?,
+	})
+}
+
+fn u16_be(data: &[u8], at: usize) -> Option<u32> {
+	Some(u32::from(u16::from_be_bytes(
+		data.get(at..at + 2)?.try_into().ok()?,
+	)))
+}
+
+fn u16_le(data: &[u8], at: usize) -> Option<u32> {
+	Some(u32::from(u16::from_le_bytes(
+		data.get(at..at + 2)?.try_into().ok()?,
+	)))
+}
+
+fn u24_le(data: &[u8], at: usize) -> Option<u32> {
+	let bytes = data.get(at..at + 3)?;
+	Some(
+		u32::from(bytes[0])
+			| u32::from(bytes[1]) << 8
+			| u32::from(bytes[2]) << 16,
+	)
+}
+
+fn u32_be(data: &[u8], at: usize) -> Option<u32> {
+	Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn u32_le(data: &[u8], at: usize) -> Option<u32> {
+	Some(u32::from_le_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// format, width and height of png, gif, jpeg, bmp and webp images
+/// read from their headers
+fn image_dimensions(data: &[u8]) -> Option<(&'static str, u32, u32)> {
+	if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+		Some(("PNG", u32_be(data, 16)?, u32_be(data, 20)?))
+	} else if data.starts_with(b"GIF87a")
+		|| data.starts_with(b"GIF89a")
+	{
+		Some(("GIF", u16_le(data, 6)?, u16_le(data, 8)?))
+	} else if data.starts_with(b"BM") {
+		// rows are stored top-down if the height is negative
+		let height =
+			i32::from_le_bytes(u32_le(data, 22)?.to_le_bytes());
+		Some(("BMP", u32_le(data, 18)?, height.unsigned_abs()))
+	} else if data.starts_with(b"\xff\xd8") {
+		jpeg_dimensions(data)
+	} else if data.starts_with(b"RIFF")
+		&& data.get(8..12) == Some(b"WEBP")
+	{
+		webp_dimensions(data)
+	} else {
+		None
+	}
+}
+
+fn jpeg_dimensions(data: &[u8]) -> Option<(&'static str, u32, u32)> {
+	let mut at = 2;
+
+	loop {
+		if *data.get(at)? != 0xff {
+			return None;
+		}
+		let marker = *data.get(at + 1)?;
+
+		match marker {
+			// fill byte
+			0xff => at += 1,
+			// markers without a length
+			0x01 | 0xd0..=0xd7 => at += 2,
+			// start of frame, except huffman and arithmetic tables
+			0xc0..=0xcf if !matches!(marker, 0xc4 | 0xc8 | 0xcc) => {
+				return Some((
+					"JPEG",
+					u16_be(data, at + 7)?,
+					u16_be(data, at + 5)?,
+				));
+			}
+			_ => {
+				at += 2 + usize::try_from(u16_be(data, at + 2)?)
+					.ok()?;
+			}
+		}
+	}
+}
+
+fn webp_dimensions(data: &[u8]) -> Option<(&'static str, u32, u32)> {
+	let (width, height) = match data.get(12..16)? {
+		b"VP8 " => {
+			(u16_le(data, 26)? & 0x3fff, u16_le(data, 28)? & 0x3fff)
+		}
+		b"VP8L" => {
+			let bits = u32_le(data, 21)?;
+			((bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1)
+		}
+		b"VP8X" => (u24_le(data, 24)? + 1, u24_le(data, 27)? + 1),
+		_ => return None,
+	};
+
+	Some(("WebP", width, height))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	const LFS_POINTER: &str = "version https://git-lfs.github.com/spec/v1
+oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393
+size 12345
+";
+
+	fn png(width: u32, height: u32) -> Vec<u8> {
+		let mut data = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+		data.extend(width.to_be_bytes());
+		data.extend(height.to_be_bytes());
+		data.extend([8, 6, 0, 0, 0]);
+		data
+	}
+
+	#[test]
+	fn test_lfs_pointer() {
+		assert_eq!(
+			lfs_pointer(LFS_POINTER.as_bytes()),
+			Some(BlobSummary::LfsPointer {
+				oid: String::from("sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393"),
+				size: 12345,
+			})
+		);
+		assert_eq!(lfs_pointer(b"version 1\nsize 1\n"), None);
+
+		let diff =
+			BinaryDiff::new(None, Some(LFS_POINTER.as_bytes()), true)
+				.unwrap();
+		assert_eq!(diff.old, None);
+		assert!(matches!(
+			diff.new,
+			Some(BlobSummary::LfsPointer { size: 12345, .. })
+		));
+		assert_eq!(BinaryDiff::new(None, Some(b"a\n"), true), None);
+	}
+
+	#[test]
+	fn test_image_dimensions() {
+		assert_eq!(
+			image_dimensions(&png(640, 480)),
+			Some(("PNG", 640, 480))
+		);
+		assert_eq!(
+			image_dimensions(b"GIF89a\x20\x00\x10\x00"),
+			Some(("GIF", 32, 16))
+		);
+
+		let mut jpeg = b"\xff\xd8\xff\xe0\x00\x04ab".to_vec();
+		jpeg.extend(b"\xff\xc0\x00\x11\x08\x01\x00\x02\x00");
+		assert_eq!(image_dimensions(&jpeg), Some(("JPEG", 512, 256)));
+
+		assert_eq!(image_dimensions(b"\x00\x01"), None);
+
+		let diff = BinaryDiff::new(
+			Some(&png(1, 2)),
+			Some(&png(3, 4)),
+			false,
+		)
+		.unwrap();
+		assert_eq!(
+			diff.new,
+			Some(BlobSummary::Image {
+				format: "PNG",
+				width: 3,
+				height: 4
+			})
+		);
+		assert_eq!(diff.hex, None);
+	}
+
+	#[test]
+	fn test_hex_diff() {
+		let old: Vec<u8> = (0..40).collect();
+		let mut new = old.clone();
+		new[20] = 0xff;
+		new.push(0);
+
+		let diff = BinaryDiff::new(Some(&old), Some(&new), false)
+			.unwrap()
+			.hex
+			.unwrap();
+
+		assert_eq!(diff.first_change, 20);
+		assert_eq!(diff.offset, 16);
+		assert_eq!(diff.old, old[16..]);
+		assert_eq!(diff.new, new[16..]);
+
+		assert_eq!(HexDiff::new(&old, &old), None);
+		assert_eq!(HexDiff::new(&old[..2], &old).unwrap().offset, 0);
+	}
+}
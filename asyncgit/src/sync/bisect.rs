@@ -0,0 +1,565 @@
+//! bisecting to find the commit that introduced a bug
+//!
+//! the state is kept the same way git does it: `BISECT_START` holds
+//! what `HEAD` pointed to before, `BISECT_LOG` makes the repository
+//! report `RepoState::Bisect` and the marked commits are stored as
+//! `refs/bisect/bad`, `refs/bisect/good-<id>` and
+//! `refs/bisect/skip-<id>`.
+
+use super::{repository::repo, utils::read_file, CommitId, RepoPath};
+use crate::error::{Error, Result};
+use git2::{
+	build::CheckoutBuilder, BranchType, Oid, Repository,
+	RepositoryState, Sort,
+};
+use scopetime::scope_time;
+use std::{
+	collections::{HashMap, HashSet},
+	fs::{self, OpenOptions},
+	io::{self, Write},
+};
+
+const BISECT_START_FILE: &str = "BISECT_START";
+const BISECT_LOG_FILE: &str = "BISECT_LOG";
+const BISECT_REFS: &str = "refs/bisect/";
+const BISECT_BAD_REF: &str = "refs/bisect/bad";
+
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BisectMark {
+	/// the bug is not present
+	Good,
+	/// the bug is present
+	Bad,
+	/// the commit can not be tested
+	Skip,
+}
+
+impl BisectMark {
+	const fn as_str(self) -> &'static str {
+		match self {
+			Self::Good => "good",
+			Self::Bad => "bad",
+			Self::Skip => "skip",
+		}
+	}
+}
+
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BisectState {
+	/// `commit` is checked out and needs to be marked next
+	Testing {
+		/// commit to test
+		commit: CommitId,
+		/// commits left that could be the first bad one
+		remaining: usize,
+		/// rough number of steps left after this one
+		steps: usize,
+	},
+	/// first bad commit
+	Found(CommitId),
+	/// only skipped commits are left, the first bad commit is one
+	/// of them
+	OnlySkipped(Vec<CommitId>),
+}
+
+struct Terms {
+	bad: Oid,
+	good: Vec<Oid>,
+	skip: HashSet<Oid>,
+}
+
+/// starts bisecting between the commit known to be `bad` and one
+/// known to be `good`, `good` has to be an ancestor of `bad`
+pub fn bisect_start(
+	repo_path: &RepoPath,
+	bad: CommitId,
+	good: CommitId,
+) -> Result<BisectState> {
+	scope_time!("bisect_start");
+
+	let repo = repo(repo_path)?;
+
+	if repo.state() != RepositoryState::Clean {
+		return Err(Error::Generic(
+			"bisect needs a clean repository state".to_string(),
+		));
+	}
+
+	if !repo.graph_descendant_of(bad.into(), good.into())? {
+		return Err(Error::Generic(
+			"the good commit has to be an ancestor of the bad commit"
+				.to_string(),
+		));
+	}
+
+	let head = repo.head()?;
+	let start = if head.is_branch() {
+		head.shorthand().unwrap_or_default().to_string()
+	} else {
+		head.peel_to_commit()?.id().to_string()
+	};
+
+	fs::write(repo.path().join(BISECT_START_FILE), start + "\n")?;
+	append_log(&repo, "git bisect start\n")?;
+
+	mark_commit(&repo, bad.into(), BisectMark::Bad)?;
+	mark_commit(&repo, good.into(), BisectMark::Good)?;
+
+	checkout_next(&repo)
+}
+
+/// marks the checked out commit and checks out the next one to test
+pub fn bisect_mark(
+	repo_path: &RepoPath,
+	mark: BisectMark,
+) -> Result<BisectState> {
+	scope_time!("bisect_mark");
+
+	let repo = repo(repo_path)?;
+
+	let head = repo.head()?.peel_to_commit()?.id();
+
+	mark_commit(&repo, head, mark)?;
+
+	checkout_next(&repo)
+}
+
+/// current progress of the bisect, does not touch the workdir
+pub fn bisect_state(repo_path: &RepoPath) -> Result<BisectState> {
+	scope_time!("bisect_state");
+
+	let repo = repo(repo_path)?;
+
+	next_state(&repo, &read_terms(&repo)?)
+}
+
+/// commits that can still be the first bad one, newest first
+pub fn bisect_range(repo_path: &RepoPath) -> Result<Vec<CommitId>> {
+	scope_time!("bisect_range");
+
+	let repo = repo(repo_path)?;
+
+	Ok(candidates(&repo, &read_terms(&repo)?)?
+		.into_iter()
+		.map(CommitId::new)
+		.collect())
+}
+
+/// the marked commits while bisecting, `None` otherwise
+///
+/// cheap compared to [`bisect_range`], which only changes when these
+/// do
+pub fn bisect_refs(
+	repo_path: &RepoPath,
+) -> Result<Option<Vec<(String, CommitId)>>> {
+	scope_time!("bisect_refs");
+
+	let repo = repo(repo_path)?;
+
+	if repo.state() != RepositoryState::Bisect {
+		return Ok(None);
+	}
+
+	let mut refs = Vec::new();
+	for reference in
+		repo.references_glob(&format!("{BISECT_REFS}*"))?
+	{
+		let reference = reference?;
+		if let (Ok(name), Some(id)) =
+			(reference.name(), reference.target())
+		{
+			refs.push((name.to_string(), CommitId::new(id)));
+		}
+	}
+	refs.sort();
+
+	Ok(Some(refs))
+}
+
+/// ends bisecting and checks out what `HEAD` was before
+pub fn bisect_reset(repo_path: &RepoPath) -> Result<()> {
+	scope_time!("bisect_reset");
+
+	let repo = repo(repo_path)?;
+
+	if let Ok(start) = read_file(&repo.path().join(BISECT_START_FILE))
+	{
+		let start = start.trim();
+
+		if let Ok(branch) = repo.find_branch(start, BranchType::Local)
+		{
+			let branch_ref = branch.into_reference();
+			let commit = branch_ref.peel_to_commit()?;
+
+			repo.checkout_tree(
+				commit.as_object(),
+				Some(CheckoutBuilder::new().safe()),
+			)?;
+			repo.set_head(branch_ref.name().unwrap_or_default())?;
+		} else {
+			checkout_detached(&repo, Oid::from_str(start)?)?;
+		}
+	}
+
+	for reference in
+		repo.references_glob(&format!("{BISECT_REFS}*"))?
+	{
+		reference?.delete()?;
+	}
+
+	for file in [BISECT_START_FILE, BISECT_LOG_FILE] {
+		match fs::remove_file(repo.path().join(file)) {
+			Err(e) if e.kind() != io::ErrorKind::NotFound => {
+				return Err(e.into())
+			}
+			_ => (),
+		}
+	}
+
+	Ok(())
+}
+
+fn mark_commit(
+	repo: &Repository,
+	id: Oid,
+	mark: BisectMark,
+) -> Result<()> {
+	let name = match mark {
+		BisectMark::Bad => BISECT_BAD_REF.to_string(),
+		BisectMark::Good | BisectMark::Skip => {
+			format!("{BISECT_REFS}{}-{id}", mark.as_str())
+		}
+	};
+
+	let log_msg = format!("bisect {}", mark.as_str());
+	repo.reference(&name, id, true, &log_msg)?;
+
+	let commit = repo.find_commit(id)?;
+	append_log(
+		repo,
+		&format!(
+			"# {}: [{id}] {}\ngit bisect {} {id}\n",
+			mark.as_str(),
+			commit.summary().ok().flatten().unwrap_or_default(),
+			mark.as_str(),
+		),
+	)
+}
+
+fn append_log(repo: &Repository, line: &str) -> Result<()> {
+	let mut file = OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(repo.path().join(BISECT_LOG_FILE))?;
+
+	file.write_all(line.as_bytes())?;
+
+	Ok(())
+}
+
+fn read_terms(repo: &Repository) -> Result<Terms> {
+	let mut bad = None;
+	let mut good = Vec::new();
+	let mut skip = HashSet::new();
+
+	for reference in
+		repo.references_glob(&format!("{BISECT_REFS}*"))?
+	{
+		let reference = reference?;
+		let name = reference.name().unwrap_or_default();
+		let Some(id) = reference.target() else {
+			continue;
+		};
+
+		if name == BISECT_BAD_REF {
+			bad = Some(id);
+		} else if name.starts_with(&format!("{BISECT_REFS}good-")) {
+			good.push(id);
+		} else if name.starts_with(&format!("{BISECT_REFS}skip-")) {
+			skip.insert(id);
+		}
+	}
+
+	let bad = bad.ok_or_else(|| {
+		Error::Generic("no bad commit marked for bisect".to_string())
+	})?;
+
+	Ok(Terms { bad, good, skip })
+}
+
+/// commits reachable from the bad commit but from none of the good
+/// ones, newest first
+fn candidates(repo: &Repository, terms: &Terms) -> Result<Vec<Oid>> {
+	let mut walk = repo.revwalk()?;
+	walk.set_sorting(Sort::TOPOLOGICAL)?;
+	walk.push(terms.bad)?;
+	for good in &terms.good {
+		walk.hide(*good)?;
+	}
+
+	Ok(walk.collect::<std::result::Result<Vec<_>, _>>()?)
+}
+
+fn next_state(
+	repo: &Repository,
+	terms: &Terms,
+) -> Result<BisectState> {
+	let candidates = candidates(repo, terms)?;
+
+	let testable = candidates
+		.iter()
+		.filter(|id| **id != terms.bad && !terms.skip.contains(id))
+		.count();
+
+	if testable == 0 {
+		return Ok(if candidates.len() > 1 {
+			BisectState::OnlySkipped(
+				candidates.into_iter().map(CommitId::new).collect(),
+			)
+		} else {
+			BisectState::Found(terms.bad.into())
+		});
+	}
+
+	let weights = ancestor_counts(repo, &candidates)?;
+	let all = candidates.len();
+
+	let commit = candidates
+		.iter()
+		.filter(|id| **id != terms.bad && !terms.skip.contains(id))
+		.max_by_key(|id| {
+			let weight = weights.get(id).copied().unwrap_or_default();
+			weight.min(all - weight)
+		})
+		.copied()
+		.ok_or_else(|| {
+			Error::Generic("no commit left to test".to_string())
+		})?;
+
+	Ok(BisectState::Testing {
+		commit: commit.into(),
+		remaining: testable,
+		steps: estimate_steps(all),
+	})
+}
+
+/// number of commits within `candidates` reachable from each of them
+/// (including itself)
+fn ancestor_counts(
+	repo: &Repository,
+	candidates: &[Oid],
+) -> Result<HashMap<Oid, usize>> {
+	let set: HashSet<Oid> = candidates.iter().copied().collect();
+	let mut parents = HashMap::with_capacity(candidates.len());
+	for id in candidates {
+		let commit = repo.find_commit(*id)?;
+		let in_range: Vec<Oid> = commit
+			.parent_ids()
+			.filter(|parent| set.contains(parent))
+			.collect();
+		parents.insert(*id, in_range);
+	}
+
+	let mut counts = HashMap::with_capacity(candidates.len());
+
+	// oldest first so parents are counted before their children
+	for id in candidates.iter().rev() {
+		let count = match parents[id].as_slice() {
+			[] => 1,
+			[parent] => counts[parent] + 1,
+			// histories converge after a merge, count them once
+			_ => {
+				let mut seen = HashSet::new();
+				let mut todo = vec![*id];
+				while let Some(next) = todo.pop() {
+					if seen.insert(next) {
+						todo.extend(parents[&next].iter().copied());
+					}
+				}
+				seen.len()
+			}
+		};
+
+		counts.insert(*id, count);
+	}
+
+	Ok(counts)
+}
+
+/// same estimate `git bisect` prints as "roughly N steps"
+const fn estimate_steps(all: usize) -> usize {
+	if all < 3 {
+		return 0;
+	}
+
+	let n = all.ilog2() as usize;
+	let e = 1 << n;
+	let x = all - e;
+
+	if e < 3 * x {
+		n
+	} else {
+		n - 1
+	}
+}
+
+fn checkout_next(repo: &Repository) -> Result<BisectState> {
+	let state = next_state(repo, &read_terms(repo)?)?;
+
+	if let BisectState::Testing { commit, .. } = &state {
+		checkout_detached(repo, (*commit).into())?;
+	}
+
+	Ok(state)
+}
+
+fn checkout_detached(repo: &Repository, id: Oid) -> Result<()> {
+	let commit = repo.find_commit(id)?;
+
+	repo.checkout_tree(
+		commit.as_object(),
+		Some(CheckoutBuilder::new().safe()),
+	)?;
+	repo.set_head_detached(id)?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		branch::get_branch_name,
+		repo_state,
+		tests::{repo_init, write_commit_file},
+		utils::get_head_repo,
+		RepoState,
+	};
+	use pretty_assertions::assert_eq;
+
+	fn commits(repo: &Repository, count: usize) -> Vec<CommitId> {
+		(0..count)
+			.map(|idx| {
+				write_commit_file(
+					repo,
+					"file.txt",
+					&idx.to_string(),
+					&format!("commit{idx}"),
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_bisect_finds_first_bad() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let ids = commits(&repo, 10);
+		let first_bad = 6;
+
+		let mut state =
+			bisect_start(repo_path, ids[9], ids[0]).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Bisect);
+
+		let mut tested = 0;
+		while let BisectState::Testing { commit, .. } = state {
+			assert_eq!(get_head_repo(&repo).unwrap(), commit);
+
+			let idx =
+				ids.iter().position(|id| *id == commit).unwrap();
+			let mark = if idx >= first_bad {
+				BisectMark::Bad
+			} else {
+				BisectMark::Good
+			};
+
+			state = bisect_mark(repo_path, mark).unwrap();
+			tested += 1;
+		}
+
+		assert_eq!(state, BisectState::Found(ids[first_bad]));
+		assert!(tested <= 4);
+
+		bisect_reset(repo_path).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(get_branch_name(repo_path).unwrap(), "master");
+		assert_eq!(get_head_repo(&repo).unwrap(), ids[9]);
+	}
+
+	#[test]
+	fn test_bisect_range_and_skip() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let ids = commits(&repo, 3);
+
+		assert_eq!(bisect_refs(repo_path).unwrap(), None);
+
+		let state = bisect_start(repo_path, ids[2], ids[0]).unwrap();
+
+		assert_eq!(
+			state,
+			BisectState::Testing {
+				commit: ids[1],
+				remaining: 1,
+				steps: 0,
+			}
+		);
+		assert_eq!(
+			bisect_range(repo_path).unwrap(),
+			vec![ids[2], ids[1]]
+		);
+
+		let refs = bisect_refs(repo_path).unwrap().unwrap();
+		assert_eq!(refs.len(), 2);
+
+		let state = bisect_mark(repo_path, BisectMark::Skip).unwrap();
+
+		assert_eq!(
+			state,
+			BisectState::OnlySkipped(vec![ids[2], ids[1]])
+		);
+		assert_eq!(
+			bisect_refs(repo_path).unwrap().unwrap(),
+			[
+				refs,
+				vec![(
+					format!("{BISECT_REFS}skip-{}", ids[1]),
+					ids[1]
+				)]
+			]
+			.concat()
+		);
+		assert_eq!(bisect_state(repo_path).unwrap(), state);
+	}
+
+	#[test]
+	fn test_bisect_good_not_ancestor() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let ids = commits(&repo, 2);
+
+		assert!(bisect_start(repo_path, ids[0], ids[1]).is_err());
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+	}
+
+	#[test]
+	fn test_estimate_steps() {
+		assert_eq!(estimate_steps(2), 0);
+		assert_eq!(estimate_steps(9), 2);
+		assert_eq!(estimate_steps(16), 3);
+		assert_eq!(estimate_steps(1000), 9);
+	}
+}
@@ -454,6 +454,24 @@ pub fn create_branch(
 	Ok(branch_ref_name)
 }
 
+/// creates a new branch pointing to `commit` without checking it out
+pub fn create_branch_at(
+	repo_path: &RepoPath,
+	name: &str,
+	commit: CommitId,
+) -> Result<String> {
+	scope_time!("create_branch_at");
+
+	let repo = repo(repo_path)?;
+
+	let commit = repo.find_commit(commit.into())?;
+
+	let branch = repo.branch(name, &commit, false)?;
+	let branch_ref = branch.into_reference();
+
+	bytes2string(branch_ref.name_bytes())
+}
+
 #[cfg(test)]
 mod tests_branch_name {
 	use super::*;
@@ -0,0 +1,374 @@
+//! cherry-picking of one or many commits
+//!
+//! the commits still to be picked after a conflict are kept in
+//! `.git/sequencer` which gets removed by `cleanup_state` along with
+//! `CHERRY_PICK_HEAD`.
+
+use super::{
+	commit::signature_allow_undefined_name,
+	repository::repo,
+	sign::{create_signed_commit, SignBuilder},
+	utils::read_file,
+	CommitId, RepoPath,
+};
+use crate::error::{Error, Result};
+use git2::{Oid, Repository, RepositoryState, ResetType};
+use scopetime::scope_time;
+use std::{fmt::Write, fs, path::PathBuf};
+
+const GIT_CHERRY_PICK_HEAD_FILE: &str = "CHERRY_PICK_HEAD";
+const SEQUENCER_DIR: &str = "sequencer";
+const SEQUENCER_TODO_FILE: &str = "todo";
+const SEQUENCER_HEAD_FILE: &str = "head";
+
+///
+#[derive(Debug, PartialEq, Eq)]
+pub enum CherryPickState {
+	/// all commits got picked
+	Finished,
+	/// stopped at a commit that did not apply cleanly
+	Conflicted,
+}
+
+struct Sequence {
+	/// `HEAD` before the cherry-pick started
+	head: Oid,
+	/// commits not picked yet
+	todo: Vec<Oid>,
+}
+
+/// cherry-picks `commits` onto `HEAD` one after another, creating a
+/// commit for each. commits that end up empty are skipped.
+/// stops in `RepoState::CherryPick` on the first conflict.
+pub fn cherry_pick(
+	repo_path: &RepoPath,
+	commits: &[CommitId],
+) -> Result<CherryPickState> {
+	scope_time!("cherry_pick");
+
+	let repo = repo(repo_path)?;
+
+	if repo.state() != RepositoryState::Clean {
+		return Err(Error::Generic(
+			"cherry-pick needs a clean repository state".to_string(),
+		));
+	}
+
+	let sequence = Sequence {
+		head: repo.head()?.peel_to_commit()?.id(),
+		todo: commits
+			.iter()
+			.copied()
+			.map(CommitId::get_oid)
+			.collect(),
+	};
+
+	pick_commits(&repo, sequence)
+}
+
+/// commit the cherry-pick is currently stopped at
+pub fn cherry_pick_head(repo_path: &RepoPath) -> Result<CommitId> {
+	scope_time!("cherry_pick_head");
+
+	let path =
+		repo(repo_path)?.path().join(GIT_CHERRY_PICK_HEAD_FILE);
+
+	let file_content = read_file(&path)?;
+
+	let id = Oid::from_str(file_content.trim())?;
+
+	Ok(id.into())
+}
+
+/// number of commits still to be picked after the current one
+pub fn cherry_pick_remaining(repo_path: &RepoPath) -> Result<usize> {
+	scope_time!("cherry_pick_remaining");
+
+	let repo = repo(repo_path)?;
+
+	Ok(read_sequence(&repo)?.todo.len())
+}
+
+/// commits the resolved conflict and picks the remaining commits
+pub fn continue_cherry_pick(
+	repo_path: &RepoPath,
+) -> Result<CherryPickState> {
+	scope_time!("continue_cherry_pick");
+
+	let repo = repo(repo_path)?;
+
+	if repo.index()?.has_conflicts() {
+		return Ok(CherryPickState::Conflicted);
+	}
+
+	let sequence = read_sequence(&repo)?;
+	let current =
+		repo.find_commit(cherry_pick_head(repo_path)?.into())?;
+
+	commit_picked(&repo, &current)?;
+
+	repo.cleanup_state()?;
+
+	pick_commits(&repo, sequence)
+}
+
+/// drops the changes of the current commit and picks the remaining
+/// commits
+pub fn skip_cherry_pick(
+	repo_path: &RepoPath,
+) -> Result<CherryPickState> {
+	scope_time!("skip_cherry_pick");
+
+	let repo = repo(repo_path)?;
+
+	let sequence = read_sequence(&repo)?;
+
+	let head = repo.head()?.peel_to_commit()?;
+	repo.reset(head.as_object(), ResetType::Hard, None)?;
+	repo.cleanup_state()?;
+
+	pick_commits(&repo, sequence)
+}
+
+/// stops cherry-picking and resets `HEAD` to where it was before
+pub fn abort_cherry_pick(repo_path: &RepoPath) -> Result<()> {
+	scope_time!("abort_cherry_pick");
+
+	let repo = repo(repo_path)?;
+
+	let head = read_sequence(&repo).map_or_else(
+		|_| repo.head()?.peel_to_commit().map(|c| c.id()),
+		|sequence| Ok(sequence.head),
+	)?;
+
+	let head = repo.find_commit(head)?;
+	repo.reset(head.as_object(), ResetType::Hard, None)?;
+	repo.cleanup_state()?;
+
+	Ok(())
+}
+
+fn pick_commits(
+	repo: &Repository,
+	mut sequence: Sequence,
+) -> Result<CherryPickState> {
+	while !sequence.todo.is_empty() {
+		let commit = repo.find_commit(sequence.todo.remove(0))?;
+
+		repo.cherrypick(&commit, None)?;
+
+		if repo.index()?.has_conflicts() {
+			write_sequence(repo, &sequence)?;
+			return Ok(CherryPickState::Conflicted);
+		}
+
+		commit_picked(repo, &commit)?;
+
+		repo.cleanup_state()?;
+	}
+
+	Ok(CherryPickState::Finished)
+}
+
+/// commits the index using author and message of `picked`
+fn commit_picked(
+	repo: &Repository,
+	picked: &git2::Commit,
+) -> Result<()> {
+	let mut index = repo.index()?;
+	let tree = repo.find_tree(index.write_tree()?)?;
+	let head = repo.head()?.peel_to_commit()?;
+
+	if tree.id() == head.tree_id() {
+		return Ok(());
+	}
+
+	let config = repo.config()?;
+	let committer = signature_allow_undefined_name(repo)?;
+	let msg = picked.message_raw().unwrap_or_default();
+
+	if config.get_bool("commit.gpgsign").unwrap_or(false) {
+		let signer = SignBuilder::from_gitconfig(repo, &config)?;
+		let id = create_signed_commit(
+			repo,
+			signer.as_ref(),
+			&picked.author(),
+			&committer,
+			msg,
+			&tree,
+			&[&head],
+		)?;
+
+		repo.head()?.set_target(id, msg)?;
+	} else {
+		repo.commit(
+			Some("HEAD"),
+			&picked.author(),
+			&committer,
+			msg,
+			&tree,
+			&[&head],
+		)?;
+	}
+
+	Ok(())
+}
+
+fn sequencer_dir(repo: &Repository) -> PathBuf {
+	repo.path().join(SEQUENCER_DIR)
+}
+
+fn write_sequence(
+	repo: &Repository,
+	sequence: &Sequence,
+) -> Result<()> {
+	let dir = sequencer_dir(repo);
+
+	fs::create_dir_all(&dir)?;
+
+	fs::write(
+		dir.join(SEQUENCER_HEAD_FILE),
+		format!("{}\n", sequence.head),
+	)?;
+
+	let todo =
+		sequence.todo.iter().fold(String::new(), |mut s, id| {
+			let _ = writeln!(s, "pick {id}");
+			s
+		});
+
+	fs::write(dir.join(SEQUENCER_TODO_FILE), todo)?;
+
+	Ok(())
+}
+
+fn read_sequence(repo: &Repository) -> Result<Sequence> {
+	let dir = sequencer_dir(repo);
+
+	let head = Oid::from_str(
+		read_file(&dir.join(SEQUENCER_HEAD_FILE))?.trim(),
+	)?;
+
+	let todo = read_file(&dir.join(SEQUENCER_TODO_FILE))?
+		.lines()
+		.filter_map(|line| line.split_whitespace().nth(1))
+		.map(Oid::from_str)
+		.collect::<std::result::Result<Vec<_>, _>>()?;
+
+	Ok(Sequence { head, todo })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		checkout_branch, create_branch, repo_state,
+		tests::{repo_init, write_commit_file},
+		utils::{get_head_repo, repo_write_file, stage_add_file},
+		RepoState,
+	};
+	use pretty_assertions::assert_eq;
+	use std::path::Path;
+
+	fn head_message(repo: &Repository) -> String {
+		repo.head()
+			.unwrap()
+			.peel_to_commit()
+			.unwrap()
+			.message()
+			.unwrap()
+			.to_string()
+	}
+
+	#[test]
+	fn test_cherry_pick_many() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		create_branch(repo_path, "foo").unwrap();
+		let c1 = write_commit_file(&repo, "a.txt", "a", "commit1");
+		let c2 = write_commit_file(&repo, "b.txt", "b", "commit2");
+
+		checkout_branch(repo_path, "master").unwrap();
+
+		let res = cherry_pick(repo_path, &[c1, c2]).unwrap();
+
+		assert_eq!(res, CherryPickState::Finished);
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(head_message(&repo), "commit2");
+		assert!(root.join("a.txt").exists());
+		assert!(root.join("b.txt").exists());
+
+		let head = repo.head().unwrap().peel_to_commit().unwrap();
+		assert_eq!(
+			head.parent(0).unwrap().message().unwrap(),
+			"commit1"
+		);
+	}
+
+	#[test]
+	fn test_cherry_pick_conflict_continue() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		create_branch(repo_path, "foo").unwrap();
+		let c1 = write_commit_file(&repo, "a.txt", "foo", "commit1");
+		let c2 = write_commit_file(&repo, "b.txt", "b", "commit2");
+
+		checkout_branch(repo_path, "master").unwrap();
+		write_commit_file(&repo, "a.txt", "master", "commit3");
+
+		let res = cherry_pick(repo_path, &[c1, c2]).unwrap();
+
+		assert_eq!(res, CherryPickState::Conflicted);
+		assert_eq!(
+			repo_state(repo_path).unwrap(),
+			RepoState::CherryPick
+		);
+		assert_eq!(cherry_pick_head(repo_path).unwrap(), c1);
+		assert_eq!(cherry_pick_remaining(repo_path).unwrap(), 1);
+
+		repo_write_file(&repo, "a.txt", "resolved").unwrap();
+		stage_add_file(repo_path, Path::new("a.txt")).unwrap();
+
+		let res = continue_cherry_pick(repo_path).unwrap();
+
+		assert_eq!(res, CherryPickState::Finished);
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(head_message(&repo), "commit2");
+		assert!(!repo.path().join(SEQUENCER_DIR).exists());
+	}
+
+	#[test]
+	fn test_cherry_pick_skip_and_abort() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		create_branch(repo_path, "foo").unwrap();
+		let c1 = write_commit_file(&repo, "a.txt", "foo", "commit1");
+		let c2 = write_commit_file(&repo, "a.txt", "foo2", "commit2");
+		let c3 = write_commit_file(&repo, "b.txt", "b", "commit3");
+
+		checkout_branch(repo_path, "master").unwrap();
+		let head = write_commit_file(&repo, "a.txt", "x", "commit4");
+
+		let res = cherry_pick(repo_path, &[c1, c2, c3]).unwrap();
+		assert_eq!(res, CherryPickState::Conflicted);
+
+		let res = skip_cherry_pick(repo_path).unwrap();
+		assert_eq!(res, CherryPickState::Conflicted);
+		assert_eq!(cherry_pick_head(repo_path).unwrap(), c2);
+
+		abort_cherry_pick(repo_path).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(get_head_repo(&repo).unwrap(), head);
+		assert!(!root.join("b.txt").exists());
+	}
+}
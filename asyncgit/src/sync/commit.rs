@@ -287,7 +287,7 @@ mod tests {
 		let details = get_commit_details(repo_path, new_id)?;
 		assert_eq!(details.message.unwrap().subject, "amended");
 
-		let files = get_commit_files(repo_path, new_id, None)?;
+		let files = get_commit_files(repo_path, new_id, None, None)?;
 
 		assert_eq!(files.len(), 2);
 
@@ -45,6 +45,7 @@ pub fn get_commit_files(
 	repo_path: &RepoPath,
 	id: CommitId,
 	other: Option<CommitId>,
+	options: Option<DiffOptions>,
 ) -> Result<Vec<StatusItem>> {
 	scope_time!("get_commit_files");
 
@@ -55,14 +56,14 @@ pub fn get_commit_files(
 			&repo,
 			sort_commits(&repo, (id, other))?,
 			None,
-			None,
+			options,
 		)?
 	} else {
 		get_commit_diff(
 			&repo,
 			id,
 			None,
-			None,
+			options,
 			Some(&get_stashes(repo_path)?.into_iter().collect()),
 		)?
 	};
@@ -107,20 +108,22 @@ pub fn get_compare_commits_diff(
 
 	let mut opts = git2::DiffOptions::new();
 	if let Some(options) = options {
-		opts.context_lines(options.context);
-		opts.ignore_whitespace(options.ignore_whitespace);
-		opts.interhunk_lines(options.interhunk_lines);
+		options.apply(&mut opts);
 	}
 	if let Some(p) = &pathspec {
 		opts.pathspec(p.clone());
 	}
 
-	let diff: Diff<'_> = repo.diff_tree_to_tree(
+	let mut diff: Diff<'_> = repo.diff_tree_to_tree(
 		Some(&trees.old),
 		Some(&trees.new),
 		Some(&mut opts),
 	)?;
 
+	if let Some(options) = options {
+		options.find_similar(&mut diff)?;
+	}
+
 	Ok(diff)
 }
 
@@ -147,9 +150,7 @@ pub(crate) fn get_commit_diff<'a>(
 
 	let mut opts = git2::DiffOptions::new();
 	if let Some(options) = options {
-		opts.context_lines(options.context);
-		opts.ignore_whitespace(options.ignore_whitespace);
-		opts.interhunk_lines(options.interhunk_lines);
+		options.apply(&mut opts);
 	}
 	if let Some(p) = &pathspec {
 		opts.pathspec(p.clone());
@@ -176,16 +177,22 @@ pub(crate) fn get_commit_diff<'a>(
 		}
 	}
 
+	if let Some(options) = options {
+		options.find_similar(&mut diff)?;
+	}
+
 	Ok(diff)
 }
 
 #[cfg(test)]
 mod tests {
-	use super::get_commit_files;
+	use super::{get_commit_diff, get_commit_files};
 	use crate::{
 		error::Result,
 		sync::{
-			commit, stage_add_file, stash_save,
+			commit,
+			diff::{DiffOptions, RenameDetection},
+			stage_add_file, stage_addremoved, stash_save,
 			tests::{get_statuses, repo_init},
 			RepoPath,
 		},
@@ -208,7 +215,7 @@ mod tests {
 
 		let id = commit(repo_path, "commit msg")?;
 
-		let diff = get_commit_files(repo_path, id, None)?;
+		let diff = get_commit_files(repo_path, id, None, None)?;
 
 		assert_eq!(diff.len(), 1);
 		assert_eq!(diff[0].status, StatusItemType::New);
@@ -229,7 +236,7 @@ mod tests {
 
 		let id = stash_save(repo_path, None, true, false)?;
 
-		let diff = get_commit_files(repo_path, id, None)?;
+		let diff = get_commit_files(repo_path, id, None, None)?;
 
 		assert_eq!(diff.len(), 1);
 		assert_eq!(diff[0].status, StatusItemType::New);
@@ -258,7 +265,7 @@ mod tests {
 
 		let id = stash_save(repo_path, None, true, false)?;
 
-		let diff = get_commit_files(repo_path, id, None)?;
+		let diff = get_commit_files(repo_path, id, None, None)?;
 
 		assert_eq!(diff.len(), 2);
 		assert_eq!(diff[0].status, StatusItemType::Modified);
@@ -266,4 +273,44 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_rename_detection() -> Result<()> {
+		let file_path1 = Path::new("file1.txt");
+		let file_path2 = Path::new("file2.txt");
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(root.join(file_path1))?
+			.write_all(b"line 1\nline 2\nline 3\nline 4\n")?;
+		stage_add_file(repo_path, file_path1)?;
+		commit(repo_path, "c1")?;
+
+		std::fs::rename(
+			root.join(file_path1),
+			root.join(file_path2),
+		)?;
+		stage_addremoved(repo_path, file_path1)?;
+		stage_add_file(repo_path, file_path2)?;
+		let id = commit(repo_path, "c2")?;
+
+		let diff = get_commit_diff(&repo, id, None, None, None)?;
+		assert_eq!(diff.deltas().len(), 2);
+
+		let options = DiffOptions {
+			renames: RenameDetection::Renames,
+			..DiffOptions::default()
+		};
+		let diff =
+			get_commit_diff(&repo, id, None, Some(options), None)?;
+		assert_eq!(diff.deltas().len(), 1);
+		assert_eq!(
+			diff.get_delta(0).unwrap().status(),
+			git2::Delta::Renamed
+		);
+
+		Ok(())
+	}
 }
@@ -1,11 +1,14 @@
 use super::{
 	commit_details::get_author_of_commit,
-	commit_files::get_commit_diff, CommitId,
+	commit_files::get_commit_diff, diff::DiffOptions, CommitId,
 };
 use crate::error::Result;
 use bitflags::bitflags;
 use fuzzy_matcher::FuzzyMatcher;
-use git2::{Diff, Repository};
+use git2::{
+	Commit, Diff, DiffFile, DiffLineType, FileMode, Patch, Repository,
+};
+use regex::{Regex, RegexBuilder};
 use std::sync::Arc;
 
 ///
@@ -46,12 +49,14 @@ bitflags! {
 		const FILENAMES = 1 << 2;
 		///
 		const AUTHORS = 1 << 3;
-		//TODO:
-		// const COMMIT_HASHES = 1 << 3;
-		// ///
-		// const DATES = 1 << 4;
-		// ///
-		// const DIFFS = 1 << 5;
+		/// commit id starts with the search pattern
+		const COMMIT_HASHES = 1 << 4;
+		/// author or committer time within
+		/// `LogFilterSearchOptions::date_range`
+		const DATES = 1 << 5;
+		/// added or removed lines of the commit's patch match the
+		/// search pattern, like `git log -G`
+		const DIFFS = 1 << 6;
 	}
 }
 
@@ -69,6 +74,13 @@ bitflags! {
 		const CASE_SENSITIVE = 1 << 0;
 		///
 		const FUZZY_SEARCH = 1 << 1;
+		/// with `SearchFields::DIFFS` only match commits that change
+		/// the number of occurrences of the pattern in a file, like
+		/// `git log -S`
+		const PICKAXE_COUNT = 1 << 2;
+		/// treat the search pattern as a regular expression, takes
+		/// precedence over `FUZZY_SEARCH`
+		const REGEX = 1 << 3;
 	}
 }
 
@@ -78,6 +90,23 @@ impl Default for SearchOptions {
 	}
 }
 
+/// time range in seconds since epoch, both ends inclusive
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+	///
+	pub since: Option<i64>,
+	///
+	pub until: Option<i64>,
+}
+
+impl DateRange {
+	///
+	pub fn contains(&self, time: i64) -> bool {
+		self.since.is_none_or(|since| time >= since)
+			&& self.until.is_none_or(|until| time <= until)
+	}
+}
+
 ///
 #[derive(Default, Debug, Clone)]
 pub struct LogFilterSearchOptions {
@@ -87,6 +116,28 @@ pub struct LogFilterSearchOptions {
 	pub fields: SearchFields,
 	///
 	pub options: SearchOptions,
+	/// only used with `SearchFields::DATES`
+	pub date_range: DateRange,
+}
+
+impl LogFilterSearchOptions {
+	/// fails if `SearchOptions::REGEX` is set and the search pattern
+	/// is not a valid regular expression
+	pub fn validate(&self) -> Result<()> {
+		if self.options.contains(SearchOptions::REGEX) {
+			self.build_regex()?;
+		}
+
+		Ok(())
+	}
+
+	fn build_regex(&self) -> Result<Regex> {
+		Ok(RegexBuilder::new(&self.search_pattern)
+			.case_insensitive(
+				!self.options.contains(SearchOptions::CASE_SENSITIVE),
+			)
+			.build()?)
+	}
 }
 
 ///
@@ -96,22 +147,41 @@ pub struct LogFilterSearch {
 	pub matcher: fuzzy_matcher::skim::SkimMatcherV2,
 	///
 	pub options: LogFilterSearchOptions,
+	regex: Option<Regex>,
 }
 
 impl LogFilterSearch {
-	///
+	/// an invalid pattern with `SearchOptions::REGEX` matches
+	/// nothing, see `LogFilterSearchOptions::validate`
 	pub fn new(options: LogFilterSearchOptions) -> Self {
 		let mut options = options;
-		if !options.options.contains(SearchOptions::CASE_SENSITIVE) {
+
+		let regex = if options.options.contains(SearchOptions::REGEX)
+		{
+			options.build_regex().ok()
+		} else {
+			None
+		};
+
+		if regex.is_none()
+			&& !options
+				.options
+				.contains(SearchOptions::CASE_SENSITIVE)
+		{
 			options.search_pattern =
 				options.search_pattern.to_lowercase();
 		}
 		Self {
 			matcher: fuzzy_matcher::skim::SkimMatcherV2::default(),
 			options,
+			regex,
 		}
 	}
 
+	const fn regex_search(&self) -> bool {
+		self.options.options.contains(SearchOptions::REGEX)
+	}
+
 	fn match_diff(&self, diff: &Diff<'_>) -> bool {
 		diff.deltas().any(|delta| {
 			if delta
@@ -131,9 +201,127 @@ impl LogFilterSearch {
 		})
 	}
 
+	fn match_content(
+		&self,
+		repo: &Repository,
+		diff: &Diff<'_>,
+	) -> Result<bool> {
+		if self.options.options.contains(SearchOptions::PICKAXE_COUNT)
+		{
+			self.match_occurrences(repo, diff)
+		} else {
+			self.match_patch(diff)
+		}
+	}
+
+	fn match_patch(&self, diff: &Diff<'_>) -> Result<bool> {
+		for idx in 0..diff.deltas().len() {
+			let Some(patch) = Patch::from_diff(diff, idx)? else {
+				continue;
+			};
+
+			for hunk in 0..patch.num_hunks() {
+				for line in 0..patch.num_lines_in_hunk(hunk)? {
+					let line = patch.line_in_hunk(hunk, line)?;
+
+					if matches!(
+						line.origin_value(),
+						DiffLineType::Addition
+							| DiffLineType::Deletion
+					) && self.match_text(&String::from_utf8_lossy(
+						line.content(),
+					)) {
+						return Ok(true);
+					}
+				}
+			}
+		}
+
+		Ok(false)
+	}
+
+	fn match_occurrences(
+		&self,
+		repo: &Repository,
+		diff: &Diff<'_>,
+	) -> Result<bool> {
+		for delta in diff.deltas() {
+			if self.count_occurrences(repo, &delta.old_file())?
+				!= self.count_occurrences(repo, &delta.new_file())?
+			{
+				return Ok(true);
+			}
+		}
+
+		Ok(false)
+	}
+
+	fn count_occurrences(
+		&self,
+		repo: &Repository,
+		file: &DiffFile<'_>,
+	) -> Result<usize> {
+		if file.id().is_zero() || file.mode() == FileMode::Commit {
+			return Ok(0);
+		}
+
+		let blob = repo.find_blob(file.id())?;
+		if blob.is_binary() {
+			return Ok(0);
+		}
+
+		let content = String::from_utf8_lossy(blob.content());
+		let pattern = self.options.search_pattern.as_str();
+
+		let count = if self.regex_search() {
+			self.regex
+				.as_ref()
+				.map_or(0, |regex| regex.find_iter(&content).count())
+		} else if self
+			.options
+			.options
+			.contains(SearchOptions::CASE_SENSITIVE)
+		{
+			content.matches(pattern).count()
+		} else {
+			content.to_lowercase().matches(pattern).count()
+		};
+
+		Ok(count)
+	}
+
+	fn match_dates(&self, commit: &Commit<'_>) -> bool {
+		!self.options.fields.contains(SearchFields::DATES)
+			|| [commit.author().when(), commit.committer().when()]
+				.iter()
+				.any(|time| {
+					self.options.date_range.contains(time.seconds())
+				})
+	}
+
+	fn match_hash(&self, commit_id: &CommitId) -> bool {
+		if self.regex_search() {
+			return self.match_text(&commit_id.to_string());
+		}
+
+		let pattern = self.options.search_pattern.trim();
+
+		!pattern.is_empty()
+			&& commit_id
+				.to_string()
+				.starts_with(&pattern.to_lowercase())
+	}
+
 	///
 	pub fn match_text(&self, text: &str) -> bool {
-		if self.options.options.contains(SearchOptions::FUZZY_SEARCH)
+		if self.regex_search() {
+			self.regex
+				.as_ref()
+				.is_some_and(|regex| regex.is_match(text))
+		} else if self
+			.options
+			.options
+			.contains(SearchOptions::FUZZY_SEARCH)
 		{
 			self.matcher
 				.fuzzy_match(
@@ -154,6 +342,23 @@ impl LogFilterSearch {
 	}
 }
 
+/// context lines are never searched, skip producing them
+fn search_diff(
+	repo: &Repository,
+	commit_id: CommitId,
+) -> Result<Diff<'_>> {
+	get_commit_diff(
+		repo,
+		commit_id,
+		None,
+		Some(DiffOptions {
+			context: 0,
+			..DiffOptions::default()
+		}),
+		None,
+	)
+}
+
 ///
 pub fn filter_commit_by_search(
 	filter: LogFilterSearch,
@@ -193,18 +398,34 @@ pub fn filter_commit_by_search(
 				.flatten()
 				.unwrap_or_default();
 
+			let diff = filter
+				.options
+				.fields
+				.intersects(
+					SearchFields::FILENAMES | SearchFields::DIFFS,
+				)
+				.then(|| search_diff(repo, *commit_id).ok())
+				.flatten();
+
 			let file_match = filter
 				.options
 				.fields
 				.contains(SearchFields::FILENAMES)
-				.then(|| {
-					get_commit_diff(
-						repo, *commit_id, None, None, None,
-					)
-					.ok()
-				})
-				.flatten()
-				.is_some_and(|diff| filter.match_diff(&diff));
+				&& diff
+					.as_ref()
+					.is_some_and(|diff| filter.match_diff(diff));
+
+			let content_match = match diff.as_ref() {
+				Some(diff)
+					if filter
+						.options
+						.fields
+						.contains(SearchFields::DIFFS) =>
+				{
+					filter.match_content(repo, diff)?
+				}
+				_ => false,
+			};
 
 			let authors_match = if filter
 				.options
@@ -223,10 +444,26 @@ pub fn filter_commit_by_search(
 				false
 			};
 
-			Ok(msg_summary_match
+			let hash_match = filter
+				.options
+				.fields
+				.contains(SearchFields::COMMIT_HASHES)
+				&& filter.match_hash(commit_id);
+
+			// the date range restricts the text search instead of
+			// adding to it
+			let text_fields =
+				filter.options.fields - SearchFields::DATES;
+			let text_match = text_fields.is_empty()
+				|| filter.options.search_pattern.is_empty()
+				|| msg_summary_match
 				|| msg_body_match
 				|| file_match
-				|| authors_match)
+				|| content_match
+				|| authors_match
+				|| hash_match;
+
+			Ok(text_match && filter.match_dates(&commit))
 		},
 	))
 }
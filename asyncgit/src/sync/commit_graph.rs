@@ -0,0 +1,369 @@
+//! lane layout for drawing the commit graph next to the log
+
+use super::CommitId;
+
+/// rows never store more lanes than this, the layout itself is not limited
+const MAX_LANES: usize = 32;
+
+/// glyph drawn in a single lane of a [`GraphRow`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GraphSymbol {
+	/// nothing to draw in this lane
+	Empty,
+	/// the commit of this row
+	Commit,
+	/// the commit of this row has more than one parent
+	MergeCommit,
+	/// a lane passing through this row
+	Vertical,
+	/// horizontal line connecting other lanes to the commit
+	Horizontal,
+	/// a lane passing through while crossed by a horizontal line
+	Crossing,
+	/// lane ends here joining the commit to its right (`╰`)
+	UpRight,
+	/// lane ends here joining the commit to its left (`╯`)
+	UpLeft,
+	/// lane starts here forking off the commit to its right (`╭`)
+	DownRight,
+	/// lane starts here forking off the commit to its left (`╮`)
+	DownLeft,
+	/// lane passes through and connects to the commit on its right (`├`)
+	VerticalRight,
+	/// lane passes through and connects to the commit on its left (`┤`)
+	VerticalLeft,
+}
+
+/// a single lane of a [`GraphRow`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GraphCell {
+	///
+	pub symbol: GraphSymbol,
+	/// colour index of the lane, meant to be used modulo a palette
+	pub color: u8,
+	/// horizontal line to the next lane (using this colour index)
+	pub connector: Option<u8>,
+}
+
+/// graph lanes of a single commit in the log
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphRow {
+	///
+	pub cells: Box<[GraphCell]>,
+}
+
+#[derive(Clone, Copy)]
+struct Lane {
+	next: CommitId,
+	color: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Join {
+	/// lane ends in this row
+	Ended,
+	/// new lane starts in this row
+	Forked,
+	/// lane ends and a new one starts in the same row
+	EndedAndForked,
+	/// already existing lane gets connected to the commit
+	Connected,
+}
+
+/// incrementally lays out the commit graph one commit at a time.
+/// commits have to be pushed in the order they are shown in the log
+/// (children before their parents).
+#[derive(Default)]
+pub struct CommitGraph {
+	lanes: Vec<Option<Lane>>,
+	next_color: u8,
+}
+
+impl CommitGraph {
+	///
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// computes the row for commit `id` and advances the layout
+	pub fn push(
+		&mut self,
+		id: CommitId,
+		parents: &[CommitId],
+	) -> GraphRow {
+		let above: Vec<bool> =
+			self.lanes.iter().map(Option::is_some).collect();
+
+		let col = self
+			.lane_of(id)
+			.or_else(|| self.lanes.iter().position(Option::is_none))
+			.unwrap_or(self.lanes.len());
+
+		if col == self.lanes.len() {
+			self.lanes.push(None);
+		}
+
+		let color = self.lanes[col]
+			.map_or_else(|| self.new_color(), |lane| lane.color);
+
+		let mut joins: Vec<(usize, Join)> = Vec::new();
+		// lanes ending here are gone from the layout afterwards
+		let mut ended_colors: Vec<(usize, u8)> = Vec::new();
+
+		for (idx, lane) in self.lanes.iter_mut().enumerate() {
+			if let Some(ended) =
+				lane.filter(|lane| idx != col && lane.next == id)
+			{
+				*lane = None;
+				joins.push((idx, Join::Ended));
+				ended_colors.push((idx, ended.color));
+			}
+		}
+
+		self.lanes[col] =
+			parents.first().map(|next| Lane { next: *next, color });
+
+		for parent in parents.iter().skip(1) {
+			if let Some(idx) = self.lane_of(*parent) {
+				if idx != col && !joins.iter().any(|j| j.0 == idx) {
+					joins.push((idx, Join::Connected));
+				}
+				continue;
+			}
+
+			let lane = Lane {
+				next: *parent,
+				color: self.new_color(),
+			};
+
+			if let Some(idx) =
+				self.lanes.iter().position(Option::is_none)
+			{
+				self.lanes[idx] = Some(lane);
+
+				if let Some(join) =
+					joins.iter_mut().find(|j| j.0 == idx)
+				{
+					join.1 = Join::EndedAndForked;
+				} else {
+					joins.push((idx, Join::Forked));
+				}
+			} else {
+				self.lanes.push(Some(lane));
+				joins.push((self.lanes.len() - 1, Join::Forked));
+			}
+		}
+
+		let row = self.build_row(
+			&above,
+			col,
+			color,
+			parents.len() > 1,
+			&joins,
+			&ended_colors,
+		);
+
+		while self.lanes.last().is_some_and(Option::is_none) {
+			self.lanes.pop();
+		}
+
+		row
+	}
+
+	fn lane_of(&self, id: CommitId) -> Option<usize> {
+		self.lanes
+			.iter()
+			.position(|lane| lane.is_some_and(|lane| lane.next == id))
+	}
+
+	const fn new_color(&mut self) -> u8 {
+		let color = self.next_color;
+		self.next_color = self.next_color.wrapping_add(1);
+		color
+	}
+
+	fn build_row(
+		&self,
+		above: &[bool],
+		col: usize,
+		commit_color: u8,
+		merge: bool,
+		joins: &[(usize, Join)],
+		ended_colors: &[(usize, u8)],
+	) -> GraphRow {
+		let width = above.len().max(self.lanes.len());
+
+		let lane_color = |idx: usize| -> u8 {
+			self.lanes
+				.get(idx)
+				.copied()
+				.flatten()
+				.map(|lane| lane.color)
+				.or_else(|| {
+					ended_colors
+						.iter()
+						.find(|(i, _)| *i == idx)
+						.map(|(_, c)| *c)
+				})
+				.unwrap_or(commit_color)
+		};
+
+		let min = joins.iter().map(|j| j.0).chain([col]).min();
+		let max = joins.iter().map(|j| j.0).chain([col]).max();
+		let (min, max) = (min.unwrap_or(col), max.unwrap_or(col));
+
+		// colour of the horizontal line at `idx`, which is owned by
+		// the outermost join it leads to
+		let span_color = |idx: usize| -> u8 {
+			let join = if idx < col {
+				joins.iter().filter(|j| j.0 <= idx).map(|j| j.0).max()
+			} else {
+				joins.iter().filter(|j| j.0 >= idx).map(|j| j.0).min()
+			};
+			join.map_or(commit_color, lane_color)
+		};
+
+		let cells = (0..width.min(MAX_LANES))
+			.map(|idx| {
+				let join =
+					joins.iter().find(|j| j.0 == idx).map(|j| j.1);
+				let left = idx < col;
+				let passing =
+					above.get(idx).copied().unwrap_or_default()
+						&& self
+							.lanes
+							.get(idx)
+							.is_some_and(Option::is_some);
+				let in_span = idx > min && idx < max;
+
+				let symbol = match join {
+					_ if idx == col => {
+						if merge {
+							GraphSymbol::MergeCommit
+						} else {
+							GraphSymbol::Commit
+						}
+					}
+					Some(Join::Ended) if left => GraphSymbol::UpRight,
+					Some(Join::Ended) => GraphSymbol::UpLeft,
+					Some(Join::Forked) if left => {
+						GraphSymbol::DownRight
+					}
+					Some(Join::Forked) => GraphSymbol::DownLeft,
+					Some(Join::EndedAndForked | Join::Connected)
+						if left =>
+					{
+						GraphSymbol::VerticalRight
+					}
+					Some(Join::EndedAndForked | Join::Connected) => {
+						GraphSymbol::VerticalLeft
+					}
+					None if passing && in_span => {
+						GraphSymbol::Crossing
+					}
+					None if passing => GraphSymbol::Vertical,
+					None if in_span => GraphSymbol::Horizontal,
+					None => GraphSymbol::Empty,
+				};
+
+				let color = match symbol {
+					GraphSymbol::Commit
+					| GraphSymbol::MergeCommit => commit_color,
+					GraphSymbol::Horizontal => span_color(idx),
+					_ => lane_color(idx),
+				};
+
+				let connector =
+					(idx >= min && idx < max).then(|| {
+						span_color(if left { idx } else { idx + 1 })
+					});
+
+				GraphCell {
+					symbol,
+					color,
+					connector,
+				}
+			})
+			.collect();
+
+		GraphRow { cells }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	fn id(idx: usize) -> CommitId {
+		CommitId::from_str_unchecked(&format!("{idx:040}")).unwrap()
+	}
+
+	fn symbols(row: &GraphRow) -> Vec<GraphSymbol> {
+		row.cells.iter().map(|cell| cell.symbol).collect()
+	}
+
+	#[test]
+	fn test_linear() {
+		let mut graph = CommitGraph::new();
+
+		let first = graph.push(id(2), &[id(1)]);
+		let second = graph.push(id(1), &[]);
+
+		assert_eq!(symbols(&first), vec![GraphSymbol::Commit]);
+		assert_eq!(symbols(&second), vec![GraphSymbol::Commit]);
+		assert_eq!(first.cells[0].connector, None);
+	}
+
+	#[test]
+	fn test_fork_and_merge() {
+		let mut graph = CommitGraph::new();
+
+		let merge = graph.push(id(4), &[id(3), id(2)]);
+		let left = graph.push(id(3), &[id(1)]);
+		let right = graph.push(id(2), &[id(1)]);
+		let base = graph.push(id(1), &[]);
+
+		assert_eq!(
+			symbols(&merge),
+			vec![GraphSymbol::MergeCommit, GraphSymbol::DownLeft]
+		);
+		assert!(merge.cells[0].connector.is_some());
+		assert_eq!(
+			symbols(&left),
+			vec![GraphSymbol::Commit, GraphSymbol::Vertical]
+		);
+		assert_eq!(
+			symbols(&right),
+			vec![GraphSymbol::Vertical, GraphSymbol::Commit]
+		);
+		assert_eq!(
+			symbols(&base),
+			vec![GraphSymbol::Commit, GraphSymbol::UpLeft]
+		);
+		assert_eq!(base.cells[1].color, right.cells[1].color);
+		assert_ne!(base.cells[0].color, base.cells[1].color);
+	}
+
+	#[test]
+	fn test_crossing() {
+		let mut graph = CommitGraph::new();
+
+		// two unrelated tips followed by a merge that forks a third lane
+		graph.push(id(9), &[id(5)]);
+		graph.push(id(8), &[id(4)]);
+		let row = graph.push(id(5), &[id(3), id(2)]);
+
+		assert_eq!(
+			symbols(&row),
+			vec![
+				GraphSymbol::MergeCommit,
+				GraphSymbol::Crossing,
+				GraphSymbol::DownLeft
+			]
+		);
+		assert!(row.cells[0].connector.is_some());
+		assert!(row.cells[1].connector.is_some());
+		assert!(row.cells[2].connector.is_none());
+	}
+}
@@ -0,0 +1,416 @@
+//! verifying commit signatures, like `git verify-commit`
+
+use super::{repository::repo, CommitId, RepoPath};
+use crate::error::Result;
+use git2::{Config, ErrorCode};
+use scopetime::scope_time;
+use std::{
+	io::Write,
+	process::{Command, Output, Stdio},
+};
+use tempfile::NamedTempFile;
+
+/// result of verifying the signature of a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureStatus {
+	/// the commit has no `gpgsig` header
+	Unsigned,
+	/// valid signature, holds the signer
+	Good(String),
+	/// the signature does not match the commit, or was made by a
+	/// revoked key
+	Bad(String),
+	/// the signature could not be checked, e.g. the key is unknown or
+	/// not in `gpg.ssh.allowedSignersFile`, or it matches but has
+	/// expired
+	Unknown(String),
+}
+
+impl SignatureStatus {
+	/// single letter like `git log --format=%G?`
+	pub const fn marker(&self) -> char {
+		match self {
+			Self::Unsigned => 'N',
+			Self::Good(_) => 'G',
+			Self::Bad(_) => 'B',
+			Self::Unknown(_) => 'E',
+		}
+	}
+}
+
+/// verifies the `gpgsig` header of `commit_id` using `gpg`, `gpgsm`
+/// or `ssh-keygen` as configured in `gpg.*`
+pub fn verify_commit_signature(
+	repo_path: &RepoPath,
+	commit_id: CommitId,
+) -> Result<SignatureStatus> {
+	scope_time!("verify_commit_signature");
+
+	let repo = repo(repo_path)?;
+
+	let (signature, signed_data) =
+		match repo.extract_signature(&commit_id.into(), None) {
+			Ok(signature) => signature,
+			Err(e) if e.code() == ErrorCode::NotFound => {
+				return Ok(SignatureStatus::Unsigned);
+			}
+			Err(e) => return Err(e.into()),
+		};
+
+	let config = repo.config()?;
+
+	let status = if signature.starts_with(b"-----BEGIN SSH SIGNATURE")
+	{
+		verify_ssh(&config, &signature, &signed_data)
+	} else {
+		verify_gpg(&config, &signature, &signed_data)
+	};
+
+	Ok(status.unwrap_or_else(|e| {
+		SignatureStatus::Unknown(format!(
+			"failed to run verification: {e}"
+		))
+	}))
+}
+
+fn verify_gpg(
+	config: &Config,
+	signature: &[u8],
+	signed_data: &[u8],
+) -> std::io::Result<SignatureStatus> {
+	let format =
+		if signature.starts_with(b"-----BEGIN SIGNED MESSAGE") {
+			"x509"
+		} else {
+			"openpgp"
+		};
+
+	let program = config
+		.get_string(&format!("gpg.{format}.program"))
+		.or_else(|_| config.get_string("gpg.program"))
+		.unwrap_or_else(|_| {
+			(if format == "x509" { "gpgsm" } else { "gpg" })
+				.to_string()
+		});
+
+	let signature_file = temp_file(signature)?;
+
+	let output = run(
+		Command::new(&program)
+			.arg("--status-fd=1")
+			.arg("--verify")
+			.arg(signature_file.path())
+			.arg("-"),
+		signed_data,
+	)?;
+
+	Ok(parse_gpg_status(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// interprets the `--status-fd` output of `gpg --verify`, see
+/// `doc/DETAILS` in the gnupg sources
+fn parse_gpg_status(status: &str) -> SignatureStatus {
+	// `<KEYWORD> <keyid> <user id>`
+	let user = |args: &str| {
+		args.split_once(' ')
+			.map_or(args, |(_, user)| user)
+			.to_string()
+	};
+
+	for line in status.lines() {
+		let Some(line) = line.strip_prefix("[GNUPG:] ") else {
+			continue;
+		};
+
+		let (keyword, args) =
+			line.split_once(' ').unwrap_or((line, ""));
+
+		match keyword {
+			"GOODSIG" => return SignatureStatus::Good(user(args)),
+			"BADSIG" => {
+				return SignatureStatus::Bad(format!(
+					"bad signature from {}",
+					user(args)
+				));
+			}
+			"EXPSIG" => {
+				return SignatureStatus::Unknown(format!(
+					"expired signature from {}",
+					user(args)
+				));
+			}
+			"EXPKEYSIG" => {
+				return SignatureStatus::Unknown(format!(
+					"signature by expired key of {}",
+					user(args)
+				));
+			}
+			"REVKEYSIG" => {
+				return SignatureStatus::Bad(format!(
+					"signature by revoked key of {}",
+					user(args)
+				));
+			}
+			"ERRSIG" => {
+				let key = args.split(' ').next().unwrap_or_default();
+				return SignatureStatus::Unknown(format!(
+					"cannot check signature of key {key}"
+				));
+			}
+			_ => (),
+		}
+	}
+
+	SignatureStatus::Unknown(String::from(
+		"no signature status reported",
+	))
+}
+
+fn verify_ssh(
+	config: &Config,
+	signature: &[u8],
+	signed_data: &[u8],
+) -> std::io::Result<SignatureStatus> {
+	let program = config
+		.get_string("gpg.ssh.program")
+		.unwrap_or_else(|_| "ssh-keygen".to_string());
+
+	let Ok(allowed_signers) =
+		config.get_path("gpg.ssh.allowedSignersFile")
+	else {
+		return Ok(SignatureStatus::Unknown(String::from(
+			"gpg.ssh.allowedSignersFile is not configured",
+		)));
+	};
+
+	let signature_file = temp_file(signature)?;
+
+	let principals = run(
+		Command::new(&program)
+			.args(["-Y", "find-principals", "-f"])
+			.arg(&allowed_signers)
+			.arg("-s")
+			.arg(signature_file.path()),
+		&[],
+	)?;
+
+	let principals = String::from_utf8_lossy(&principals.stdout);
+	let principal = principals
+		.lines()
+		.map(str::trim)
+		.find(|principal| !principal.is_empty());
+
+	if let Some(principal) = principal {
+		let output = run(
+			Command::new(&program)
+				.args(["-Y", "verify", "-n", "git", "-f"])
+				.arg(&allowed_signers)
+				.arg("-I")
+				.arg(principal)
+				.arg("-s")
+				.arg(signature_file.path()),
+			signed_data,
+		)?;
+
+		return Ok(if output.status.success() {
+			SignatureStatus::Good(principal.to_string())
+		} else {
+			SignatureStatus::Bad(stderr_message(&output))
+		});
+	}
+
+	// the signer is not allowed, but the signature may still be
+	// intact
+	let output = run(
+		Command::new(&program)
+			.args(["-Y", "check-novalidate", "-n", "git", "-s"])
+			.arg(signature_file.path()),
+		signed_data,
+	)?;
+
+	Ok(if output.status.success() {
+		SignatureStatus::Unknown(format!(
+			"signer is not in {}",
+			allowed_signers.display()
+		))
+	} else {
+		SignatureStatus::Bad(stderr_message(&output))
+	})
+}
+
+fn temp_file(content: &[u8]) -> std::io::Result<NamedTempFile> {
+	let mut file = NamedTempFile::new()?;
+	file.write_all(content)?;
+	file.flush()?;
+	Ok(file)
+}
+
+fn run(cmd: &mut Command, stdin: &[u8]) -> std::io::Result<Output> {
+	log::trace!("verify command: {cmd:?}");
+
+	let mut child = cmd
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.spawn()?;
+
+	if let Some(mut input) = child.stdin.take() {
+		input.write_all(stdin)?;
+	}
+
+	child.wait_with_output()
+}
+
+fn stderr_message(output: &Output) -> String {
+	String::from_utf8_lossy(&output.stderr).trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		sign::{create_signed_commit, SignBuilder},
+		tests::{repo_init_empty, write_commit_file},
+	};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_parse_gpg_status() {
+		assert_eq!(
+			parse_gpg_status(
+				"[GNUPG:] NEWSIG\n[GNUPG:] GOODSIG 0123ABCD Jane Doe <jane@example.com>\n[GNUPG:] VALIDSIG 0123ABCD\n"
+			),
+			SignatureStatus::Good(String::from(
+				"Jane Doe <jane@example.com>"
+			))
+		);
+		assert_eq!(
+			parse_gpg_status("[GNUPG:] BADSIG 0123ABCD Jane Doe\n")
+				.marker(),
+			'B'
+		);
+		assert_eq!(
+			parse_gpg_status(
+				"[GNUPG:] ERRSIG 0123ABCD 1 8 00 1700000000 9\n[GNUPG:] NO_PUBKEY 0123ABCD\n"
+			),
+			SignatureStatus::Unknown(String::from(
+				"cannot check signature of key 0123ABCD"
+			))
+		);
+		assert_eq!(
+			parse_gpg_status("[GNUPG:] EXPSIG 0123ABCD Jane Doe\n"),
+			SignatureStatus::Unknown(String::from(
+				"expired signature from Jane Doe"
+			))
+		);
+		assert_eq!(parse_gpg_status("").marker(), 'E');
+	}
+
+	#[test]
+	fn test_unsigned() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let id = write_commit_file(&repo, "foo", "a", "commit");
+
+		assert_eq!(
+			verify_commit_signature(repo_path, id).unwrap(),
+			SignatureStatus::Unsigned
+		);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_ssh_verify() -> Result<()> {
+		let email = "gitui-ssh-test@example.com";
+		let dir = tempfile::tempdir()?;
+		let key_path = dir.path().join("id_ed25519");
+		let pub_path = dir.path().join("id_ed25519.pub");
+
+		let keygen = Command::new("ssh-keygen")
+			.args([
+				"-q", "-t", "ed25519", "-N", "", "-C", email, "-f",
+			])
+			.arg(&key_path)
+			.output()?;
+		assert!(keygen.status.success());
+
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+			config.set_str(
+				"user.signingKey",
+				&pub_path.to_string_lossy(),
+			)?;
+		}
+
+		let signer =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+		let sig = git2::Signature::now("gitui test", email)?;
+		let tree = {
+			let tree_id = repo.index()?.write_tree()?;
+			repo.find_tree(tree_id)?
+		};
+
+		let id: CommitId = create_signed_commit(
+			&repo,
+			&*signer,
+			&sig,
+			&sig,
+			"signed",
+			&tree,
+			&[],
+		)?
+		.into();
+
+		assert_eq!(
+			verify_commit_signature(repo_path, id)?.marker(),
+			'E'
+		);
+
+		let pub_key = std::fs::read_to_string(&pub_path)?;
+		let allowed = dir.path().join("allowed_signers");
+		std::fs::write(&allowed, format!("{email} {pub_key}"))?;
+		repo.config()?.set_str(
+			"gpg.ssh.allowedSignersFile",
+			&allowed.to_string_lossy(),
+		)?;
+
+		assert_eq!(
+			verify_commit_signature(repo_path, id)?,
+			SignatureStatus::Good(email.to_string())
+		);
+
+		// reuse the signature for different commit content
+		let (signature, _) =
+			repo.extract_signature(&id.into(), None)?;
+		let buffer = repo.commit_create_buffer(
+			&sig,
+			&sig,
+			"tampered",
+			&tree,
+			&[],
+		)?;
+		let tampered: CommitId = repo
+			.commit_signed(
+				std::str::from_utf8(&buffer).unwrap(),
+				std::str::from_utf8(&signature).unwrap(),
+				None,
+			)?
+			.into();
+
+		assert_eq!(
+			verify_commit_signature(repo_path, tampered)?.marker(),
+			'B'
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,569 @@
+//! reading and resolving the conflict regions of conflicted files
+
+use super::{
+	repository::repo,
+	utils::{stage_add_file, work_dir},
+	RepoPath,
+};
+use crate::error::{Error, Result};
+use git2::{AttrCheckFlags, Repository};
+use scopetime::scope_time;
+use std::{fs, path::Path};
+
+/// markers of unresolved regions, as git writes them
+const MARKER_OURS: u8 = b'<';
+const MARKER_BASE: u8 = b'|';
+const MARKER_THEIRS: u8 = b'=';
+const MARKER_END: u8 = b'>';
+
+/// marker size git uses unless the `conflict-marker-size` attribute
+/// is set for a file
+const DEFAULT_MARKER_SIZE: usize = 7;
+
+/// a line including its line ending
+pub type ConflictLine = Vec<u8>;
+
+/// lines of both sides of a conflict and of their common ancestor
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConflictRegion {
+	///
+	pub ours: Vec<ConflictLine>,
+	/// empty unless the file shows the ancestor (`diff3` and
+	/// `zdiff3` conflict styles)
+	pub base: Vec<ConflictLine>,
+	///
+	pub theirs: Vec<ConflictLine>,
+}
+
+/// how to resolve a `ConflictRegion`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+	///
+	Ours,
+	///
+	Theirs,
+	/// our lines followed by theirs
+	Both,
+}
+
+/// part of a conflicted file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictChunk {
+	/// lines both sides agree on
+	Merged(Vec<ConflictLine>),
+	///
+	Conflict(ConflictRegion),
+}
+
+/// a conflicted file split into merged and conflicting chunks by the
+/// conflict markers it contains
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileConflict {
+	///
+	pub chunks: Vec<ConflictChunk>,
+	marker_size: usize,
+}
+
+impl Default for FileConflict {
+	fn default() -> Self {
+		Self {
+			chunks: Vec::new(),
+			marker_size: DEFAULT_MARKER_SIZE,
+		}
+	}
+}
+
+impl FileConflict {
+	/// number of conflict regions
+	pub fn conflicts(&self) -> usize {
+		self.regions().count()
+	}
+
+	///
+	pub fn regions(&self) -> impl Iterator<Item = &ConflictRegion> {
+		self.chunks.iter().filter_map(|chunk| match chunk {
+			ConflictChunk::Merged(_) => None,
+			ConflictChunk::Conflict(region) => Some(region),
+		})
+	}
+
+	/// file content with `resolutions[i]` applied to the i-th
+	/// conflict region, unresolved regions keep their markers
+	pub fn content(
+		&self,
+		resolutions: &[Option<ConflictResolution>],
+	) -> Vec<u8> {
+		let mut res = Vec::new();
+		let mut conflict_idx = 0;
+
+		for chunk in &self.chunks {
+			match chunk {
+				ConflictChunk::Merged(lines) => {
+					res.extend(lines.iter().flatten());
+				}
+				ConflictChunk::Conflict(region) => {
+					let resolution = resolutions
+						.get(conflict_idx)
+						.copied()
+						.flatten();
+					conflict_idx += 1;
+
+					match resolution {
+						Some(ConflictResolution::Ours) => {
+							push_lines(&mut res, &region.ours);
+						}
+						Some(ConflictResolution::Theirs) => {
+							push_lines(&mut res, &region.theirs);
+						}
+						Some(ConflictResolution::Both) => {
+							push_lines(&mut res, &region.ours);
+							push_lines(&mut res, &region.theirs);
+						}
+						None => self.push_markers(&mut res, region),
+					}
+				}
+			}
+		}
+
+		res
+	}
+
+	fn push_markers(
+		&self,
+		res: &mut Vec<u8>,
+		region: &ConflictRegion,
+	) {
+		let marker = |res: &mut Vec<u8>, marker: u8, label: &[u8]| {
+			res.extend(std::iter::repeat_n(marker, self.marker_size));
+			res.extend_from_slice(label);
+			res.push(b'\n');
+		};
+
+		marker(res, MARKER_OURS, b" ours");
+		push_lines(res, &region.ours);
+		if !region.base.is_empty() {
+			marker(res, MARKER_BASE, b" base");
+			push_lines(res, &region.base);
+		}
+		marker(res, MARKER_THEIRS, b"");
+		push_lines(res, &region.theirs);
+		marker(res, MARKER_END, b" theirs");
+	}
+}
+
+fn push_lines(res: &mut Vec<u8>, lines: &[ConflictLine]) {
+	for line in lines {
+		res.extend_from_slice(line);
+		if !line.ends_with(b"\n") {
+			res.push(b'\n');
+		}
+	}
+}
+
+/// fails unless `path` is conflicted with content on both sides
+fn ensure_conflicted(repo: &Repository, path: &str) -> Result<()> {
+	let conflict = repo
+		.index()?
+		.conflicts()?
+		.filter_map(std::result::Result::ok)
+		.find(|conflict| {
+			[&conflict.our, &conflict.their, &conflict.ancestor]
+				.into_iter()
+				.flatten()
+				.any(|entry| entry.path == path.as_bytes())
+		})
+		.ok_or_else(|| {
+			Error::Generic(format!("'{path}' is not conflicted"))
+		})?;
+
+	if conflict.our.is_none() || conflict.their.is_none() {
+		return Err(Error::Generic(format!(
+			"'{path}' was deleted on one side, stage or remove it to resolve"
+		)));
+	}
+
+	Ok(())
+}
+
+/// size of the markers git wrote into `path`, see
+/// `conflict-marker-size` in `gitattributes(5)`
+fn marker_size(repo: &Repository, path: &str) -> usize {
+	repo.get_attr(
+		Path::new(path),
+		"conflict-marker-size",
+		AttrCheckFlags::FILE_THEN_INDEX,
+	)
+	.ok()
+	.flatten()
+	.and_then(|size| size.parse().ok())
+	.filter(|size| *size > 0)
+	.unwrap_or(DEFAULT_MARKER_SIZE)
+}
+
+/// whether `line` is a conflict marker of exactly `size` `marker`
+/// characters, optionally followed by a label
+fn is_marker(line: &[u8], marker: u8, size: usize) -> bool {
+	line.len() >= size
+		&& line[..size].iter().all(|c| *c == marker)
+		&& matches!(line.get(size), None | Some(b' ' | b'\n' | b'\r'))
+}
+
+/// splits the conflicted `path` in the working tree into its conflict
+/// regions, whichever conflict style, merge driver or rerere wrote it
+pub fn get_file_conflict(
+	repo_path: &RepoPath,
+	path: &str,
+) -> Result<FileConflict> {
+	scope_time!("get_file_conflict");
+
+	let repo = repo(repo_path)?;
+	ensure_conflicted(&repo, path)?;
+
+	let content = fs::read(work_dir(&repo)?.join(path))?;
+	let conflict = parse_conflict(&content, marker_size(&repo, path));
+
+	if conflict.conflicts() == 0 {
+		return Err(Error::Generic(format!(
+			"'{path}' has no conflict markers left, stage it to resolve"
+		)));
+	}
+
+	Ok(conflict)
+}
+
+/// splits `content` by conflict markers of `marker_size`, the
+/// ancestor section is optional
+fn parse_conflict(
+	content: &[u8],
+	marker_size: usize,
+) -> FileConflict {
+	enum Side {
+		Ours,
+		Base,
+		Theirs,
+	}
+
+	let is_marker =
+		|line: &[u8], marker| is_marker(line, marker, marker_size);
+
+	let mut chunks = Vec::new();
+	let mut merged = Vec::new();
+	let mut region: Option<(ConflictRegion, Side)> = None;
+	// lines of the current region as they are, in case it never ends
+	let mut region_lines = Vec::new();
+
+	for line in content.split_inclusive(|c| *c == b'\n') {
+		if region.is_some() || is_marker(line, MARKER_OURS) {
+			region_lines.push(line.to_vec());
+		}
+
+		match &mut region {
+			None if is_marker(line, MARKER_OURS) => {
+				if !merged.is_empty() {
+					chunks.push(ConflictChunk::Merged(
+						std::mem::take(&mut merged),
+					));
+				}
+				region =
+					Some((ConflictRegion::default(), Side::Ours));
+			}
+			None => merged.push(line.to_vec()),
+			Some((_, side @ Side::Ours))
+				if is_marker(line, MARKER_BASE) =>
+			{
+				*side = Side::Base;
+			}
+			Some((_, side @ (Side::Ours | Side::Base)))
+				if is_marker(line, MARKER_THEIRS) =>
+			{
+				*side = Side::Theirs;
+			}
+			Some((_, Side::Theirs))
+				if is_marker(line, MARKER_END) =>
+			{
+				if let Some((region, _)) = region.take() {
+					chunks.push(ConflictChunk::Conflict(region));
+				}
+				region_lines.clear();
+			}
+			Some((region, side)) => {
+				let lines = match side {
+					Side::Ours => &mut region.ours,
+					Side::Base => &mut region.base,
+					Side::Theirs => &mut region.theirs,
+				};
+				lines.push(line.to_vec());
+			}
+		}
+	}
+
+	// an unterminated region is no conflict
+	if region.is_some() {
+		merged.extend(region_lines);
+	}
+
+	if !merged.is_empty() {
+		chunks.push(ConflictChunk::Merged(merged));
+	}
+
+	FileConflict {
+		chunks,
+		marker_size,
+	}
+}
+
+/// writes `conflict` with `resolutions` applied to the conflicted
+/// `path` and marks it as resolved
+///
+/// fails if the working tree file changed since `conflict` was read
+pub fn resolve_conflict(
+	repo_path: &RepoPath,
+	path: &str,
+	conflict: &FileConflict,
+	resolutions: &[Option<ConflictResolution>],
+) -> Result<()> {
+	scope_time!("resolve_conflict");
+
+	write_conflict(repo_path, path, conflict, resolutions)?;
+
+	stage_add_file(repo_path, Path::new(path))
+}
+
+/// writes `conflict` with `resolutions` applied to the conflicted
+/// `path` without resolving it
+///
+/// fails if the working tree file changed since `conflict` was read
+pub fn write_conflict(
+	repo_path: &RepoPath,
+	path: &str,
+	conflict: &FileConflict,
+	resolutions: &[Option<ConflictResolution>],
+) -> Result<()> {
+	scope_time!("write_conflict");
+
+	if get_file_conflict(repo_path, path).ok().as_ref()
+		!= Some(conflict)
+	{
+		return Err(Error::Generic(format!(
+			"'{path}' was edited since, resolve it in the editor"
+		)));
+	}
+
+	let repo = repo(repo_path)?;
+	fs::write(
+		work_dir(&repo)?.join(path),
+		conflict.content(resolutions),
+	)?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		checkout_branch, create_branch, merge_branch,
+		status::{get_status, StatusItemType, StatusType},
+		tests::{repo_init, write_commit_file},
+	};
+	use git2::BranchType;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_parse_conflict() {
+		// `conflict-marker-size` set to tell content lines apart
+		let marker = |c: &str| c.repeat(32);
+		let conflict = parse_conflict(
+			format!(
+				"a\n{} ours\nb\n=======\n{} base\nx\n{}\nc\n{} theirs\nd\n",
+				marker("<"),
+				marker("|"),
+				marker("="),
+				marker(">"),
+			)
+			.as_bytes(),
+			32,
+		);
+
+		assert_eq!(
+			conflict.chunks,
+			vec![
+				ConflictChunk::Merged(vec![b"a\n".to_vec()]),
+				ConflictChunk::Conflict(ConflictRegion {
+					ours: vec![
+						b"b\n".to_vec(),
+						b"=======\n".to_vec()
+					],
+					base: vec![b"x\n".to_vec()],
+					theirs: vec![b"c\n".to_vec()],
+				}),
+				ConflictChunk::Merged(vec![b"d\n".to_vec()]),
+			]
+		);
+
+		assert_eq!(
+			conflict.content(&[Some(ConflictResolution::Both)]),
+			b"a\nb\n=======\nc\nd\n"
+		);
+		assert_eq!(
+			conflict.content(&[Some(ConflictResolution::Theirs)]),
+			b"a\nc\nd\n"
+		);
+		assert_eq!(
+			String::from_utf8(conflict.content(&[None])).unwrap(),
+			format!(
+				"a\n{} ours\nb\n=======\n{} base\nx\n{}\nc\n{} theirs\nd\n",
+				marker("<"),
+				marker("|"),
+				marker("="),
+				marker(">"),
+			)
+		);
+	}
+
+	#[test]
+	fn test_parse_merge_style() {
+		let conflict = parse_conflict(
+			b"a\n<<<<<<< HEAD\nb\n=======\nc\n>>>>>>> foo\n<<<<<<< x\n",
+			DEFAULT_MARKER_SIZE,
+		);
+
+		assert_eq!(
+			conflict.chunks,
+			vec![
+				ConflictChunk::Merged(vec![b"a\n".to_vec()]),
+				ConflictChunk::Conflict(ConflictRegion {
+					ours: vec![b"b\n".to_vec()],
+					base: Vec::new(),
+					theirs: vec![b"c\n".to_vec()],
+				}),
+				ConflictChunk::Merged(vec![b"<<<<<<< x\n".to_vec()]),
+			]
+		);
+		assert_eq!(
+			conflict.content(&[None]),
+			b"a\n<<<<<<< ours\nb\n=======\nc\n>>>>>>> theirs\n<<<<<<< x\n"
+		);
+	}
+
+	/// merges `foo` changing `b` into `foo` into `master` changing it
+	/// into `bar`
+	fn merge_conflict(repo: &Repository, repo_path: &RepoPath) {
+		write_commit_file(repo, "test.txt", "a\nb\nc\n", "commit1");
+		create_branch(repo_path, "foo").unwrap();
+		write_commit_file(repo, "test.txt", "a\nfoo\nc\n", "commit2");
+		checkout_branch(repo_path, "master").unwrap();
+		write_commit_file(repo, "test.txt", "a\nbar\nc\n", "commit3");
+
+		merge_branch(repo_path, "foo", BranchType::Local).unwrap();
+	}
+
+	#[test]
+	fn test_resolve_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		merge_conflict(&repo, repo_path);
+
+		let conflict =
+			get_file_conflict(repo_path, "test.txt").unwrap();
+		assert_eq!(conflict.conflicts(), 1);
+
+		let resolutions = [Some(ConflictResolution::Both)];
+		assert_eq!(
+			conflict.content(&resolutions),
+			b"a\nbar\nfoo\nc\n"
+		);
+		resolve_conflict(
+			repo_path,
+			"test.txt",
+			&conflict,
+			&resolutions,
+		)
+		.unwrap();
+
+		let status =
+			get_status(repo_path, StatusType::Stage, None).unwrap();
+		assert!(status
+			.iter()
+			.all(|item| item.status != StatusItemType::Conflicted));
+		assert!(get_file_conflict(repo_path, "test.txt").is_err());
+	}
+
+	#[test]
+	fn test_zdiff3_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		merge_conflict(&repo, repo_path);
+
+		// as written by git with `merge.conflictStyle=zdiff3` and both
+		// sides adding `same` in front of their change
+		fs::write(
+			root.join("test.txt"),
+			"a\nsame\n<<<<<<< HEAD\nbar\n||||||| 1a2b3c4\nb\n=======\nfoo\n>>>>>>> foo\nc\n",
+		)
+		.unwrap();
+
+		let conflict =
+			get_file_conflict(repo_path, "test.txt").unwrap();
+		assert_eq!(
+			conflict.regions().next(),
+			Some(&ConflictRegion {
+				ours: vec![b"bar\n".to_vec()],
+				base: vec![b"b\n".to_vec()],
+				theirs: vec![b"foo\n".to_vec()],
+			})
+		);
+
+		resolve_conflict(
+			repo_path,
+			"test.txt",
+			&conflict,
+			&[Some(ConflictResolution::Theirs)],
+		)
+		.unwrap();
+		assert_eq!(
+			fs::read(root.join("test.txt")).unwrap(),
+			b"a\nsame\nfoo\nc\n"
+		);
+	}
+
+	#[test]
+	fn test_keep_edited_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		merge_conflict(&repo, repo_path);
+
+		let conflict =
+			get_file_conflict(repo_path, "test.txt").unwrap();
+		write_conflict(repo_path, "test.txt", &conflict, &[])
+			.unwrap();
+		assert_eq!(
+			get_file_conflict(repo_path, "test.txt").unwrap(),
+			conflict
+		);
+
+		fs::write(root.join("test.txt"), "a\nmanual\nc\n").unwrap();
+
+		assert!(get_file_conflict(repo_path, "test.txt").is_err());
+		assert!(resolve_conflict(
+			repo_path,
+			"test.txt",
+			&conflict,
+			&[Some(ConflictResolution::Theirs)]
+		)
+		.is_err());
+		assert_eq!(
+			fs::read(root.join("test.txt")).unwrap(),
+			b"a\nmanual\nc\n"
+		);
+	}
+}
@@ -1,11 +1,12 @@
 //! sync git api for fetching a diff
 
 use super::{
+	binary_diff::LFS_POINTER_MAX_SIZE,
 	commit_files::{
 		get_commit_diff, get_compare_commits_diff, OldNew,
 	},
 	utils::{get_head_repo, work_dir},
-	CommitId, RepoPath,
+	word_diff, BinaryDiff, CommitId, RepoPath,
 };
 use crate::{
 	error::Error,
@@ -15,11 +16,15 @@ use crate::{
 };
 use easy_cast::Conv;
 use git2::{
-	Delta, Diff, DiffDelta, DiffFormat, DiffHunk, Patch, Repository,
+	Delta, Diff, DiffDelta, DiffFile, DiffFormat, DiffHunk, Patch,
+	Repository,
 };
 use scopetime::scope_time;
 use serde::{Deserialize, Serialize};
-use std::{cell::RefCell, fs, path::Path, rc::Rc};
+use std::{cell::RefCell, fs, ops::Range, path::Path, rc::Rc};
+
+/// binaries larger than this are summarized by their size only
+const BINARY_SUMMARY_MAX_SIZE: u64 = 16 * 1024 * 1024;
 
 /// type of diff of a single line
 #[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
@@ -33,16 +38,22 @@ pub enum DiffLineType {
 	Add,
 	/// line deleted
 	Delete,
+	/// marker following the deleted last line of a file that lacked
+	/// a trailing newline, the new file ends with one
+	AddEOFNL,
+	/// marker following the added last line of a file that lacks
+	/// a trailing newline, the old file ended with one
+	DeleteEOFNL,
 }
 
 impl From<git2::DiffLineType> for DiffLineType {
 	fn from(line_type: git2::DiffLineType) -> Self {
 		match line_type {
 			git2::DiffLineType::HunkHeader => Self::Header,
-			git2::DiffLineType::DeleteEOFNL
-			| git2::DiffLineType::Deletion => Self::Delete,
-			git2::DiffLineType::AddEOFNL
-			| git2::DiffLineType::Addition => Self::Add,
+			git2::DiffLineType::Deletion => Self::Delete,
+			git2::DiffLineType::Addition => Self::Add,
+			git2::DiffLineType::AddEOFNL => Self::AddEOFNL,
+			git2::DiffLineType::DeleteEOFNL => Self::DeleteEOFNL,
 			_ => Self::None,
 		}
 	}
@@ -57,6 +68,12 @@ pub struct DiffLine {
 	pub line_type: DiffLineType,
 	///
 	pub position: DiffLinePosition,
+	/// byte ranges of `content` that differ from the paired
+	/// deleted/added line, empty if the line was not paired
+	pub word_changes: Vec<Range<usize>>,
+	/// line ended with a carriage return, which is trimmed off
+	/// `content` together with the newline
+	pub carriage_return: bool,
 }
 
 ///
@@ -125,12 +142,59 @@ pub struct FileDiff {
 	pub sizes: (u64, u64),
 	/// size delta in bytes
 	pub size_delta: i64,
+	/// summary of binary files and lfs pointers, which have no hunks
+	pub binary: Option<BinaryDiff>,
+}
+
+/// algorithm used to match the lines of both sides of a diff
+#[derive(
+	Debug,
+	Default,
+	Hash,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Serialize,
+	Deserialize,
+)]
+pub enum DiffAlgorithm {
+	/// git's default
+	#[default]
+	Myers,
+	/// spends extra time to find the smallest possible diff
+	Minimal,
+	/// anchors the diff on lines that appear once on both sides
+	Patience,
+}
+
+/// detection of renamed and copied files
+#[derive(
+	Debug,
+	Default,
+	Hash,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Serialize,
+	Deserialize,
+)]
+pub enum RenameDetection {
+	///
+	#[default]
+	Off,
+	///
+	Renames,
+	/// renames and copies from files modified in the same change
+	Copies,
 }
 
 /// see <https://libgit2.org/libgit2/#HEAD/type/git_diff_options>
 #[derive(
 	Debug, Hash, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,
 )]
+#[serde(default)]
 pub struct DiffOptions {
 	/// see <https://libgit2.org/libgit2/#HEAD/type/git_diff_options>
 	pub ignore_whitespace: bool,
@@ -138,6 +202,17 @@ pub struct DiffOptions {
 	pub context: u32,
 	/// see <https://libgit2.org/libgit2/#HEAD/type/git_diff_options>
 	pub interhunk_lines: u32,
+	/// ignore changes in the amount of whitespace
+	pub ignore_whitespace_change: bool,
+	/// ignore changes that only add or delete empty lines
+	pub ignore_blank_lines: bool,
+	///
+	pub algorithm: DiffAlgorithm,
+	///
+	pub renames: RenameDetection,
+	/// similarity in percent above which two files count as renamed
+	/// or copied
+	pub similarity_threshold: u16,
 }
 
 impl Default for DiffOptions {
@@ -146,10 +221,53 @@ impl Default for DiffOptions {
 			ignore_whitespace: false,
 			context: 3,
 			interhunk_lines: 0,
+			ignore_whitespace_change: false,
+			ignore_blank_lines: false,
+			algorithm: DiffAlgorithm::default(),
+			renames: RenameDetection::default(),
+			similarity_threshold: 50,
 		}
 	}
 }
 
+impl DiffOptions {
+	pub(crate) fn apply(&self, opt: &mut git2::DiffOptions) {
+		opt.context_lines(self.context);
+		opt.ignore_whitespace(self.ignore_whitespace);
+		opt.ignore_whitespace_change(self.ignore_whitespace_change);
+		opt.ignore_blank_lines(self.ignore_blank_lines);
+		opt.interhunk_lines(self.interhunk_lines);
+		opt.minimal(self.algorithm == DiffAlgorithm::Minimal);
+		opt.patience(self.algorithm == DiffAlgorithm::Patience);
+	}
+
+	pub(crate) fn detects_renames(&self) -> bool {
+		self.renames != RenameDetection::Off
+	}
+
+	/// pairs deleted and added files of `diff` according to
+	/// `renames`
+	pub(crate) fn find_similar(&self, diff: &mut Diff) -> Result<()> {
+		if !self.detects_renames() {
+			return Ok(());
+		}
+
+		let mut opt = git2::DiffFindOptions::new();
+		opt.renames(true);
+		opt.rename_threshold(self.similarity_threshold);
+		if self.renames == RenameDetection::Copies {
+			opt.copies(true);
+			opt.copy_threshold(self.similarity_threshold);
+		}
+
+		diff.find_similar(Some(&mut opt))?;
+
+		Ok(())
+	}
+}
+
+/// diff of `p` to stage or reset hunks of, without rename detection
+/// as a renamed file can not be applied hunk by hunk
 pub(crate) fn get_diff_raw<'a>(
 	repo: &'a Repository,
 	p: &str,
@@ -159,16 +277,58 @@ pub(crate) fn get_diff_raw<'a>(
 ) -> Result<Diff<'a>> {
 	// scope_time!("get_diff_raw");
 
+	let options = options.map(|options| DiffOptions {
+		renames: RenameDetection::Off,
+		..options
+	});
+
+	get_diff_raw_paths(repo, &[p], stage, reverse, options)
+}
+
+/// `get_diff_raw` showing a renamed `p` compared to its old path
+fn get_diff_raw_renamed<'a>(
+	repo: &'a Repository,
+	p: &str,
+	stage: bool,
+	options: Option<DiffOptions>,
+) -> Result<Diff<'a>> {
+	if !options.is_some_and(|options| options.detects_renames()) {
+		return get_diff_raw(repo, p, stage, false, options);
+	}
+
+	// `find_similar` only pairs a renamed file with its old path
+	// if that is part of the diff as well
+	let renamed_diff =
+		get_diff_raw_paths(repo, &[], stage, false, options)?;
+	let mut pathspec = vec![p];
+	pathspec.extend(
+		delta_index(&renamed_diff, p)
+			.and_then(|idx| renamed_diff.get_delta(idx))
+			.filter(|delta| delta.status() == Delta::Renamed)
+			.and_then(|delta| delta.old_file().path())
+			.and_then(Path::to_str),
+	);
+
+	get_diff_raw_paths(repo, &pathspec, stage, false, options)
+}
+
+fn get_diff_raw_paths<'a>(
+	repo: &'a Repository,
+	pathspec: &[&str],
+	stage: bool,
+	reverse: bool,
+	options: Option<DiffOptions>,
+) -> Result<Diff<'a>> {
 	let mut opt = git2::DiffOptions::new();
 	if let Some(options) = options {
-		opt.context_lines(options.context);
-		opt.ignore_whitespace(options.ignore_whitespace);
-		opt.interhunk_lines(options.interhunk_lines);
+		options.apply(&mut opt);
+	}
+	for p in pathspec {
+		opt.pathspec(p);
 	}
-	opt.pathspec(p);
 	opt.reverse(reverse);
 
-	let diff = if stage {
+	let mut diff = if stage {
 		// diff against head
 		if let Ok(id) = get_head_repo(repo) {
 			let parent = repo.find_commit(id.into())?;
@@ -192,6 +352,10 @@ pub(crate) fn get_diff_raw<'a>(
 		repo.diff_index_to_workdir(None, Some(&mut opt))?
 	};
 
+	if let Some(options) = options {
+		options.find_similar(&mut diff)?;
+	}
+
 	Ok(diff)
 }
 
@@ -206,9 +370,9 @@ pub fn get_diff(
 
 	let repo = repo(repo_path)?;
 	let work_dir = work_dir(&repo)?;
-	let diff = get_diff_raw(&repo, p, stage, false, options)?;
+	let diff = get_diff_raw_renamed(&repo, p, stage, options)?;
 
-	raw_diff_to_file_diff(&diff, work_dir)
+	raw_diff_to_file_diff(&repo, &diff, None, work_dir)
 }
 
 /// returns diff of a specific file inside a commit
@@ -223,15 +387,16 @@ pub fn get_diff_commit(
 
 	let repo = repo(repo_path)?;
 	let work_dir = work_dir(&repo)?;
+	let (pathspec, path) = pathspec_or_path(p, options);
 	let diff = get_commit_diff(
 		&repo,
 		id,
-		Some(p),
+		pathspec,
 		options,
 		Some(&get_stashes(repo_path)?.into_iter().collect()),
 	)?;
 
-	raw_diff_to_file_diff(&diff, work_dir)
+	file_diff_of(&repo, &diff, path.as_deref(), work_dir)
 }
 
 /// get file changes of a diff between two commits
@@ -245,20 +410,123 @@ pub fn get_diff_commits(
 
 	let repo = repo(repo_path)?;
 	let work_dir = work_dir(&repo)?;
+	let (pathspec, path) = pathspec_or_path(p, options);
 	let diff =
-		get_compare_commits_diff(&repo, ids, Some(p), options)?;
+		get_compare_commits_diff(&repo, ids, pathspec, options)?;
+
+	file_diff_of(&repo, &diff, path.as_deref(), work_dir)
+}
+
+/// a diff limited to `p` sees a renamed file as added, so with rename
+/// detection the commit is diffed as a whole and `p` picked from it:
+/// returns either the pathspec or the path to pick
+fn pathspec_or_path(
+	p: String,
+	options: Option<DiffOptions>,
+) -> (Option<String>, Option<String>) {
+	if options.is_some_and(|options| options.detects_renames()) {
+		(None, Some(p))
+	} else {
+		(Some(p), None)
+	}
+}
+
+/// see `pathspec_or_path`
+fn file_diff_of(
+	repo: &Repository,
+	diff: &Diff,
+	path: Option<&str>,
+	work_dir: &Path,
+) -> Result<FileDiff> {
+	path.map_or_else(
+		|| raw_diff_to_file_diff(repo, diff, None, work_dir),
+		|path| {
+			delta_index(diff, path).map_or_else(
+				|| Ok(FileDiff::default()),
+				|idx| {
+					raw_diff_to_file_diff(
+						repo,
+						diff,
+						Some(idx),
+						work_dir,
+					)
+				},
+			)
+		},
+	)
+}
 
-	raw_diff_to_file_diff(&diff, work_dir)
+/// index of the delta of `p` in `diff`, renamed files are found by
+/// their new path
+fn delta_index(diff: &Diff, p: &str) -> Option<usize> {
+	diff.deltas().position(|delta| {
+		delta.new_file().path() == Some(Path::new(p))
+	})
+}
+
+/// returns the diff of every file changed in commit `id` compared to
+/// its first parent, or to `compare` if given, in the order of
+/// `get_commit_files`
+pub fn get_diff_files(
+	repo_path: &RepoPath,
+	id: CommitId,
+	compare: Option<CommitId>,
+	options: Option<DiffOptions>,
+) -> Result<Vec<(String, FileDiff)>> {
+	scope_time!("get_diff_files");
+
+	let repo = repo(repo_path)?;
+	let work_dir = work_dir(&repo)?;
+
+	// diffed once and split by delta, like `get_commit_files` does
+	let diff = if let Some(old) = compare {
+		get_compare_commits_diff(
+			&repo,
+			OldNew { old, new: id },
+			None,
+			options,
+		)?
+	} else {
+		get_commit_diff(
+			&repo,
+			id,
+			None,
+			options,
+			Some(&get_stashes(repo_path)?.into_iter().collect()),
+		)?
+	};
+
+	diff.deltas()
+		.enumerate()
+		.map(|(idx, delta)| {
+			let path = delta
+				.new_file()
+				.path()
+				.map(|p| p.to_str().unwrap_or("").to_string())
+				.unwrap_or_default();
+			let file_diff = raw_diff_to_file_diff(
+				&repo,
+				&diff,
+				Some(idx),
+				work_dir,
+			)?;
+
+			Ok((path, file_diff))
+		})
+		.collect()
 }
 
 ///
 //TODO: refactor into helper type with the inline closures as dedicated functions
 #[allow(clippy::too_many_lines)]
 fn raw_diff_to_file_diff(
+	repo: &Repository,
 	diff: &Diff,
+	delta_idx: Option<usize>,
 	work_dir: &Path,
 ) -> Result<FileDiff> {
 	let res = Rc::new(RefCell::new(FileDiff::default()));
+	let mut untracked_binary = false;
 	{
 		let mut current_lines = Vec::new();
 		let mut current_hunk: Option<HunkHeader> = None;
@@ -266,12 +534,15 @@ fn raw_diff_to_file_diff(
 		let res_cell = Rc::clone(&res);
 		let adder = move |header: &HunkHeader,
 		                  lines: &Vec<DiffLine>| {
+			let mut lines = lines.clone();
+			word_diff::highlight_hunk(&mut lines);
+
 			let mut res = res_cell.borrow_mut();
+			res.lines += lines.len();
 			res.hunks.push(Hunk {
 				header_hash: hash(header),
-				lines: lines.clone(),
+				lines,
 			});
-			res.lines += lines.len();
 		};
 
 		let res_cell = Rc::clone(&res);
@@ -302,21 +573,26 @@ fn raw_diff_to_file_diff(
 					}
 				}
 
+				let content = String::from_utf8_lossy(line.content());
 				let diff_line = DiffLine {
 					position: DiffLinePosition::from(&line),
-					content: String::from_utf8_lossy(line.content())
-						//Note: trim await trailing newline characters
-						.trim_matches(is_newline)
-						.into(),
+					//Note: trim await trailing newline characters
+					content: content.trim_matches(is_newline).into(),
 					line_type: line.origin_value().into(),
+					word_changes: Vec::new(),
+					carriage_return: content
+						.trim_end_matches('\n')
+						.ends_with('\r'),
 				};
 
 				current_lines.push(diff_line);
 			}
 		};
 
-		let new_file_diff = if diff.deltas().len() == 1 {
-			if let Some(delta) = diff.deltas().next() {
+		let single_delta = delta_idx
+			.or_else(|| (diff.deltas().len() == 1).then_some(0));
+		let new_file_diff = if let Some(idx) = single_delta {
+			if let Some(delta) = diff.get_delta(idx) {
 				if delta.status() == Delta::Untracked {
 					let relative_path =
 						delta.new_file().path().ok_or_else(|| {
@@ -347,6 +623,8 @@ fn raw_diff_to_file_diff(
 								true
 							},
 						)?;
+						untracked_binary =
+							patch.delta().flags().is_binary();
 
 						true
 					} else {
@@ -363,13 +641,25 @@ fn raw_diff_to_file_diff(
 		};
 
 		if !new_file_diff {
-			diff.print(
-				DiffFormat::Patch,
-				move |delta, hunk, line: git2::DiffLine| {
-					put(delta, hunk, line);
-					true
-				},
-			)?;
+			if let Some(idx) = delta_idx {
+				if let Some(mut patch) = Patch::from_diff(diff, idx)?
+				{
+					patch.print(
+						&mut |delta, hunk, line: git2::DiffLine| {
+							put(delta, hunk, line);
+							true
+						},
+					)?;
+				}
+			} else {
+				diff.print(
+					DiffFormat::Patch,
+					move |delta, hunk, line: git2::DiffLine| {
+						put(delta, hunk, line);
+						true
+					},
+				)?;
+			}
 		}
 
 		if !current_lines.is_empty() {
@@ -386,9 +676,69 @@ fn raw_diff_to_file_diff(
 			res.borrow_mut().untracked = true;
 		}
 	}
-	let res = Rc::try_unwrap(res)
-		.map_err(|_| Error::Generic("rc unwrap error".to_owned()))?;
-	Ok(res.into_inner())
+	let mut res = Rc::try_unwrap(res)
+		.map_err(|_| Error::Generic("rc unwrap error".to_owned()))?
+		.into_inner();
+
+	res.binary = binary_diff(
+		repo,
+		diff.get_delta(delta_idx.unwrap_or_default()),
+		work_dir,
+		untracked_binary,
+	);
+	if res.binary.is_some() {
+		// lfs pointers are shown by their summary only
+		res.hunks.clear();
+		res.lines = 0;
+	}
+
+	Ok(res)
+}
+
+/// loads both sides of the file in `delta` to summarize them unless
+/// it is text other than lfs pointers, `binary` is set when the
+/// content was found to be binary outside of `delta`
+fn binary_diff(
+	repo: &Repository,
+	delta: Option<DiffDelta>,
+	work_dir: &Path,
+	binary: bool,
+) -> Option<BinaryDiff> {
+	let delta = delta?;
+	let is_text = !(binary
+		|| delta.flags().is_binary()
+		|| delta.old_file().is_binary()
+		|| delta.new_file().is_binary());
+	let max_size = if is_text {
+		LFS_POINTER_MAX_SIZE
+	} else {
+		BINARY_SUMMARY_MAX_SIZE
+	};
+
+	let files = [delta.old_file(), delta.new_file()];
+	if files
+		.iter()
+		.any(|file| file.exists() && file.size() > max_size)
+	{
+		return None;
+	}
+
+	let load = |file: &DiffFile| {
+		if !file.exists() {
+			return None;
+		}
+
+		repo.find_blob(file.id())
+			.map(|blob| blob.content().to_vec())
+			.ok()
+			.or_else(|| fs::read(work_dir.join(file.path()?)).ok())
+	};
+
+	BinaryDiff::new(
+		load(&files[0]).as_deref(),
+		load(&files[1]).as_deref(),
+		is_text,
+	)
 }
 
 const fn is_newline(c: char) -> bool {
@@ -415,14 +765,21 @@ fn new_file_content(path: &Path) -> Option<Vec<u8>> {
 
 #[cfg(test)]
 mod tests {
-	use super::{get_diff, get_diff_commit};
+	use super::{
+		get_diff, get_diff_commit, get_diff_files, DiffLineType,
+		DiffOptions, RenameDetection,
+	};
 	use crate::{
 		error::Result,
 		sync::{
-			commit, stage_add_file,
+			commit, get_commit_files, stage_add_file,
+			stage_addremoved,
 			status::{get_status, StatusType},
-			tests::{get_statuses, repo_init, repo_init_empty},
-			RepoPath,
+			tests::{
+				get_statuses, repo_init, repo_init_empty,
+				write_commit_file,
+			},
+			BlobSummary, RepoPath,
 		},
 	};
 	use std::{
@@ -487,6 +844,35 @@ mod tests {
 		assert_eq!(diff.hunks.len(), 1);
 	}
 
+	#[test]
+	fn test_ignore_blank_lines() -> Result<()> {
+		let file_path = Path::new("foo.txt");
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(root.join(file_path))?.write_all(b"a\nb\n")?;
+		stage_add_file(repo_path, file_path)?;
+		commit(repo_path, "c1")?;
+
+		File::create(root.join(file_path))?.write_all(b"a\n\nb\n")?;
+
+		let diff = get_diff(repo_path, "foo.txt", false, None)?;
+		assert_eq!(diff.hunks.len(), 1);
+
+		let options = DiffOptions {
+			ignore_blank_lines: true,
+			..DiffOptions::default()
+		};
+		let diff =
+			get_diff(repo_path, "foo.txt", false, Some(options))?;
+		assert_eq!(diff.hunks.len(), 0);
+		assert_eq!(diff.binary, None);
+
+		Ok(())
+	}
+
 	static HUNK_A: &str = r"
 1   start
 2
@@ -555,6 +941,68 @@ mod tests {
 		assert_eq!(res.hunks.len(), 2);
 	}
 
+	#[test]
+	fn test_word_changes() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let file_path = root.join("bar.txt");
+		fs::write(&file_path, "timeout = 30\n").unwrap();
+		stage_add_file(repo_path, Path::new("bar.txt")).unwrap();
+
+		fs::write(&file_path, "timeout = 300\n").unwrap();
+
+		let res =
+			get_diff(repo_path, "bar.txt", false, None).unwrap();
+		let lines = &res.hunks[0].lines;
+
+		assert_eq!(&*lines[1].content, "timeout = 30");
+		assert_eq!(lines[1].word_changes, vec![10..12]);
+		assert_eq!(&*lines[2].content, "timeout = 300");
+		assert_eq!(lines[2].word_changes, vec![10..13]);
+	}
+
+	#[test]
+	fn test_line_endings() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let file_path = root.join("bar.txt");
+		fs::write(&file_path, "a\nb\n").unwrap();
+		stage_add_file(repo_path, Path::new("bar.txt")).unwrap();
+
+		fs::write(&file_path, "a\r\nb").unwrap();
+
+		let res =
+			get_diff(repo_path, "bar.txt", false, None).unwrap();
+		let lines: Vec<_> = res.hunks[0]
+			.lines
+			.iter()
+			.map(|line| {
+				(line.line_type, &*line.content, line.carriage_return)
+			})
+			.collect();
+
+		assert_eq!(
+			lines[1..],
+			[
+				(DiffLineType::Delete, "a", false),
+				(DiffLineType::Delete, "b", false),
+				(DiffLineType::Add, "a", true),
+				(DiffLineType::Add, "b", false),
+				(
+					DiffLineType::DeleteEOFNL,
+					"\\ No newline at end of file",
+					false
+				),
+			]
+		);
+	}
+
 	#[test]
 	fn test_diff_newfile_in_sub_dir_current_dir() {
 		let file_path = Path::new("foo/foo.txt");
@@ -636,6 +1084,57 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_binary_summary() -> Result<()> {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		fs::write(root.join("bar"), b"\x00\x01\x02")?;
+		stage_add_file(repo_path, Path::new("bar"))?;
+		fs::write(root.join("bar"), b"\x00\x01\x03\x04")?;
+
+		let diff = get_diff(repo_path, "bar", false, None)?;
+		let hex = diff.binary.unwrap().hex.unwrap();
+		assert_eq!(hex.first_change, 2);
+		assert_eq!(hex.old, b"\x00\x01\x02");
+		assert_eq!(hex.new, b"\x00\x01\x03\x04");
+
+		fs::write(
+			root.join("image.psd"),
+			"version https://git-lfs.github.com/spec/v1\noid sha256:abc\nsize 42\n",
+		)?;
+
+		let diff = get_diff(repo_path, "image.psd", false, None)?;
+		assert!(diff.hunks.is_empty());
+		assert_eq!(
+			diff.binary.unwrap().new,
+			Some(BlobSummary::LfsPointer {
+				oid: String::from("sha256:abc"),
+				size: 42
+			})
+		);
+
+		let diff = get_diff(repo_path, "bar", true, None)?;
+		assert_eq!(diff.binary.unwrap().old, None);
+
+		fs::write(root.join("bar"), b"\x00\x01\x03\x04\x05")?;
+		let diff = get_diff(repo_path, "bar", false, None)?;
+		assert!(diff.binary.unwrap().hex.is_some());
+
+		fs::write(root.join("empty"), b"")?;
+		let diff = get_diff(repo_path, "empty", false, None)?;
+		assert_eq!(diff.binary, None);
+
+		stage_add_file(repo_path, Path::new("empty"))?;
+		let diff = get_diff(repo_path, "empty", true, None)?;
+		assert!(diff.hunks.is_empty());
+		assert_eq!(diff.binary, None);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_diff_delta_size_commit() -> Result<()> {
 		let file_path = Path::new("bar");
@@ -664,6 +1163,96 @@ mod tests {
 		assert_eq!(diff.sizes, (1, 2));
 		assert_eq!(diff.size_delta, 1);
 
+		Ok(())
+	}
+	#[test]
+	fn test_diff_files() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let first =
+			write_commit_file(&repo, "a.txt", "a\n", "commit1");
+		write_commit_file(&repo, "b.txt", "b\n", "commit2");
+		let last =
+			write_commit_file(&repo, "a.txt", "a\nc\n", "commit3");
+
+		let files =
+			get_diff_files(repo_path, last, None, None).unwrap();
+		assert_eq!(files.len(), 1);
+		assert_eq!(files[0].0, "a.txt");
+		assert_eq!(files[0].1.hunks[0].lines.len(), 3);
+
+		let files =
+			get_diff_files(repo_path, last, Some(first), None)
+				.unwrap();
+		let paths: Vec<_> =
+			files.iter().map(|(path, _)| path.as_str()).collect();
+		assert_eq!(paths, ["a.txt", "b.txt"]);
+		assert_eq!(&*files[0].1.hunks[0].lines[2].content, "c");
+		assert_eq!(&*files[1].1.hunks[0].lines[1].content, "b");
+	}
+
+	#[test]
+	fn test_diff_renamed_file() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(
+			&repo,
+			"a.txt",
+			"1\n2\n3\n4\n5\n6\n7\n8\n",
+			"c1",
+		);
+
+		fs::remove_file(root.join("a.txt"))?;
+		File::create(root.join("b.txt"))?
+			.write_all(b"1\n2\n3\n4\n5\n6\n7\neight\n")?;
+		stage_addremoved(repo_path, Path::new("a.txt"))?;
+		stage_add_file(repo_path, Path::new("b.txt"))?;
+
+		let options = DiffOptions {
+			renames: RenameDetection::Renames,
+			..DiffOptions::default()
+		};
+
+		// the rename is staged
+		let diff = get_diff(repo_path, "b.txt", true, None)?;
+		assert_eq!(diff.hunks[0].lines.len(), 9);
+		let diff = get_diff(repo_path, "b.txt", true, Some(options))?;
+		assert_eq!(diff.hunks[0].lines.len(), 6);
+
+		let id = commit(repo_path, "c2")?;
+
+		let files = get_commit_files(repo_path, id, None, None)?;
+		assert_eq!(files.len(), 2);
+		let files =
+			get_commit_files(repo_path, id, None, Some(options))?;
+		assert_eq!(files.len(), 1);
+		assert_eq!(files[0].path, "b.txt");
+
+		let diff =
+			get_diff_commit(repo_path, id, "b.txt".into(), None)?;
+		assert_eq!(diff.hunks[0].lines.len(), 9);
+		let diff = get_diff_commit(
+			repo_path,
+			id,
+			"b.txt".into(),
+			Some(options),
+		)?;
+		assert_eq!(diff.hunks.len(), 1);
+		assert_eq!(diff.hunks[0].lines.len(), 6);
+		assert_eq!(&*diff.hunks[0].lines[5].content, "eight");
+
+		let files =
+			get_diff_files(repo_path, id, None, Some(options))?;
+		assert_eq!(files.len(), 1);
+		assert_eq!(files[0].0, "b.txt");
+		assert_eq!(files[0].1.hunks[0].lines.len(), 6);
+
 		Ok(())
 	}
 }
@@ -7,8 +7,9 @@ use crate::{
 	hash,
 	sync::repository::repo,
 };
-use git2::{ApplyLocation, ApplyOptions, Diff};
+use git2::{ApplyLocation, ApplyOptions, Diff, Patch};
 use scopetime::scope_time;
+use std::path::Path;
 
 ///
 pub fn stage_hunk(
@@ -148,12 +149,201 @@ pub fn unstage_hunk(
 	Ok(count == 1)
 }
 
+/// the unstaged hunk `hunk_hash` of `file_path` as a patch that can be
+/// edited and passed to `stage_edited_hunk`
+pub fn hunk_patch(
+	repo_path: &RepoPath,
+	file_path: &str,
+	hunk_hash: u64,
+	options: Option<DiffOptions>,
+) -> Result<String> {
+	scope_time!("hunk_patch");
+
+	let repo = repo(repo_path)?;
+
+	let diff = get_diff_raw(&repo, file_path, false, false, options)?;
+
+	let hunk_index =
+		find_hunk_index(&diff, hunk_hash).ok_or_else(|| {
+			Error::Generic("hunk not found".to_string())
+		})?;
+
+	let mut patch = Patch::from_diff(&diff, 0)?.ok_or_else(|| {
+		Error::Generic("no patch found".to_string())
+	})?;
+	let patch = String::from_utf8(patch.to_buf()?.to_vec())?;
+
+	// keep the file header and only the selected hunk
+	let mut res = String::new();
+	let mut hunk = None;
+	for line in patch.split_inclusive('\n') {
+		if line.starts_with("@@") {
+			hunk = Some(hunk.map_or(0, |idx| idx + 1));
+		}
+
+		if hunk.is_none() || hunk == Some(hunk_index) {
+			res.push_str(line);
+		}
+	}
+
+	Ok(res)
+}
+
+/// applies a patch created by `hunk_patch` and edited by the user to
+/// the index, the line counts of the hunk headers are recalculated
+/// and lines starting with `#` are ignored
+pub fn stage_edited_hunk(
+	repo_path: &RepoPath,
+	file_path: &str,
+	patch: &str,
+) -> Result<()> {
+	scope_time!("stage_edited_hunk");
+
+	let patch = recount_patch(patch)?;
+
+	let diff = Diff::from_buffer(patch.as_bytes()).map_err(|e| {
+		Error::Generic(format!(
+			"edited hunk is not a valid patch: {e}"
+		))
+	})?;
+
+	let touches_only_file = diff.deltas().all(|delta| {
+		delta.new_file().path() == Some(Path::new(file_path))
+			&& delta.old_file().path() == Some(Path::new(file_path))
+	});
+	if diff.deltas().len() != 1 || !touches_only_file {
+		return Err(Error::Generic(format!(
+			"edited hunk must only change '{file_path}'"
+		)));
+	}
+
+	let repo = repo(repo_path)?;
+
+	repo.apply(&diff, ApplyLocation::Index, None).map_err(|e| {
+		Error::Generic(format!("edited hunk does not apply: {e}"))
+	})?;
+
+	Ok(())
+}
+
+/// drops comments and rewrites the hunk headers to match the lines
+/// that follow them, lines keep their line endings so that `\r` of
+/// files with CRLF endings survives
+fn recount_patch(patch: &str) -> Result<String> {
+	struct Hunk {
+		old_start: u32,
+		new_start: u32,
+		section: String,
+		lines: Vec<String>,
+	}
+
+	let mut header = Vec::new();
+	let mut hunks: Vec<Hunk> = Vec::new();
+
+	for (idx, line) in patch.split_inclusive('\n').enumerate() {
+		if line.starts_with('#') {
+			continue;
+		}
+
+		if line.starts_with("@@") {
+			let line = line.trim_end_matches(['\n', '\r']);
+			let (old_start, new_start, section) =
+				parse_hunk_header(line).ok_or_else(|| {
+					Error::Generic(format!(
+						"invalid hunk header in line {}: {line}",
+						idx + 1
+					))
+				})?;
+
+			hunks.push(Hunk {
+				old_start,
+				new_start,
+				section: section.to_string(),
+				lines: Vec::new(),
+			});
+		} else if let Some(hunk) = hunks.last_mut() {
+			let line = match line.chars().next() {
+				// editors tend to strip the space of empty context lines
+				Some('\n' | '\r') => format!(" {line}"),
+				Some(' ' | '+' | '-' | '\\') => line.to_string(),
+				_ => {
+					return Err(Error::Generic(format!(
+						"invalid line {} in edited hunk: {}",
+						idx + 1,
+						line.trim_end()
+					)));
+				}
+			};
+
+			hunk.lines.push(line);
+		} else {
+			header.push(line);
+		}
+	}
+
+	let has_changes = hunks.iter().any(|hunk| {
+		hunk.lines.iter().any(|line| line.starts_with(['+', '-']))
+	});
+	if !has_changes {
+		return Err(Error::Generic(
+			"edited hunk contains no changes".to_string(),
+		));
+	}
+
+	let mut res: Vec<String> =
+		header.into_iter().map(String::from).collect();
+
+	for hunk in hunks {
+		let count = |prefix: [char; 2]| {
+			hunk.lines
+				.iter()
+				.filter(|l| l.starts_with(prefix))
+				.count()
+		};
+
+		res.push(format!(
+			"@@ -{},{} +{},{} @@{}\n",
+			hunk.old_start,
+			count([' ', '-']),
+			hunk.new_start,
+			count([' ', '+']),
+			hunk.section
+		));
+		res.extend(hunk.lines);
+	}
+
+	let mut res = res.concat();
+	if !res.ends_with('\n') {
+		res.push('\n');
+	}
+
+	Ok(res)
+}
+
+/// start lines and the trailing section of `@@ -a,b +c,d @@ section`
+fn parse_hunk_header(line: &str) -> Option<(u32, u32, &str)> {
+	let rest = line.strip_prefix("@@ -")?;
+	let (ranges, section) = rest.split_once(" @@")?;
+	let (old, new) = ranges.split_once(" +")?;
+
+	let start = |range: &str| {
+		range.split(',').next().and_then(|start| start.parse().ok())
+	};
+
+	Some((start(old)?, start(new)?, section))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::{
 		error::Result,
-		sync::{diff::get_diff, tests::repo_init_empty},
+		sync::{
+			diff::{get_diff, RenameDetection},
+			stage_add_file, stage_addremoved,
+			tests::{repo_init, repo_init_empty, write_commit_file},
+			utils::repo_write_file,
+		},
 	};
 	use std::{
 		fs::{self, File},
@@ -191,4 +381,184 @@ mod tests {
 
 		Ok(())
 	}
+
+	fn edit_hunk_patch(
+		repo: &git2::Repository,
+		repo_path: &RepoPath,
+	) -> Result<String> {
+		edit_hunk_patch_of(
+			repo,
+			repo_path,
+			"a\nb\nc\n",
+			"a\nB\nc\nd\n",
+		)
+	}
+
+	fn edit_hunk_patch_of(
+		repo: &git2::Repository,
+		repo_path: &RepoPath,
+		committed: &str,
+		changed: &str,
+	) -> Result<String> {
+		write_commit_file(repo, "foo.txt", committed, "c1");
+		repo_write_file(repo, "foo.txt", changed)?;
+
+		let diff = get_diff(repo_path, "foo.txt", false, None)?;
+
+		hunk_patch(
+			repo_path,
+			"foo.txt",
+			diff.hunks[0].header_hash,
+			None,
+		)
+	}
+
+	fn staged_content(repo: &git2::Repository) -> String {
+		let mut index = repo.index().unwrap();
+		index.read(true).unwrap();
+		let entry = index.get_path(Path::new("foo.txt"), 0).unwrap();
+		let blob = repo.find_blob(entry.id).unwrap();
+		String::from_utf8(blob.content().to_vec()).unwrap()
+	}
+
+	#[test]
+	fn test_unstage_hunk_of_renamed_file() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let lines: Vec<String> =
+			(1..=40).map(|i| format!("{i}\n")).collect();
+		write_commit_file(&repo, "a.txt", &lines.concat(), "c1");
+
+		let mut changed = lines;
+		changed[4] = String::from("five\n");
+		changed[34] = String::from("thirty-five\n");
+		fs::remove_file(root.join("a.txt"))?;
+		repo_write_file(&repo, "b.txt", &changed.concat())?;
+		stage_addremoved(repo_path, Path::new("a.txt"))?;
+		stage_add_file(repo_path, Path::new("b.txt"))?;
+
+		let options = DiffOptions {
+			renames: RenameDetection::Renames,
+			..DiffOptions::default()
+		};
+
+		// hunks of the rename can not be unstaged one by one
+		let diff = get_diff(repo_path, "b.txt", true, Some(options))?;
+		assert_eq!(diff.hunks.len(), 2);
+		assert!(unstage_hunk(
+			repo_path,
+			"b.txt",
+			diff.hunks[0].header_hash,
+			Some(options)
+		)
+		.is_err());
+
+		let mut index = repo.index()?;
+		index.read(true)?;
+		let entry = index.get_path(Path::new("b.txt"), 0).unwrap();
+		assert_eq!(
+			repo.find_blob(entry.id)?.content(),
+			changed.concat().as_bytes()
+		);
+		assert!(index.get_path(Path::new("a.txt"), 0).is_none());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stage_edited_hunk() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let patch = edit_hunk_patch(&repo, repo_path)?;
+
+		assert!(patch.starts_with("diff --git a/foo.txt b/foo.txt"));
+		assert!(
+			patch.contains("@@ -1,3 +1,4 @@\n a\n-b\n+B\n c\n+d\n")
+		);
+
+		// drop the added line and stage a different replacement
+		let edited = patch
+			.replace("+B\n", "+X\n# comment\n")
+			.replace("+d\n", "");
+		stage_edited_hunk(repo_path, "foo.txt", &edited)?;
+
+		assert_eq!(staged_content(&repo), "a\nX\nc\n");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stage_edited_hunk_crlf() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let patch = edit_hunk_patch_of(
+			&repo,
+			repo_path,
+			"a\r\nb\r\nc\r\n",
+			"a\r\nB\r\nc\r\nd\r\n",
+		)?;
+
+		assert!(patch.contains(
+			"@@ -1,3 +1,4 @@\n a\r\n-b\r\n+B\r\n c\r\n+d\r\n"
+		));
+
+		let edited = patch
+			.replace("+B\r\n", "+X\r\n# comment\n")
+			.replace("+d\r\n", "");
+		stage_edited_hunk(repo_path, "foo.txt", &edited)?;
+
+		assert_eq!(staged_content(&repo), "a\r\nX\r\nc\r\n");
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_stage_edited_hunk_invalid() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let patch = edit_hunk_patch(&repo, repo_path)?;
+
+		let err = stage_edited_hunk(
+			repo_path,
+			"foo.txt",
+			&patch.replace("+d\n", "d\n"),
+		)
+		.unwrap_err();
+		assert!(err.to_string().contains("invalid line 10"));
+
+		let err = stage_edited_hunk(
+			repo_path,
+			"foo.txt",
+			&patch
+				.replace("-b\n", " b\n")
+				.replace("+B\n", "")
+				.replace("+d\n", ""),
+		)
+		.unwrap_err();
+		assert!(err.to_string().contains("no changes"));
+
+		let err = stage_edited_hunk(
+			repo_path,
+			"foo.txt",
+			&patch.replace(" c\n", " x\n"),
+		)
+		.unwrap_err();
+		assert!(err.to_string().contains("does not apply"));
+
+		assert_eq!(staged_content(&repo), "a\nb\nc\n");
+
+		Ok(())
+	}
 }
@@ -1,12 +1,35 @@
-use super::{CommitId, SharedCommitFilterFn};
+use super::{
+	CommitGraph, CommitId, GraphRow, LogTips, SharedCommitFilterFn,
+};
 use crate::error::Result;
 use git2::{Commit, Oid, Repository};
 use gix::revision::Walk;
+use serde::{Deserialize, Serialize};
 use std::{
 	cmp::Ordering,
 	collections::{BinaryHeap, HashSet},
 };
 
+/// which parts of the history the log walks
+#[derive(
+	Debug,
+	Default,
+	Hash,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Serialize,
+	Deserialize,
+)]
+pub struct LogWalkerOptions {
+	/// only follow the first parent of merges, like
+	/// `git log --first-parent`
+	pub first_parent: bool,
+	/// leave out merge commits, like `git log --no-merges`
+	pub hide_merges: bool,
+}
+
 struct TimeOrderedCommit<'a>(Commit<'a>);
 
 impl Eq for TimeOrderedCommit<'_> {}
@@ -33,9 +56,12 @@ impl Ord for TimeOrderedCommit<'_> {
 pub struct LogWalker<'a> {
 	commits: BinaryHeap<TimeOrderedCommit<'a>>,
 	visited: HashSet<Oid>,
+	/// reachable from an excluded tip
+	hidden: HashSet<Oid>,
 	limit: usize,
 	repo: &'a Repository,
 	filter: Option<SharedCommitFilterFn>,
+	options: LogWalkerOptions,
 }
 
 impl<'a> LogWalker<'a> {
@@ -43,16 +69,46 @@ impl<'a> LogWalker<'a> {
 	pub fn new(repo: &'a Repository, limit: usize) -> Result<Self> {
 		let c = repo.head()?.peel_to_commit()?;
 
-		let mut commits = BinaryHeap::with_capacity(10);
-		commits.push(TimeOrderedCommit(c));
+		Self::with_tips(
+			repo,
+			limit,
+			&LogTips {
+				include: vec![c.id().into()],
+				exclude: Vec::new(),
+			},
+			LogWalkerOptions::default(),
+		)
+	}
 
-		Ok(Self {
-			commits,
+	/// walks the ancestry of `tips.include` leaving out everything
+	/// reachable from `tips.exclude`
+	pub fn with_tips(
+		repo: &'a Repository,
+		limit: usize,
+		tips: &LogTips,
+		options: LogWalkerOptions,
+	) -> Result<Self> {
+		let mut walker = Self {
+			commits: BinaryHeap::with_capacity(10),
 			limit,
 			visited: HashSet::with_capacity(1000),
+			hidden: HashSet::new(),
 			repo,
 			filter: None,
-		})
+			options,
+		};
+
+		for id in &tips.exclude {
+			let c = repo.find_commit((*id).into())?;
+			walker.hidden.insert(c.id());
+			walker.visit(c);
+		}
+
+		for id in &tips.include {
+			walker.visit(repo.find_commit((*id).into())?);
+		}
+
+		Ok(walker)
 	}
 
 	///
@@ -74,10 +130,37 @@ impl<'a> LogWalker<'a> {
 		let mut count = 0_usize;
 
 		while let Some(c) = self.commits.pop() {
-			for p in c.0.parents() {
+			let hidden = self.hidden.contains(&c.0.id());
+
+			let parents = if self.options.first_parent {
+				1
+			} else {
+				usize::MAX
+			};
+
+			for p in c.0.parents().take(parents) {
+				if hidden {
+					self.hidden.insert(p.id());
+				}
 				self.visit(p);
 			}
 
+			if hidden {
+				if self
+					.commits
+					.iter()
+					.all(|c| self.hidden.contains(&c.0.id()))
+				{
+					self.commits.clear();
+				}
+
+				continue;
+			}
+
+			if self.options.hide_merges && c.0.parent_count() > 1 {
+				continue;
+			}
+
 			let id: CommitId = c.0.id().into();
 			let commit_should_be_included =
 				if let Some(ref filter) = self.filter {
@@ -121,6 +204,8 @@ pub struct LogWalkerWithoutFilter<'a> {
 	walk: Walk<'a>,
 	limit: usize,
 	visited: usize,
+	graph: Option<CommitGraph>,
+	options: LogWalkerOptions,
 }
 
 impl<'a> LogWalkerWithoutFilter<'a> {
@@ -128,29 +213,65 @@ impl<'a> LogWalkerWithoutFilter<'a> {
 	pub fn new(
 		repo: &'a mut gix::Repository,
 		limit: usize,
+	) -> Result<Self> {
+		let head: CommitId = repo.head()?.peel_to_commit()?.id.into();
+
+		Self::with_tips(
+			repo,
+			limit,
+			&LogTips {
+				include: vec![head],
+				exclude: Vec::new(),
+			},
+			LogWalkerOptions::default(),
+		)
+	}
+
+	/// see [`LogWalker::with_tips`]
+	pub fn with_tips(
+		repo: &'a mut gix::Repository,
+		limit: usize,
+		tips: &LogTips,
+		options: LogWalkerOptions,
 	) -> Result<Self> {
 		// This seems to be an object cache size that yields optimal performance. There’s no specific
 		// reason this is 2^14, so benchmarking might reveal that there’s better values.
 		repo.object_cache_size_if_unset(2_usize.pow(14));
 
-		let commit = repo.head()?.peel_to_commit()?;
-
-		let tips = [commit.id];
-
-		let platform = repo
-			.rev_walk(tips)
+		let mut platform = repo
+			.rev_walk(tips.include.iter().copied().map(gix::ObjectId::from))
+			.with_hidden(
+				tips.exclude.iter().copied().map(gix::ObjectId::from),
+			)
 			.sorting(gix::revision::walk::Sorting::ByCommitTime(gix::traverse::commit::simple::CommitTimeOrder::NewestFirst))
 			.use_commit_graph(false);
 
+		if options.first_parent {
+			platform = platform.first_parent_only();
+		}
+
 		let walk = platform.all()?;
 
 		Ok(Self {
 			walk,
 			limit,
 			visited: 0,
+			graph: None,
+			options,
 		})
 	}
 
+	/// also lay out the commit graph while walking,
+	/// see [`Self::read_with_graph`]. there are no lanes to draw when
+	/// merges are hidden.
+	#[must_use]
+	pub fn with_graph(self) -> Self {
+		Self {
+			graph: (!self.options.hide_merges).then(CommitGraph::new),
+			..self
+		}
+	}
+
 	///
 	pub const fn visited(&self) -> usize {
 		self.visited
@@ -158,10 +279,48 @@ impl<'a> LogWalkerWithoutFilter<'a> {
 
 	///
 	pub fn read(&mut self, out: &mut Vec<CommitId>) -> Result<usize> {
+		Ok(self.read_internal(out, None))
+	}
+
+	/// same as `read` but also pushes one [`GraphRow`] per commit into
+	/// `graph`. requires the walker to be created `with_graph`.
+	pub fn read_with_graph(
+		&mut self,
+		out: &mut Vec<CommitId>,
+		graph: &mut Vec<GraphRow>,
+	) -> Result<usize> {
+		Ok(self.read_internal(out, Some(graph)))
+	}
+
+	fn read_internal(
+		&mut self,
+		out: &mut Vec<CommitId>,
+		mut graph_out: Option<&mut Vec<GraphRow>>,
+	) -> usize {
 		let mut count = 0_usize;
+		let mut parents = Vec::with_capacity(2);
 
 		while let Some(Ok(info)) = self.walk.next() {
-			out.push(info.id.into());
+			if self.options.hide_merges && self.is_merge(&info) {
+				continue;
+			}
+
+			let id: CommitId = info.id.into();
+
+			if let (Some(graph), Some(graph_out)) =
+				(self.graph.as_mut(), graph_out.as_deref_mut())
+			{
+				parents.clear();
+				parents.extend(
+					info.parent_ids
+						.iter()
+						.copied()
+						.map(CommitId::from),
+				);
+				graph_out.push(graph.push(id, &parents));
+			}
+
+			out.push(id);
 
 			count += 1;
 
@@ -172,7 +331,18 @@ impl<'a> LogWalkerWithoutFilter<'a> {
 
 		self.visited += count;
 
-		Ok(count)
+		count
+	}
+
+	// `parent_ids` only holds the first parent when walking
+	// `first_parent_only`
+	fn is_merge(&self, info: &gix::revision::walk::Info<'_>) -> bool {
+		if !self.options.first_parent {
+			return info.parent_ids.len() > 1;
+		}
+
+		info.object()
+			.is_ok_and(|commit| commit.parent_ids().count() > 1)
 	}
 }
 
@@ -182,14 +352,17 @@ mod tests {
 	use crate::error::Result;
 	use crate::sync::commit_filter::{SearchFields, SearchOptions};
 	use crate::sync::repository::gix_repo;
-	use crate::sync::tests::write_commit_file;
+	use crate::sync::tests::{
+		write_commit_file, write_commit_file_at,
+	};
+	use crate::sync::{branch::create_branch_at, log_tips};
 	use crate::sync::{
 		commit, get_commits_info, stage_add_file,
 		tests::repo_init_empty,
 	};
 	use crate::sync::{
-		diff_contains_file, filter_commit_by_search, LogFilterSearch,
-		LogFilterSearchOptions, RepoPath,
+		diff_contains_file, filter_commit_by_search, DateRange,
+		LogFilterSearch, LogFilterSearchOptions, RepoPath,
 	};
 	use pretty_assertions::assert_eq;
 	use std::{fs::File, io::Write, path::Path};
@@ -286,6 +459,171 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_logwalker_with_tips() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = write_commit_file(&repo, "foo", "a", "base");
+		create_branch_at(repo_path, "other", base).unwrap();
+		let first = write_commit_file(&repo, "foo", "b", "first");
+		let second = write_commit_file(&repo, "foo", "c", "second");
+
+		let tips = log_tips(repo_path, "other..HEAD")?;
+
+		let mut items = Vec::new();
+		LogWalker::with_tips(
+			&repo,
+			100,
+			&tips,
+			LogWalkerOptions::default(),
+		)?
+		.read(&mut items)?;
+		assert_eq!(items, vec![second, first]);
+
+		let mut gix_repo: gix::Repository = gix_repo(repo_path)?;
+		let mut items = Vec::new();
+		LogWalkerWithoutFilter::with_tips(
+			&mut gix_repo,
+			100,
+			&tips,
+			LogWalkerOptions::default(),
+		)?
+		.read(&mut items)?;
+		assert_eq!(items, vec![second, first]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_options() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = write_commit_file_at(
+			&repo,
+			"foo",
+			"a",
+			"base",
+			git2::Time::new(100, 0),
+		);
+		let side = write_commit_file_at(
+			&repo,
+			"bar",
+			"b",
+			"side",
+			git2::Time::new(200, 0),
+		);
+
+		let tree = repo.find_commit(side.into())?.tree()?;
+		let signature = |time| {
+			git2::Signature::new(
+				"name",
+				"email",
+				&git2::Time::new(time, 0),
+			)
+		};
+		let first: CommitId = repo
+			.commit(
+				None,
+				&signature(300)?,
+				&signature(300)?,
+				"first",
+				&tree,
+				&[&repo.find_commit(base.into())?],
+			)?
+			.into();
+		let merge: CommitId = repo
+			.commit(
+				None,
+				&signature(400)?,
+				&signature(400)?,
+				"merge",
+				&tree,
+				&[
+					&repo.find_commit(first.into())?,
+					&repo.find_commit(side.into())?,
+				],
+			)?
+			.into();
+		repo.head()?.set_target(merge.into(), "merge")?;
+
+		let tips = log_tips(repo_path, "")?;
+
+		let walk = |options| -> Result<(Vec<_>, Vec<_>)> {
+			let mut items = Vec::new();
+			LogWalker::with_tips(&repo, 100, &tips, options)?
+				.read(&mut items)?;
+
+			let mut gix_repo: gix::Repository = gix_repo(repo_path)?;
+			let mut gix_items = Vec::new();
+			LogWalkerWithoutFilter::with_tips(
+				&mut gix_repo,
+				100,
+				&tips,
+				options,
+			)?
+			.read(&mut gix_items)?;
+
+			Ok((items, gix_items))
+		};
+
+		let (items, gix_items) = walk(LogWalkerOptions::default())?;
+		assert_eq!(items, vec![merge, first, side, base]);
+		assert_eq!(gix_items, items);
+
+		let (items, gix_items) = walk(LogWalkerOptions {
+			first_parent: true,
+			..LogWalkerOptions::default()
+		})?;
+		assert_eq!(items, vec![merge, first, base]);
+		assert_eq!(gix_items, items);
+
+		let (items, gix_items) = walk(LogWalkerOptions {
+			hide_merges: true,
+			..LogWalkerOptions::default()
+		})?;
+		assert_eq!(items, vec![first, side, base]);
+		assert_eq!(gix_items, items);
+
+		let (items, gix_items) = walk(LogWalkerOptions {
+			first_parent: true,
+			hide_merges: true,
+		})?;
+		assert_eq!(items, vec![first, base]);
+		assert_eq!(gix_items, items);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_with_graph() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "foo", "a", "commit1");
+		write_commit_file(&repo, "foo", "b", "commit2");
+
+		let mut repo: gix::Repository = gix_repo(repo_path)?;
+		let mut walk =
+			LogWalkerWithoutFilter::new(&mut repo, 100)?.with_graph();
+		let mut items = Vec::new();
+		let mut graph = Vec::new();
+		walk.read_with_graph(&mut items, &mut graph).unwrap();
+
+		assert_eq!(items.len(), 2);
+		assert_eq!(graph.len(), 2);
+		assert_eq!(graph[0].cells.len(), 1);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_logwalker_with_filter() -> Result<()> {
 		let file_path = Path::new("foo");
@@ -355,6 +693,7 @@ mod tests {
 				fields: SearchFields::MESSAGE_SUMMARY,
 				options: SearchOptions::FUZZY_SEARCH,
 				search_pattern: String::from("my msg"),
+				..Default::default()
 			}),
 		);
 
@@ -372,6 +711,7 @@ mod tests {
 				fields: SearchFields::FILENAMES,
 				options: SearchOptions::FUZZY_SEARCH,
 				search_pattern: String::from("fo"),
+				..Default::default()
 			}),
 		);
 
@@ -383,4 +723,163 @@ mod tests {
 
 		assert_eq!(items.len(), 2);
 	}
+
+	#[test]
+	fn test_logwalker_with_filter_hash_and_dates() {
+		let (_td, repo) = repo_init_empty().unwrap();
+
+		let first = write_commit_file_at(
+			&repo,
+			"foo",
+			"a",
+			"commit1",
+			git2::Time::new(1000, 0),
+		);
+		let second = write_commit_file_at(
+			&repo,
+			"foo",
+			"b",
+			"commit2",
+			git2::Time::new(2000, 0),
+		);
+		write_commit_file_at(
+			&repo,
+			"foo",
+			"c",
+			"commit3",
+			git2::Time::new(3000, 0),
+		);
+
+		let read = |options: LogFilterSearchOptions| {
+			let filter = filter_commit_by_search(
+				LogFilterSearch::new(options),
+			);
+			let mut items = Vec::new();
+			let mut walker = LogWalker::new(&repo, 100)
+				.unwrap()
+				.filter(Some(filter));
+			walker.read(&mut items).unwrap();
+			items
+		};
+
+		let items = read(LogFilterSearchOptions {
+			fields: SearchFields::COMMIT_HASHES,
+			search_pattern: first.to_string()[..7].to_uppercase(),
+			..Default::default()
+		});
+
+		assert_eq!(items, vec![first]);
+
+		let range = DateRange {
+			since: Some(1500),
+			until: Some(2500),
+		};
+
+		let items = read(LogFilterSearchOptions {
+			fields: SearchFields::DATES,
+			date_range: range,
+			..Default::default()
+		});
+
+		assert_eq!(items, vec![second]);
+
+		let items = read(LogFilterSearchOptions {
+			fields: SearchFields::MESSAGE_SUMMARY
+				| SearchFields::DATES,
+			search_pattern: String::from("commit1"),
+			date_range: range,
+			..Default::default()
+		});
+
+		assert!(items.is_empty());
+	}
+
+	#[test]
+	fn test_logwalker_with_filter_diffs() {
+		let (_td, repo) = repo_init_empty().unwrap();
+
+		let added = write_commit_file(
+			&repo,
+			"config.toml",
+			"a = 1\nfoo_key = 2\n",
+			"add",
+		);
+		let changed = write_commit_file(
+			&repo,
+			"config.toml",
+			"a = 1\nfoo_key = 3\n",
+			"change",
+		);
+		write_commit_file(&repo, "other.txt", "unrelated", "other");
+
+		let read = |options: SearchOptions| {
+			let filter = filter_commit_by_search(
+				LogFilterSearch::new(LogFilterSearchOptions {
+					fields: SearchFields::DIFFS,
+					options,
+					search_pattern: String::from("FOO_KEY"),
+					..Default::default()
+				}),
+			);
+			let mut items = Vec::new();
+			let mut walker = LogWalker::new(&repo, 100)
+				.unwrap()
+				.filter(Some(filter));
+			walker.read(&mut items).unwrap();
+			items
+		};
+
+		assert_eq!(
+			read(SearchOptions::empty()),
+			vec![changed, added]
+		);
+		assert_eq!(read(SearchOptions::PICKAXE_COUNT), vec![added]);
+		assert!(read(SearchOptions::CASE_SENSITIVE).is_empty());
+	}
+
+	#[test]
+	fn test_logwalker_with_filter_regex() {
+		let (_td, repo) = repo_init_empty().unwrap();
+
+		let ticket =
+			write_commit_file(&repo, "a.txt", "a", "PROJ-123: fix");
+		write_commit_file(&repo, "b.txt", "b", "proj-abc: nope");
+		let file = write_commit_file(&repo, "lib.rs", "c", "c");
+
+		let read = |fields: SearchFields, pattern: &str| {
+			let options = LogFilterSearchOptions {
+				fields,
+				options: SearchOptions::REGEX,
+				search_pattern: pattern.to_string(),
+				..Default::default()
+			};
+			assert!(options.validate().is_ok());
+
+			let filter = filter_commit_by_search(
+				LogFilterSearch::new(options),
+			);
+			let mut items = Vec::new();
+			let mut walker = LogWalker::new(&repo, 100)
+				.unwrap()
+				.filter(Some(filter));
+			walker.read(&mut items).unwrap();
+			items
+		};
+
+		assert_eq!(
+			read(SearchFields::MESSAGE_SUMMARY, r"proj-\d+"),
+			vec![ticket]
+		);
+		assert_eq!(
+			read(SearchFields::FILENAMES, r"^lib\.rs$"),
+			vec![file]
+		);
+
+		let invalid = LogFilterSearchOptions {
+			options: SearchOptions::REGEX,
+			search_pattern: String::from("PROJ-(\\d+"),
+			..Default::default()
+		};
+		assert!(invalid.validate().is_err());
+	}
 }
@@ -5,6 +5,9 @@ use crate::{
 		rebase::{
 			abort_rebase, continue_rebase, get_rebase_progress,
 		},
+		rebase_interactive::{
+			continue_rebase_interactive, is_rebase_interactive,
+		},
 		repository::repo,
 		reset_stage, reset_workdir, CommitId,
 	},
@@ -83,6 +86,10 @@ pub fn continue_pending_rebase(
 
 	let repo = repo(repo_path)?;
 
+	if is_rebase_interactive(&repo) {
+		return continue_rebase_interactive(&repo);
+	}
+
 	continue_rebase(&repo)
 }
 
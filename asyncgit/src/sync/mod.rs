@@ -3,15 +3,21 @@
 //TODO: remove once we have this activated on the toplevel
 #![deny(clippy::expect_used)]
 
+mod binary_diff;
+mod bisect;
 pub mod blame;
 pub mod branch;
+mod cherry_pick;
 pub mod commit;
 mod commit_details;
 pub mod commit_files;
 mod commit_filter;
+mod commit_graph;
 mod commit_revert;
+mod commit_signature;
 mod commits_info;
 mod config;
+mod conflicts;
 pub mod cred;
 pub mod diff;
 mod hooks;
@@ -19,11 +25,15 @@ mod hunks;
 mod ignore;
 mod logwalker;
 mod merge;
+mod notes;
 mod patches;
 mod rebase;
+mod rebase_interactive;
+mod reflog;
 pub mod remotes;
 mod repository;
 mod reset;
+mod revspec;
 mod reword;
 pub mod sign;
 mod staging;
@@ -34,28 +44,47 @@ mod submodules;
 mod tags;
 mod tree;
 pub mod utils;
+mod word_diff;
 
+pub use binary_diff::{
+	BinaryDiff, BlobSummary, HexDiff, HEX_ROW_LEN,
+};
+pub use bisect::{
+	bisect_mark, bisect_range, bisect_refs, bisect_reset,
+	bisect_start, bisect_state, BisectMark, BisectState,
+};
 pub use blame::{blame_file, BlameHunk, FileBlame};
 pub use branch::{
 	branch_compare_upstream, checkout_branch, checkout_commit,
-	config_is_pull_rebase, create_branch, delete_branch,
-	get_branch_remote, get_branch_upstream_merge, get_branches_info,
-	merge_commit::merge_upstream_commit,
+	config_is_pull_rebase, create_branch, create_branch_at,
+	delete_branch, get_branch_remote, get_branch_upstream_merge,
+	get_branches_info, merge_commit::merge_upstream_commit,
 	merge_ff::branch_merge_upstream_fastforward,
 	merge_rebase::merge_upstream_rebase, rename::rename_branch,
 	validate_branch_name, BranchCompare, BranchDetails, BranchInfo,
 };
+pub use cherry_pick::{
+	abort_cherry_pick, cherry_pick, cherry_pick_head,
+	cherry_pick_remaining, continue_cherry_pick, skip_cherry_pick,
+	CherryPickState,
+};
 pub use commit::{amend, commit, tag_commit};
 pub use commit_details::{
 	get_commit_details, CommitDetails, CommitMessage, CommitSignature,
 };
 pub use commit_files::get_commit_files;
 pub use commit_filter::{
-	diff_contains_file, filter_commit_by_search, LogFilterSearch,
-	LogFilterSearchOptions, SearchFields, SearchOptions,
-	SharedCommitFilterFn,
+	diff_contains_file, filter_commit_by_search, DateRange,
+	LogFilterSearch, LogFilterSearchOptions, SearchFields,
+	SearchOptions, SharedCommitFilterFn,
+};
+pub use commit_graph::{
+	CommitGraph, GraphCell, GraphRow, GraphSymbol,
 };
 pub use commit_revert::{commit_revert, revert_commit, revert_head};
+pub use commit_signature::{
+	verify_commit_signature, SignatureStatus,
+};
 pub use commits_info::{
 	get_commit_info, get_commits_info, CommitId, CommitInfo,
 };
@@ -63,6 +92,11 @@ pub use config::{
 	get_config_string, untracked_files_config,
 	ShowUntrackedFilesConfig,
 };
+pub use conflicts::{
+	get_file_conflict, resolve_conflict, write_conflict,
+	ConflictChunk, ConflictLine, ConflictRegion, ConflictResolution,
+	FileConflict,
+};
 pub use diff::get_diff_commit;
 pub use git2::BranchType;
 pub use hooks::{
@@ -70,24 +104,42 @@ pub use hooks::{
 	hooks_pre_push, hooks_prepare_commit_msg, HookResult,
 	PrePushTarget, PrepareCommitMsgSource,
 };
-pub use hunks::{reset_hunk, stage_hunk, unstage_hunk};
+pub use hunks::{
+	hunk_patch, reset_hunk, stage_edited_hunk, stage_hunk,
+	unstage_hunk,
+};
 pub use ignore::add_to_ignore;
-pub use logwalker::{LogWalker, LogWalkerWithoutFilter};
+pub use logwalker::{
+	LogWalker, LogWalkerOptions, LogWalkerWithoutFilter,
+};
 pub use merge::{
 	abort_pending_rebase, abort_pending_state,
 	continue_pending_rebase, merge_branch, merge_commit, merge_msg,
 	mergehead_ids, rebase_progress,
 };
-pub use rebase::rebase_branch;
+pub use notes::{
+	get_note, get_notes_refs, notes_ref_name, remove_note, set_note,
+};
+pub use rebase::{rebase_branch, RebaseState};
+pub use rebase_interactive::{
+	rebase_interactive, rebase_interactive_todo, RebaseAction,
+	RebaseTodoItem,
+};
+pub use reflog::{get_reflog, ReflogEntry};
 pub use remotes::{
-	add_remote, delete_remote, get_default_remote,
-	get_default_remote_for_fetch, get_default_remote_for_push,
-	get_remote_url, get_remotes, push::AsyncProgress, rename_remote,
-	tags::PushTagsProgress, update_remote_url, validate_remote_name,
+	add_remote,
+	clone::{clone_dir_name, clone_repo, CloneRequest},
+	delete_remote, get_default_remote, get_default_remote_for_fetch,
+	get_default_remote_for_push, get_remote_url, get_remotes,
+	push::AsyncProgress,
+	rename_remote,
+	tags::PushTagsProgress,
+	update_remote_url, validate_remote_name,
 };
 pub(crate) use repository::{gix_repo, repo};
 pub use repository::{RepoPath, RepoPathRef};
 pub use reset::{reset_repo, reset_stage, reset_workdir};
+pub use revspec::{log_tips, LogTips};
 pub use reword::reword;
 pub use staging::{discard_lines, stage_lines};
 pub use stash::{
@@ -105,8 +157,9 @@ pub use tags::{
 };
 pub use tree::{tree_file_content, tree_files, TreeFile};
 pub use utils::{
-	get_head, get_head_tuple, repo_dir, repo_open_error,
-	stage_add_all, stage_add_file, stage_addremoved, Head,
+	filter_by_pathspec, get_head, get_head_tuple, repo_dir,
+	repo_open_error, stage_add_all, stage_add_file, stage_addremoved,
+	stage_intent_to_add, Head,
 };
 
 pub use git2::ResetType;
@@ -0,0 +1,168 @@
+//! git notes attached to commits, like `git notes`
+//!
+//! a `notes_ref` of `None` means the default notes ref: `core.notesRef`
+//! or `refs/notes/commits`
+
+use super::{
+	commit::signature_allow_undefined_name, repository::repo,
+	CommitId, RepoPath,
+};
+use crate::error::Result;
+use git2::{ErrorCode, Reference};
+use scopetime::scope_time;
+
+/// returns the note of `commit_id`, `None` if it has none
+pub fn get_note(
+	repo_path: &RepoPath,
+	notes_ref: Option<&str>,
+	commit_id: CommitId,
+) -> Result<Option<String>> {
+	scope_time!("get_note");
+
+	let repo = repo(repo_path)?;
+
+	let note = match repo.find_note(notes_ref, commit_id.into()) {
+		Ok(note) => note,
+		Err(e) if e.code() == ErrorCode::NotFound => return Ok(None),
+		Err(e) => return Err(e.into()),
+	};
+
+	Ok(Some(
+		String::from_utf8_lossy(note.message_bytes()).to_string(),
+	))
+}
+
+/// adds a note to `commit_id`, replacing an existing one
+pub fn set_note(
+	repo_path: &RepoPath,
+	notes_ref: Option<&str>,
+	commit_id: CommitId,
+	message: &str,
+) -> Result<()> {
+	scope_time!("set_note");
+
+	let repo = repo(repo_path)?;
+	let signature = signature_allow_undefined_name(&repo)?;
+
+	repo.note(
+		&signature,
+		&signature,
+		notes_ref,
+		commit_id.into(),
+		message,
+		true,
+	)?;
+
+	Ok(())
+}
+
+///
+pub fn remove_note(
+	repo_path: &RepoPath,
+	notes_ref: Option<&str>,
+	commit_id: CommitId,
+) -> Result<()> {
+	scope_time!("remove_note");
+
+	let repo = repo(repo_path)?;
+	let signature = signature_allow_undefined_name(&repo)?;
+
+	repo.note_delete(
+		commit_id.into(),
+		notes_ref,
+		&signature,
+		&signature,
+	)?;
+
+	Ok(())
+}
+
+/// names of all refs under `refs/notes/`
+pub fn get_notes_refs(repo_path: &RepoPath) -> Result<Vec<String>> {
+	scope_time!("get_notes_refs");
+
+	let repo = repo(repo_path)?;
+
+	let mut refs = repo
+		.references_glob("refs/notes/*")?
+		.filter_map(|reference| {
+			reference.ok()?.name().ok().map(String::from)
+		})
+		.collect::<Vec<_>>();
+
+	refs.sort();
+
+	Ok(refs)
+}
+
+/// full name of the notes ref `name`, which may leave out
+/// `refs/notes/`, `None` if it is not a valid ref name
+pub fn notes_ref_name(name: &str) -> Option<String> {
+	let name = if name.starts_with("refs/") {
+		name.to_string()
+	} else {
+		format!("refs/notes/{name}")
+	};
+
+	Reference::is_valid_name(&name).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::tests::{repo_init_empty, write_commit_file};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_notes() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let id = write_commit_file(&repo, "foo", "a", "commit");
+
+		assert_eq!(get_note(repo_path, None, id).unwrap(), None);
+
+		set_note(repo_path, None, id, "build 1").unwrap();
+		set_note(repo_path, None, id, "build 2").unwrap();
+		set_note(repo_path, Some("refs/notes/deploy"), id, "prod")
+			.unwrap();
+
+		assert_eq!(
+			get_note(repo_path, None, id).unwrap().as_deref(),
+			Some("build 2")
+		);
+		assert_eq!(
+			get_note(repo_path, Some("refs/notes/deploy"), id)
+				.unwrap()
+				.as_deref(),
+			Some("prod")
+		);
+		assert_eq!(
+			get_notes_refs(repo_path).unwrap(),
+			vec![
+				String::from("refs/notes/commits"),
+				String::from("refs/notes/deploy"),
+			]
+		);
+
+		remove_note(repo_path, None, id).unwrap();
+
+		assert_eq!(get_note(repo_path, None, id).unwrap(), None);
+		assert!(remove_note(repo_path, None, id).is_err());
+	}
+
+	#[test]
+	fn test_notes_ref_name() {
+		assert_eq!(
+			notes_ref_name("deploy").as_deref(),
+			Some("refs/notes/deploy")
+		);
+		assert_eq!(
+			notes_ref_name("refs/notes/deploy").as_deref(),
+			Some("refs/notes/deploy")
+		);
+		assert_eq!(notes_ref_name("a..b"), None);
+	}
+}
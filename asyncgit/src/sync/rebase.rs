@@ -75,6 +75,8 @@ pub enum RebaseState {
 	Finished,
 	///
 	Conflicted,
+	/// interactive rebase stopped at an `edit` step
+	Stopped,
 }
 
 /// rebase
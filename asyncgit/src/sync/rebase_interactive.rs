@@ -0,0 +1,570 @@
+//! interactive rebase on top of the libgit2 rebase machinery
+//!
+//! libgit2 only knows how to `pick`, so the todo list is written into
+//! its `rebase-merge` state (`cmt.N`/`end`) and the actual action of
+//! every step is kept next to it. this way an interrupted rebase
+//! (conflict or `edit` step) survives restarts and is cleaned up by
+//! libgit2 on `finish`/`abort` like any other rebase.
+
+use super::{
+	commit::signature_allow_undefined_name, rebase::RebaseState,
+	repository::repo, CommitId, RepoPath,
+};
+use crate::error::{Error, Result};
+use git2::{ErrorCode, Rebase, Repository};
+use scopetime::scope_time;
+use std::{fs, path::PathBuf};
+
+const REBASE_DIR: &str = "rebase-merge";
+const TODO_FILE: &str = "gitui-todo";
+const MSG_FILE_PREFIX: &str = "gitui-msg.";
+const STOPPED_FILE: &str = "gitui-stopped";
+
+/// what to do with a commit in an interactive rebase
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RebaseAction {
+	/// use commit
+	Pick,
+	/// use commit, but change its message
+	Reword,
+	/// use commit, but stop to allow amending it
+	Edit,
+	/// meld into previous commit, keeping both messages
+	Squash,
+	/// meld into previous commit, discarding this message
+	Fixup,
+	/// remove commit
+	Drop,
+}
+
+impl RebaseAction {
+	///
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Pick => "pick",
+			Self::Reword => "reword",
+			Self::Edit => "edit",
+			Self::Squash => "squash",
+			Self::Fixup => "fixup",
+			Self::Drop => "drop",
+		}
+	}
+
+	fn from_str(s: &str) -> Option<Self> {
+		Some(match s {
+			"pick" => Self::Pick,
+			"reword" => Self::Reword,
+			"edit" => Self::Edit,
+			"squash" => Self::Squash,
+			"fixup" => Self::Fixup,
+			"drop" => Self::Drop,
+			_ => return None,
+		})
+	}
+
+	const fn uses_message(self) -> bool {
+		matches!(self, Self::Reword | Self::Squash)
+	}
+}
+
+/// single line of the interactive rebase todo list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebaseTodoItem {
+	///
+	pub action: RebaseAction,
+	///
+	pub id: CommitId,
+	/// full commit message, used by `Reword` and `Squash`
+	pub message: String,
+}
+
+impl RebaseTodoItem {
+	/// first line of the message
+	pub fn summary(&self) -> &str {
+		self.message.lines().next().unwrap_or_default()
+	}
+}
+
+/// creates the todo list to interactively rebase all commits from
+/// `from` (inclusive) up to `HEAD`, oldest first
+pub fn rebase_interactive_todo(
+	repo_path: &RepoPath,
+	from: CommitId,
+) -> Result<Vec<RebaseTodoItem>> {
+	scope_time!("rebase_interactive_todo");
+
+	let repo = repo(repo_path)?;
+
+	if repo.find_commit(from.into())?.parent_count() == 0 {
+		return Err(Error::NoParent);
+	}
+
+	let mut todo = Vec::new();
+	let mut cur = repo.head()?.peel_to_commit()?;
+	loop {
+		if cur.parent_count() > 1 {
+			return Err(Error::Generic(
+				"interactive rebase across merge commits is not supported"
+					.to_string(),
+			));
+		}
+
+		todo.push(RebaseTodoItem {
+			action: RebaseAction::Pick,
+			id: cur.id().into(),
+			message: cur
+				.message_raw()
+				.unwrap_or_default()
+				.to_string(),
+		});
+
+		if CommitId::from(cur.id()) == from {
+			break;
+		}
+
+		cur = cur.parent(0).map_err(|_| {
+			Error::Generic(format!(
+				"commit {} is not an ancestor of HEAD",
+				from.get_short_string()
+			))
+		})?;
+	}
+
+	todo.reverse();
+
+	Ok(todo)
+}
+
+/// rebases `HEAD` on the parent of the first item of `todo` applying
+/// the actions in the given order
+pub fn rebase_interactive(
+	repo_path: &RepoPath,
+	todo: &[RebaseTodoItem],
+) -> Result<RebaseState> {
+	scope_time!("rebase_interactive");
+
+	let repo = repo(repo_path)?;
+
+	let steps: Vec<&RebaseTodoItem> = todo
+		.iter()
+		.filter(|item| item.action != RebaseAction::Drop)
+		.collect();
+
+	if matches!(
+		steps.first().map(|item| item.action),
+		Some(RebaseAction::Squash | RebaseAction::Fixup)
+	) {
+		return Err(Error::Generic(
+			"cannot squash without a previous commit".to_string(),
+		));
+	}
+
+	// the parent of the oldest commit is the only one not in the list
+	let mut upstream = None;
+	for item in todo {
+		let parent = repo
+			.find_commit(item.id.into())?
+			.parent_id(0)
+			.map_err(|_| Error::NoParent)?;
+
+		if !todo.iter().any(|item| item.id == parent.into()) {
+			upstream = Some(parent);
+		}
+	}
+
+	let upstream = upstream.ok_or(Error::NoParent)?;
+	let upstream = repo.find_annotated_commit(upstream)?;
+
+	// libgit2 sets up its state and checks out `upstream` here,
+	// the list of commits it came up with gets replaced below
+	repo.rebase(None, Some(&upstream), None, None)?;
+
+	if let Err(e) = write_todo(&repo, &steps) {
+		repo.open_rebase(None)?.abort()?;
+		return Err(e);
+	}
+
+	let rebase = repo.open_rebase(None)?;
+
+	run_steps(&repo, rebase)
+}
+
+/// returns `true` if the pending rebase was started by
+/// [`rebase_interactive`]
+pub fn is_rebase_interactive(repo: &Repository) -> bool {
+	rebase_dir(repo).join(TODO_FILE).exists()
+}
+
+/// continues an interactive rebase that stopped because of a
+/// conflict or an `edit` step
+pub fn continue_rebase_interactive(
+	repo: &Repository,
+) -> Result<RebaseState> {
+	let mut rebase = repo.open_rebase(None)?;
+
+	if repo.index()?.has_conflicts() {
+		return Ok(RebaseState::Conflicted);
+	}
+
+	if let Some(current) = rebase.operation_current() {
+		let stopped = rebase_dir(repo).join(STOPPED_FILE);
+
+		if stopped.exists() {
+			// commit was already created, take along what got staged
+			amend_head_with_index(repo)?;
+			fs::remove_file(stopped)?;
+		} else {
+			let (action, message) = read_step(repo, current)?;
+			if commit_step(repo, &mut rebase, action, &message)? {
+				return stop_for_edit(repo);
+			}
+		}
+	}
+
+	run_steps(repo, rebase)
+}
+
+fn rebase_dir(repo: &Repository) -> PathBuf {
+	repo.path().join(REBASE_DIR)
+}
+
+fn write_todo(
+	repo: &Repository,
+	steps: &[&RebaseTodoItem],
+) -> Result<()> {
+	let dir = rebase_dir(repo);
+
+	for idx in 1.. {
+		let path = dir.join(format!("cmt.{idx}"));
+		if !path.exists() {
+			break;
+		}
+		fs::remove_file(path)?;
+	}
+
+	let mut actions = String::new();
+
+	for (idx, item) in steps.iter().enumerate() {
+		let nr = idx + 1;
+		fs::write(
+			dir.join(format!("cmt.{nr}")),
+			format!("{}\n", item.id),
+		)?;
+
+		if item.action.uses_message() {
+			fs::write(
+				dir.join(format!("{MSG_FILE_PREFIX}{nr}")),
+				&item.message,
+			)?;
+		}
+
+		actions.push_str(item.action.as_str());
+		actions.push('\n');
+	}
+
+	fs::write(dir.join("end"), format!("{}\n", steps.len()))?;
+	fs::write(dir.join(TODO_FILE), actions)?;
+
+	Ok(())
+}
+
+fn read_step(
+	repo: &Repository,
+	idx: usize,
+) -> Result<(RebaseAction, String)> {
+	let dir = rebase_dir(repo);
+
+	let actions = fs::read_to_string(dir.join(TODO_FILE))?;
+	let action = actions
+		.lines()
+		.nth(idx)
+		.and_then(RebaseAction::from_str)
+		.ok_or_else(|| {
+			Error::Generic(format!("invalid rebase todo step: {idx}"))
+		})?;
+
+	let message = if action.uses_message() {
+		fs::read_to_string(
+			dir.join(format!("{MSG_FILE_PREFIX}{}", idx + 1)),
+		)?
+	} else {
+		String::new()
+	};
+
+	Ok((action, message))
+}
+
+fn run_steps(
+	repo: &Repository,
+	mut rebase: Rebase,
+) -> Result<RebaseState> {
+	while let Some(op) = rebase.next() {
+		let _op = op?;
+
+		if repo.index()?.has_conflicts() {
+			return Ok(RebaseState::Conflicted);
+		}
+
+		let current = rebase.operation_current().unwrap_or_default();
+		let (action, message) = read_step(repo, current)?;
+
+		if commit_step(repo, &mut rebase, action, &message)? {
+			return stop_for_edit(repo);
+		}
+	}
+
+	let signature = signature_allow_undefined_name(repo)?;
+
+	rebase.finish(Some(&signature))?;
+
+	Ok(RebaseState::Finished)
+}
+
+/// commits the currently applied step, returns `true` if the rebase
+/// is supposed to stop afterwards
+fn commit_step(
+	repo: &Repository,
+	rebase: &mut Rebase,
+	action: RebaseAction,
+	message: &str,
+) -> Result<bool> {
+	let signature = signature_allow_undefined_name(repo)?;
+
+	match action {
+		RebaseAction::Squash | RebaseAction::Fixup => {
+			let head = repo.head()?.peel_to_commit()?;
+			let message = if action == RebaseAction::Squash {
+				format!(
+					"{}\n\n{}",
+					head.message_raw().unwrap_or_default().trim_end(),
+					message
+				)
+			} else {
+				head.message_raw().unwrap_or_default().to_string()
+			};
+
+			let tree = repo.find_tree(repo.index()?.write_tree()?)?;
+			let parents = head.parents().collect::<Vec<_>>();
+			let parents = parents.iter().collect::<Vec<_>>();
+
+			let id = repo.commit(
+				None,
+				&head.author(),
+				&signature,
+				&message,
+				&tree,
+				&parents,
+			)?;
+
+			repo.set_head_detached(id)?;
+
+			Ok(false)
+		}
+		_ => {
+			let message =
+				(action == RebaseAction::Reword).then_some(message);
+
+			match rebase.commit(None, &signature, message) {
+				// nothing left of this commit, skip it like git does
+				Err(e) if e.code() == ErrorCode::Applied => {}
+				res => {
+					res?;
+				}
+			}
+
+			Ok(action == RebaseAction::Edit)
+		}
+	}
+}
+
+fn stop_for_edit(repo: &Repository) -> Result<RebaseState> {
+	fs::write(rebase_dir(repo).join(STOPPED_FILE), "")?;
+
+	Ok(RebaseState::Stopped)
+}
+
+fn amend_head_with_index(repo: &Repository) -> Result<()> {
+	let head = repo.head()?.peel_to_commit()?;
+	let tree_id = repo.index()?.write_tree()?;
+
+	if tree_id == head.tree_id() {
+		return Ok(());
+	}
+
+	let tree = repo.find_tree(tree_id)?;
+	let id = head.amend(None, None, None, None, None, Some(&tree))?;
+
+	repo.set_head_detached(id)?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		repo_state,
+		tests::{repo_init, write_commit_file},
+		utils::repo_write_file,
+		RepoState,
+	};
+	use pretty_assertions::assert_eq;
+
+	fn messages(repo: &Repository, count: usize) -> Vec<String> {
+		let mut res = Vec::new();
+		let mut cur = repo.head().unwrap().peel_to_commit().unwrap();
+		res.push(cur.message().unwrap().trim_end().to_string());
+		for _ in 1..count {
+			cur = cur.parent(0).unwrap();
+			res.push(cur.message().unwrap().trim_end().to_string());
+		}
+		res
+	}
+
+	fn setup() -> (tempfile::TempDir, Repository, RepoPath, CommitId)
+	{
+		let (td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: RepoPath =
+			root.as_os_str().to_str().unwrap().into();
+
+		let c1 = write_commit_file(&repo, "a.txt", "a", "commit1");
+		write_commit_file(&repo, "b.txt", "b", "commit2");
+		write_commit_file(&repo, "c.txt", "c", "commit3");
+
+		(td, repo, repo_path, c1)
+	}
+
+	#[test]
+	fn test_todo() {
+		let (_td, _repo, repo_path, c1) = setup();
+
+		let todo = rebase_interactive_todo(&repo_path, c1).unwrap();
+
+		assert_eq!(
+			todo.iter()
+				.map(RebaseTodoItem::summary)
+				.collect::<Vec<_>>(),
+			vec!["commit1", "commit2", "commit3"]
+		);
+		assert!(todo.iter().all(|i| i.action == RebaseAction::Pick));
+	}
+
+	#[test]
+	fn test_reorder_drop_reword() {
+		let (_td, repo, repo_path, c1) = setup();
+
+		let mut todo =
+			rebase_interactive_todo(&repo_path, c1).unwrap();
+		todo.swap(0, 2);
+		todo[1].action = RebaseAction::Drop;
+		todo[2].action = RebaseAction::Reword;
+		todo[2].message = String::from("reworded");
+
+		let res = rebase_interactive(&repo_path, &todo).unwrap();
+
+		assert_eq!(res, RebaseState::Finished);
+		assert_eq!(repo_state(&repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(
+			messages(&repo, 3),
+			vec!["reworded", "commit3", "initial"]
+		);
+		assert!(!repo.workdir().unwrap().join("b.txt").exists());
+		assert!(repo.head().unwrap().is_branch());
+	}
+
+	#[test]
+	fn test_squash_fixup() {
+		let (_td, repo, repo_path, c1) = setup();
+
+		let mut todo =
+			rebase_interactive_todo(&repo_path, c1).unwrap();
+		todo[1].action = RebaseAction::Squash;
+		todo[2].action = RebaseAction::Fixup;
+
+		let res = rebase_interactive(&repo_path, &todo).unwrap();
+
+		assert_eq!(res, RebaseState::Finished);
+		assert_eq!(
+			messages(&repo, 2),
+			vec!["commit1\n\ncommit2", "initial"]
+		);
+
+		let head = repo.head().unwrap().peel_to_commit().unwrap();
+		let tree = head.tree().unwrap();
+		assert!(tree.get_name("a.txt").is_some());
+		assert!(tree.get_name("b.txt").is_some());
+		assert!(tree.get_name("c.txt").is_some());
+	}
+
+	#[test]
+	fn test_squash_first() {
+		let (_td, _repo, repo_path, c1) = setup();
+
+		let mut todo =
+			rebase_interactive_todo(&repo_path, c1).unwrap();
+		todo[0].action = RebaseAction::Fixup;
+
+		assert!(rebase_interactive(&repo_path, &todo).is_err());
+		assert_eq!(repo_state(&repo_path).unwrap(), RepoState::Clean);
+	}
+
+	#[test]
+	fn test_edit() {
+		let (_td, repo, repo_path, c1) = setup();
+
+		let mut todo =
+			rebase_interactive_todo(&repo_path, c1).unwrap();
+		todo[1].action = RebaseAction::Edit;
+
+		let res = rebase_interactive(&repo_path, &todo).unwrap();
+
+		assert_eq!(res, RebaseState::Stopped);
+		assert_eq!(
+			repo_state(&repo_path).unwrap(),
+			RepoState::Rebase
+		);
+		assert!(is_rebase_interactive(&repo));
+
+		repo_write_file(&repo, "d.txt", "d").unwrap();
+		let mut index = repo.index().unwrap();
+		index.add_path(std::path::Path::new("d.txt")).unwrap();
+		index.write().unwrap();
+
+		let res = continue_rebase_interactive(&repo).unwrap();
+
+		assert_eq!(res, RebaseState::Finished);
+		assert_eq!(repo_state(&repo_path).unwrap(), RepoState::Clean);
+		assert!(!is_rebase_interactive(&repo));
+
+		let head = repo.head().unwrap().peel_to_commit().unwrap();
+		let edited = head.parent(0).unwrap();
+		assert_eq!(edited.message().unwrap(), "commit2");
+		assert!(edited.tree().unwrap().get_name("d.txt").is_some());
+	}
+
+	#[test]
+	fn test_conflict() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let c1 = write_commit_file(&repo, "a.txt", "1", "commit1");
+		write_commit_file(&repo, "a.txt", "2", "commit2");
+
+		let mut todo =
+			rebase_interactive_todo(repo_path, c1).unwrap();
+		todo.swap(0, 1);
+
+		let res = rebase_interactive(repo_path, &todo).unwrap();
+
+		assert_eq!(res, RebaseState::Conflicted);
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Rebase);
+
+		crate::sync::abort_pending_rebase(repo_path).unwrap();
+
+		assert_eq!(repo_state(repo_path).unwrap(), RepoState::Clean);
+		assert_eq!(messages(&repo, 2), vec!["commit2", "commit1"]);
+	}
+}
@@ -0,0 +1,105 @@
+//! reading the reflog of `HEAD` or a branch
+
+use super::{repository::repo, CommitId, RepoPath};
+use crate::error::Result;
+use scopetime::scope_time;
+
+/// single entry of a reflog
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflogEntry {
+	/// commit the ref pointed to after this entry
+	pub id: CommitId,
+	/// commit the ref pointed to before this entry
+	pub old_id: CommitId,
+	///
+	pub message: String,
+	///
+	pub committer: String,
+	/// unix timestamp of the entry
+	pub time: i64,
+}
+
+/// returns the reflog of `refname` (e.g. `HEAD` or
+/// `refs/heads/master`), newest entry first
+pub fn get_reflog(
+	repo_path: &RepoPath,
+	refname: &str,
+) -> Result<Vec<ReflogEntry>> {
+	scope_time!("get_reflog");
+
+	let repo = repo(repo_path)?;
+	let reflog = repo.reflog(refname)?;
+
+	let entries = reflog
+		.iter()
+		.map(|entry| {
+			let committer = entry.committer();
+
+			ReflogEntry {
+				id: entry.id_new().into(),
+				old_id: entry.id_old().into(),
+				message: entry
+					.message_bytes()
+					.map(String::from_utf8_lossy)
+					.unwrap_or_default()
+					.to_string(),
+				committer: committer
+					.name()
+					.unwrap_or_default()
+					.to_string(),
+				time: committer.when().seconds(),
+			}
+		})
+		.collect();
+
+	Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		checkout_branch, create_branch,
+		tests::{repo_init, write_commit_file},
+	};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_head_reflog() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let c1 = write_commit_file(&repo, "a.txt", "a", "commit1");
+		let c2 = write_commit_file(&repo, "b.txt", "b", "commit2");
+
+		let reflog = get_reflog(repo_path, "HEAD").unwrap();
+
+		assert_eq!(reflog[0].id, c2);
+		assert_eq!(reflog[0].old_id, c1);
+		assert_eq!(reflog[1].id, c1);
+	}
+
+	#[test]
+	fn test_branch_reflog() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		create_branch(repo_path, "foo").unwrap();
+		let c1 = write_commit_file(&repo, "a.txt", "a", "commit1");
+		checkout_branch(repo_path, "master").unwrap();
+
+		let head = get_reflog(repo_path, "HEAD").unwrap();
+		let foo = get_reflog(repo_path, "refs/heads/foo").unwrap();
+
+		assert!(head[0].message.starts_with("checkout:"));
+		assert_eq!(foo[0].id, c1);
+		assert!(get_reflog(repo_path, "refs/heads/master")
+			.unwrap()
+			.iter()
+			.all(|entry| entry.id != c1));
+	}
+}
@@ -0,0 +1,170 @@
+//! cloning a repository
+
+use super::{proxy_auto, Callbacks};
+use crate::{
+	error::Result,
+	sync::{
+		cred::BasicAuthCredential,
+		remotes::push::ProgressNotification,
+	},
+};
+use crossbeam_channel::Sender;
+use git2::{
+	build::RepoBuilder, FetchOptions, Repository,
+	SubmoduleUpdateOptions,
+};
+use scopetime::scope_time;
+use std::path::PathBuf;
+
+///
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct CloneRequest {
+	/// url or path of the repository to clone
+	pub url: String,
+	/// directory to clone into, must not exist or be empty
+	pub path: PathBuf,
+	/// branch to check out instead of the remote `HEAD`
+	pub branch: Option<String>,
+	/// number of commits to fetch for a shallow clone
+	pub depth: Option<i32>,
+	/// also clone all submodules, recursively
+	pub recurse_submodules: bool,
+}
+
+///
+pub fn clone_repo(
+	request: &CloneRequest,
+	basic_credential: &Option<BasicAuthCredential>,
+	progress_sender: &Option<Sender<ProgressNotification>>,
+) -> Result<()> {
+	scope_time!("clone_repo");
+
+	let fetch_options = |progress_sender, basic_credential| {
+		let callbacks =
+			Callbacks::new(progress_sender, basic_credential);
+		let mut options = FetchOptions::new();
+		options.proxy_options(proxy_auto());
+		options.remote_callbacks(callbacks.callbacks());
+		if let Some(depth) = request.depth {
+			options.depth(depth);
+		}
+		options
+	};
+
+	let mut builder = RepoBuilder::new();
+	builder.fetch_options(fetch_options(
+		progress_sender.clone(),
+		basic_credential.clone(),
+	));
+	if let Some(branch) = &request.branch {
+		builder.branch(branch);
+	}
+
+	let repo = builder.clone(&request.url, &request.path)?;
+
+	if request.recurse_submodules {
+		update_submodules(&repo, &|| {
+			fetch_options(
+				progress_sender.clone(),
+				basic_credential.clone(),
+			)
+		})?;
+	}
+
+	Ok(())
+}
+
+/// directory name `git clone` picks for `url`, e.g. `gitui` for
+/// `git@github.com:gitui-org/gitui.git`
+pub fn clone_dir_name(url: &str) -> Option<String> {
+	let url = url.trim_end_matches('/');
+	let url = url.strip_suffix(".git").unwrap_or(url);
+	let name = url.rsplit(['/', ':', '\\']).next()?;
+
+	(!name.is_empty()).then(|| name.to_string())
+}
+
+/// `fetch_options` creates new callbacks for every submodule because
+/// credentials are only tried once per `Callbacks`
+fn update_submodules<'a>(
+	repo: &Repository,
+	fetch_options: &dyn Fn() -> FetchOptions<'a>,
+) -> Result<()> {
+	for mut submodule in repo.submodules()? {
+		let mut options = SubmoduleUpdateOptions::new();
+		options.allow_fetch(true);
+		options.fetch(fetch_options());
+
+		submodule.update(true, Some(&mut options))?;
+
+		update_submodules(&submodule.open()?, fetch_options)?;
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		create_branch,
+		tests::{repo_init, write_commit_file},
+		RepoPath,
+	};
+	use tempfile::TempDir;
+
+	#[test]
+	fn test_clone_dir_name() {
+		assert_eq!(
+			clone_dir_name("git@github.com:gitui-org/gitui.git"),
+			Some(String::from("gitui"))
+		);
+		assert_eq!(
+			clone_dir_name("https://example.com/a/repo/"),
+			Some(String::from("repo"))
+		);
+		assert_eq!(
+			clone_dir_name("../repo.git"),
+			Some(String::from("repo"))
+		);
+		assert_eq!(clone_dir_name("https://"), None);
+	}
+
+	#[test]
+	fn test_clone_branch() {
+		let (remote_dir, remote) = repo_init().unwrap();
+		let remote_path: &RepoPath =
+			&remote_dir.path().to_str().unwrap().into();
+
+		create_branch(remote_path, "feature").unwrap();
+		write_commit_file(
+			&remote,
+			"foo.txt",
+			"foo",
+			"feature commit",
+		);
+
+		let target = TempDir::new().unwrap();
+		let path = target.path().join("clone");
+
+		clone_repo(
+			&CloneRequest {
+				url: remote_dir.path().to_str().unwrap().to_string(),
+				path: path.clone(),
+				branch: Some(String::from("master")),
+				..CloneRequest::default()
+			},
+			&None,
+			&None,
+		)
+		.unwrap();
+
+		let repo = Repository::open(&path).unwrap();
+		// the remote `HEAD` is `feature`
+		assert_eq!(
+			repo.head().unwrap().shorthand().unwrap(),
+			"master"
+		);
+		assert!(!path.join("foo.txt").exists());
+	}
+}
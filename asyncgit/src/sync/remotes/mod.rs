@@ -1,6 +1,7 @@
 //!
 
 mod callbacks;
+pub(crate) mod clone;
 pub(crate) mod push;
 pub(crate) mod tags;
 
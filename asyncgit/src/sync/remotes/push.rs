@@ -343,6 +343,7 @@ mod tests {
 			sync::get_commit_files(
 				&tmp_repo_dir.path().to_str().unwrap().into(),
 				repo_1_commit,
+				None,
 				None
 			)
 			.unwrap()[0]
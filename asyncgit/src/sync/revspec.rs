@@ -0,0 +1,176 @@
+//! revision arguments for the log, like `git log main..feature`
+
+use super::{repository::repo, CommitId, RepoPath};
+use crate::error::{Error, Result};
+use git2::Repository;
+use scopetime::scope_time;
+
+/// commits to start walking from and commits whose ancestry is left
+/// out, like `git rev-list <include>... ^<exclude>...`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LogTips {
+	///
+	pub include: Vec<CommitId>,
+	///
+	pub exclude: Vec<CommitId>,
+}
+
+impl LogTips {
+	fn include(&mut self, id: CommitId) {
+		if !self.include.contains(&id) {
+			self.include.push(id);
+		}
+	}
+
+	fn exclude(&mut self, id: CommitId) {
+		if !self.exclude.contains(&id) {
+			self.exclude.push(id);
+		}
+	}
+}
+
+/// resolves a whitespace separated list of revisions
+///
+/// each one is a ref or commit (`main`, `v1.0`, `HEAD~3`), an
+/// exclusion (`^main`), a range (`main..feature`), a symmetric
+/// difference (`main...feature`) or `--all`. an empty side of a range
+/// means `HEAD`, an empty spec is just `HEAD`.
+pub fn log_tips(repo_path: &RepoPath, spec: &str) -> Result<LogTips> {
+	scope_time!("log_tips");
+
+	let repo = repo(repo_path)?;
+
+	resolve_log_tips(&repo, spec)
+}
+
+fn resolve_log_tips(
+	repo: &Repository,
+	spec: &str,
+) -> Result<LogTips> {
+	let mut tips = LogTips::default();
+
+	for arg in spec.split_whitespace() {
+		if arg == "--all" {
+			for id in all_refs(repo)? {
+				tips.include(id);
+			}
+		} else if let Some(rev) = arg.strip_prefix('^') {
+			tips.exclude(resolve(repo, rev)?);
+		} else if let Some((left, right)) = arg.split_once("...") {
+			let left = resolve(repo, left)?;
+			let right = resolve(repo, right)?;
+
+			tips.include(left);
+			tips.include(right);
+
+			for base in
+				repo.merge_bases(left.into(), right.into())?.iter()
+			{
+				tips.exclude((*base).into());
+			}
+		} else if let Some((left, right)) = arg.split_once("..") {
+			tips.exclude(resolve(repo, left)?);
+			tips.include(resolve(repo, right)?);
+		} else {
+			tips.include(resolve(repo, arg)?);
+		}
+	}
+
+	if tips.include.is_empty() {
+		if !tips.exclude.is_empty() {
+			return Err(Error::Generic(format!(
+				"no revision to walk from in: '{spec}'"
+			)));
+		}
+
+		tips.include(resolve(repo, "HEAD")?);
+	}
+
+	Ok(tips)
+}
+
+fn resolve(repo: &Repository, rev: &str) -> Result<CommitId> {
+	let rev = if rev.is_empty() { "HEAD" } else { rev };
+
+	Ok(repo.revparse_single(rev)?.peel_to_commit()?.id().into())
+}
+
+fn all_refs(repo: &Repository) -> Result<Vec<CommitId>> {
+	let mut ids = Vec::new();
+
+	if let Ok(head) =
+		repo.head().and_then(|head| head.peel_to_commit())
+	{
+		ids.push(head.id().into());
+	}
+
+	for reference in repo.references()? {
+		let reference = reference?;
+
+		// stashes are refs too but `git log --all` does not
+		// list them either
+		if reference.name().ok() == Some("refs/stash") {
+			continue;
+		}
+
+		if let Ok(commit) = reference.peel_to_commit() {
+			ids.push(commit.id().into());
+		}
+	}
+
+	Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		branch::create_branch_at,
+		tests::{repo_init_empty, write_commit_file},
+	};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_ranges() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = write_commit_file(&repo, "a.txt", "a", "base");
+		create_branch_at(repo_path, "other", base).unwrap();
+		let head = write_commit_file(&repo, "b.txt", "b", "head");
+
+		assert_eq!(
+			log_tips(repo_path, "").unwrap(),
+			LogTips {
+				include: vec![head],
+				exclude: vec![],
+			}
+		);
+		assert_eq!(
+			log_tips(repo_path, "other..").unwrap(),
+			LogTips {
+				include: vec![head],
+				exclude: vec![base],
+			}
+		);
+		assert_eq!(
+			log_tips(repo_path, "HEAD ^other").unwrap(),
+			log_tips(repo_path, "other..HEAD").unwrap(),
+		);
+		assert_eq!(
+			log_tips(repo_path, "other...HEAD").unwrap(),
+			LogTips {
+				include: vec![base, head],
+				exclude: vec![base],
+			}
+		);
+		assert_eq!(
+			log_tips(repo_path, "--all").unwrap().include,
+			vec![head, base]
+		);
+		assert!(log_tips(repo_path, "^other").is_err());
+		assert!(log_tips(repo_path, "nope").is_err());
+	}
+}
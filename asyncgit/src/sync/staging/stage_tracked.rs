@@ -2,8 +2,12 @@ use super::apply_selection;
 use crate::{
 	error::{Error, Result},
 	sync::{
-		diff::DiffLinePosition, patches::get_file_diff_patch,
-		patches::patch_get_hunklines, repository::repo, RepoPath,
+		diff::DiffLinePosition,
+		patches::get_file_diff_patch,
+		patches::patch_get_hunklines,
+		repository::repo,
+		utils::{add_intent_to_add, clear_intent_to_add},
+		RepoPath,
 	},
 };
 use easy_cast::Conv;
@@ -28,6 +32,15 @@ pub fn stage_lines(
 
 	let mut index = repo.index()?;
 	index.read(true)?;
+
+	// lines of an untracked file are staged onto an intent-to-add
+	// entry, just like `git add -N` followed by `git add -p`
+	if !is_stage && index.get_path(Path::new(file_path), 0).is_none()
+	{
+		add_intent_to_add(&repo, &mut index, Path::new(file_path))?;
+		index.write()?;
+	}
+
 	let mut idx =
 		index.get_path(Path::new(file_path), 0).ok_or_else(|| {
 			Error::Generic(String::from(
@@ -51,6 +64,7 @@ pub fn stage_lines(
 
 	idx.id = blob_id;
 	idx.file_size = u32::try_conv(new_content.len())?;
+	clear_intent_to_add(&mut idx);
 	index.add(&idx)?;
 
 	index.write()?;
@@ -65,7 +79,9 @@ mod test {
 	use crate::sync::{
 		diff::get_diff,
 		tests::{get_statuses, repo_init, write_commit_file},
-		utils::{repo_write_file, stage_add_file},
+		utils::{
+			repo_write_file, stage_add_file, stage_intent_to_add,
+		},
 	};
 
 	#[test]
@@ -188,4 +204,72 @@ c = 4";
 
 		assert_eq!(diff.lines, 4);
 	}
+
+	#[test]
+	fn test_intent_to_add() {
+		let (path, repo) = repo_init().unwrap();
+		let path: &RepoPath = &path.path().to_str().unwrap().into();
+
+		repo_write_file(&repo, "new.txt", "0\n1\n").unwrap();
+
+		stage_intent_to_add(path, Path::new("new.txt")).unwrap();
+
+		assert_eq!(get_statuses(path), (1, 0));
+
+		let diff = get_diff(path, "new.txt", false, None).unwrap();
+		assert!(!diff.untracked);
+		assert_eq!(diff.lines, 3);
+
+		let diff = get_diff(path, "new.txt", true, None).unwrap();
+		assert!(diff.hunks.is_empty());
+	}
+
+	#[test]
+	fn test_stage_untracked_lines() {
+		static FILE: &str = r"0
+1
+2
+3
+";
+
+		let (path, repo) = repo_init().unwrap();
+		let path: &RepoPath = &path.path().to_str().unwrap().into();
+
+		repo_write_file(&repo, "new.txt", FILE).unwrap();
+
+		assert_eq!(get_statuses(path), (1, 0));
+
+		stage_lines(
+			path,
+			"new.txt",
+			false,
+			&[
+				DiffLinePosition {
+					old_lineno: None,
+					new_lineno: Some(1),
+				},
+				DiffLinePosition {
+					old_lineno: None,
+					new_lineno: Some(2),
+				},
+			],
+		)
+		.unwrap();
+
+		assert_eq!(get_statuses(path), (1, 1));
+
+		let diff = get_diff(path, "new.txt", true, None).unwrap();
+		assert_eq!(diff.lines, 3);
+		assert_eq!(
+			&*diff.hunks[0].lines[0].content,
+			"@@ -0,0 +1,2 @@"
+		);
+
+		let diff = get_diff(path, "new.txt", false, None).unwrap();
+		assert_eq!(diff.lines, 5);
+		assert_eq!(
+			&*diff.hunks[0].lines[0].content,
+			"@@ -1,2 +1,4 @@"
+		);
+	}
 }
@@ -15,6 +15,10 @@ pub enum RepoState {
 	///
 	Revert,
 	///
+	CherryPick,
+	///
+	Bisect,
+	///
 	Other,
 }
 
@@ -24,7 +28,10 @@ impl From<RepositoryState> for RepoState {
 			RepositoryState::Clean => Self::Clean,
 			RepositoryState::Merge => Self::Merge,
 			RepositoryState::Revert => Self::Revert,
+			RepositoryState::CherryPick
+			| RepositoryState::CherryPickSequence => Self::CherryPick,
 			RepositoryState::RebaseMerge => Self::Rebase,
+			RepositoryState::Bisect => Self::Bisect,
 			_ => {
 				log::warn!("state not supported yet: {state:?}");
 				Self::Other
@@ -1,20 +1,29 @@
 //! sync git api (various methods)
 
 use super::{
-	repository::repo, CommitId, RepoPath, ShowUntrackedFilesConfig,
+	repository::repo, status::StatusItem, CommitId, RepoPath,
+	ShowUntrackedFilesConfig,
 };
 use crate::{
 	error::{Error, Result},
 	sync::config::untracked_files_config_repo,
 };
-use git2::{IndexAddOption, Repository, RepositoryOpenFlags};
+use git2::{
+	Index, IndexAddOption, IndexEntry, IndexTime, Pathspec,
+	PathspecFlags, Repository, RepositoryOpenFlags,
+};
 use scopetime::scope_time;
 use std::{
-	fs::File,
+	fs::{self, File},
 	io::Write,
 	path::{Path, PathBuf},
 };
 
+// see `git_index_entry` in libgit2
+const INDEX_ENTRY_NAME_MASK: u16 = 0x0fff;
+const INDEX_ENTRY_EXTENDED: u16 = 0x4000;
+const INDEX_ENTRY_INTENT_TO_ADD: u16 = 1 << 13;
+
 ///
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Head {
@@ -109,6 +118,86 @@ pub fn stage_add_file(
 	Ok(())
 }
 
+/// marks the untracked file at `path` as intent-to-add (`git add -N`):
+/// it is tracked with empty content so that its lines can be staged
+pub fn stage_intent_to_add(
+	repo_path: &RepoPath,
+	path: &Path,
+) -> Result<()> {
+	scope_time!("stage_intent_to_add");
+
+	let repo = repo(repo_path)?;
+
+	let mut index = repo.index()?;
+
+	add_intent_to_add(&repo, &mut index, path)?;
+	index.write()?;
+
+	Ok(())
+}
+
+pub(crate) fn add_intent_to_add(
+	repo: &Repository,
+	index: &mut Index,
+	path: &Path,
+) -> Result<()> {
+	let meta = fs::symlink_metadata(work_dir(repo)?.join(path))?;
+	let path = path.to_str().ok_or_else(|| {
+		Error::Generic(String::from("invalid file path"))
+	})?;
+
+	index.add(&IndexEntry {
+		ctime: IndexTime::new(0, 0),
+		mtime: IndexTime::new(0, 0),
+		dev: 0,
+		ino: 0,
+		mode: file_mode(&meta),
+		uid: 0,
+		gid: 0,
+		file_size: 0,
+		id: repo.blob(&[])?,
+		flags: u16::try_from(path.len())
+			.unwrap_or(INDEX_ENTRY_NAME_MASK)
+			.min(INDEX_ENTRY_NAME_MASK)
+			| INDEX_ENTRY_EXTENDED,
+		flags_extended: INDEX_ENTRY_INTENT_TO_ADD,
+		path: path.as_bytes().to_vec(),
+	})?;
+
+	Ok(())
+}
+
+/// turns an intent-to-add `entry` into a regular one, needed once
+/// it gets actual content
+pub(crate) const fn clear_intent_to_add(entry: &mut IndexEntry) {
+	entry.flags_extended &= !INDEX_ENTRY_INTENT_TO_ADD;
+	if entry.flags_extended == 0 {
+		entry.flags &= !INDEX_ENTRY_EXTENDED;
+	}
+}
+
+#[cfg(unix)]
+fn file_mode(meta: &fs::Metadata) -> u32 {
+	use std::os::unix::fs::PermissionsExt;
+
+	if meta.file_type().is_symlink() {
+		0o120_000
+	} else if meta.permissions().mode() & 0o111 != 0 {
+		0o100_755
+	} else {
+		0o100_644
+	}
+}
+
+#[cfg(not(unix))]
+fn file_mode(meta: &fs::Metadata) -> u32 {
+	if meta.file_type().is_symlink() {
+		0o120_000
+	} else {
+		0o100_644
+	}
+}
+
 /// like `stage_add_file` but uses a pattern to match/glob multiple files/folders
 pub fn stage_add_all(
 	repo_path: &RepoPath,
@@ -142,6 +231,192 @@ pub fn stage_add_all(
 	Ok(())
 }
 
+/// one entry of a pathspec with its magic
+struct PathspecEntry {
+	exclude: bool,
+	glob: bool,
+	pattern: String,
+}
+
+impl PathspecEntry {
+	/// understands the `:!`, `:^` short forms and the `exclude` and
+	/// `glob` long form magic, e.g. `:(glob,exclude)`
+	fn parse(spec: &str) -> Result<Self> {
+		let (magic, pattern) = if let Some(rest) =
+			spec.strip_prefix(":(")
+		{
+			rest.split_once(')').ok_or_else(|| {
+				Error::Generic(format!("unterminated magic: {spec}"))
+			})?
+		} else if let Some(rest) = spec
+			.strip_prefix(":!")
+			.or_else(|| spec.strip_prefix(":^"))
+		{
+			("exclude", rest)
+		} else {
+			("", spec)
+		};
+
+		let mut entry = Self {
+			exclude: false,
+			glob: false,
+			pattern: pattern.to_string(),
+		};
+		for word in magic.split(',').filter(|word| !word.is_empty()) {
+			match word {
+				"exclude" => entry.exclude = true,
+				"glob" => entry.glob = true,
+				_ => {
+					return Err(Error::Generic(format!(
+						"unsupported pathspec magic: {word}"
+					)))
+				}
+			}
+		}
+
+		Ok(entry)
+	}
+}
+
+/// splits `pathspec` at whitespace, quotes and backslashes keep
+/// whitespace in a path like in a shell
+fn split_pathspec(pathspec: &str) -> Result<Vec<String>> {
+	let mut specs = Vec::new();
+	let mut current: Option<String> = None;
+	let mut quote = None;
+	let mut chars = pathspec.chars();
+
+	while let Some(c) = chars.next() {
+		match (quote, c) {
+			(Some(q), c) if c == q => quote = None,
+			(None, '"' | '\'') => {
+				quote = Some(c);
+				current.get_or_insert_with(String::new);
+			}
+			(None | Some('"'), '\\') => {
+				if let Some(next) = chars.next() {
+					current
+						.get_or_insert_with(String::new)
+						.push(next);
+				}
+			}
+			(None, c) if c.is_whitespace() => {
+				specs.extend(current.take());
+			}
+			(_, c) => current.get_or_insert_with(String::new).push(c),
+		}
+	}
+
+	if quote.is_some() {
+		return Err(Error::Generic(String::from(
+			"unterminated quote",
+		)));
+	}
+	specs.extend(current);
+
+	Ok(specs)
+}
+
+/// regex for a `:(glob)` pattern: `*` and `?` stop at `/`, `**/`
+/// matches any number of directories and a match can be a directory
+fn glob_regex(pattern: &str) -> Result<regex::Regex> {
+	let mut res = String::from("^");
+	let mut chars = pattern.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		match c {
+			'*' if chars.peek() == Some(&'*') => {
+				chars.next();
+				if chars.peek() == Some(&'/') {
+					chars.next();
+					res.push_str("(.*/)?");
+				} else {
+					res.push_str(".*");
+				}
+			}
+			'*' => res.push_str("[^/]*"),
+			'?' => res.push_str("[^/]"),
+			c => res.push_str(&regex::escape(&c.to_string())),
+		}
+	}
+	res.push_str("(/.*)?$");
+
+	Ok(regex::Regex::new(&res)?)
+}
+
+/// matches paths against the entries of one kind of a pathspec
+struct PathspecMatcher {
+	pathspec: Option<Pathspec>,
+	globs: Vec<regex::Regex>,
+}
+
+impl PathspecMatcher {
+	fn new<'a>(
+		entries: impl Iterator<Item = &'a PathspecEntry>,
+	) -> Result<Self> {
+		let (globs, plain): (Vec<_>, Vec<_>) =
+			entries.partition(|entry| entry.glob);
+
+		Ok(Self {
+			pathspec: if plain.is_empty() {
+				None
+			} else {
+				Some(Pathspec::new(
+					plain.iter().map(|entry| entry.pattern.as_str()),
+				)?)
+			},
+			globs: globs
+				.iter()
+				.map(|entry| glob_regex(&entry.pattern))
+				.collect::<Result<_>>()?,
+		})
+	}
+
+	const fn is_empty(&self) -> bool {
+		self.pathspec.is_none() && self.globs.is_empty()
+	}
+
+	fn matches(&self, path: &str) -> bool {
+		self.pathspec.as_ref().is_some_and(|pathspec| {
+			pathspec
+				.matches_path(Path::new(path), PathspecFlags::DEFAULT)
+		}) || self.globs.iter().any(|glob| glob.is_match(path))
+	}
+}
+
+/// keeps the `items` matching `pathspec`, a whitespace separated list
+/// of globs like `src/*.rs` matched the way `git add` does.
+///
+/// entries prefixed with `:!`, `:^` or `:(exclude)` drop the files they
+/// match, `:(glob)` entries like `:(glob)src/**/*.rs` use shell glob
+/// rules. quote an entry or escape its spaces with `\` to match paths
+/// with spaces
+pub fn filter_by_pathspec(
+	pathspec: &str,
+	items: Vec<StatusItem>,
+) -> Result<Vec<StatusItem>> {
+	scope_time!("filter_by_pathspec");
+
+	let entries = split_pathspec(pathspec)?
+		.iter()
+		.map(|spec| PathspecEntry::parse(spec))
+		.collect::<Result<Vec<_>>>()?;
+
+	let include =
+		PathspecMatcher::new(entries.iter().filter(|e| !e.exclude))?;
+	let exclude =
+		PathspecMatcher::new(entries.iter().filter(|e| e.exclude))?;
+
+	Ok(items
+		.into_iter()
+		.filter(|item| {
+			// an empty pathspec matches everything
+			(include.is_empty() || include.matches(&item.path))
+				&& !exclude.matches(&item.path)
+		})
+		.collect())
+}
+
 /// Undo last commit in repo
 pub fn undo_last_commit(repo_path: &RepoPath) -> Result<()> {
 	let repo = repo(repo_path)?;
@@ -242,6 +517,83 @@ mod tests {
 		path::Path,
 	};
 
+	#[test]
+	fn test_filter_by_pathspec() {
+		let items =
+			["Cargo.lock", "src/main.rs", "src/a/b.rs", "README.md"]
+				.into_iter()
+				.map(|path| StatusItem {
+					path: path.to_string(),
+					status:
+						crate::sync::status::StatusItemType::Modified,
+				})
+				.collect::<Vec<_>>();
+
+		let filter = |pathspec: &str| {
+			filter_by_pathspec(pathspec, items.clone())
+				.unwrap()
+				.into_iter()
+				.map(|item| item.path)
+				.collect::<Vec<_>>()
+		};
+
+		// like git, without `:(glob)` magic `**/` needs a directory
+		assert_eq!(filter("src/**/*.rs"), vec!["src/a/b.rs"]);
+		assert_eq!(
+			filter("src/*.rs"),
+			vec!["src/main.rs", "src/a/b.rs"]
+		);
+		assert_eq!(filter("src"), vec!["src/main.rs", "src/a/b.rs"]);
+		assert_eq!(
+			filter(":!*.lock"),
+			vec!["src/main.rs", "src/a/b.rs", "README.md"]
+		);
+		assert_eq!(
+			filter("*.md *.lock"),
+			vec!["Cargo.lock", "README.md"]
+		);
+		assert_eq!(filter("src :^src/a"), vec!["src/main.rs"]);
+		assert_eq!(
+			filter(":(glob)src/**/*.rs"),
+			vec!["src/main.rs", "src/a/b.rs"]
+		);
+		assert_eq!(filter(":(glob)src/*.rs"), vec!["src/main.rs"]);
+		assert_eq!(
+			filter(":(glob)src :(exclude,glob)**/b.rs"),
+			vec!["src/main.rs"]
+		);
+		assert!(
+			filter_by_pathspec(":(icase)src", items.clone()).is_err()
+		);
+		assert_eq!(
+			filter(""),
+			items.iter().map(|i| i.path.clone()).collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn test_split_pathspec() {
+		assert_eq!(
+			split_pathspec(r#"a  "b c" d\ e 'f"g' "#).unwrap(),
+			vec!["a", "b c", "d e", "f\"g"]
+		);
+		assert!(split_pathspec("'a").is_err());
+
+		let items = ["my file.txt", "my", "file.txt"]
+			.into_iter()
+			.map(|path| StatusItem {
+				path: path.to_string(),
+				status: crate::sync::status::StatusItemType::Modified,
+			})
+			.collect::<Vec<_>>();
+		let matches = filter_by_pathspec("'my file.txt'", items)
+			.unwrap()
+			.into_iter()
+			.map(|item| item.path)
+			.collect::<Vec<_>>();
+		assert_eq!(matches, vec!["my file.txt"]);
+	}
+
 	#[test]
 	fn test_stage_add_smoke() {
 		let file_path = Path::new("foo");
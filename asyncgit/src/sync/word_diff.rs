@@ -0,0 +1,243 @@
+//! intra-line diff of paired deleted and added lines
+
+use super::diff::{DiffLine, DiffLineType};
+use std::ops::Range;
+
+/// lines with more tokens than this product are not compared to keep
+/// the quadratic comparison cheap
+const MAX_CELLS: usize = 256 * 256;
+
+/// changed byte ranges of the old and the new line
+type ChangedRanges = (Vec<Range<usize>>, Vec<Range<usize>>);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TokenClass {
+	Word,
+	Whitespace,
+	Other,
+}
+
+impl TokenClass {
+	fn of(c: char) -> Self {
+		if c.is_alphanumeric() || c == '_' {
+			Self::Word
+		} else if c.is_whitespace() {
+			Self::Whitespace
+		} else {
+			Self::Other
+		}
+	}
+}
+
+/// splits into runs of word characters, runs of whitespace and
+/// single other characters (punctuation)
+fn tokenize(line: &str) -> Vec<Range<usize>> {
+	let mut tokens: Vec<Range<usize>> = Vec::new();
+	let mut last_class = None;
+
+	for (idx, c) in line.char_indices() {
+		let class = TokenClass::of(c);
+		let end = idx + c.len_utf8();
+
+		match tokens.last_mut() {
+			Some(token)
+				if last_class == Some(class)
+					&& class != TokenClass::Other =>
+			{
+				token.end = end;
+			}
+			_ => tokens.push(idx..end),
+		}
+
+		last_class = Some(class);
+	}
+
+	tokens
+}
+
+/// byte ranges of `old` and `new` that are not part of the longest
+/// common token sequence, `None` if the lines have nothing but
+/// whitespace in common
+pub fn changed_ranges(old: &str, new: &str) -> Option<ChangedRanges> {
+	let old_tokens = tokenize(old);
+	let new_tokens = tokenize(new);
+
+	let (n, m) = (old_tokens.len(), new_tokens.len());
+	if n == 0 || m == 0 || n * m > MAX_CELLS {
+		return None;
+	}
+
+	let token_eq = |i: usize, j: usize| {
+		old[old_tokens[i].clone()] == new[new_tokens[j].clone()]
+	};
+
+	// lcs[i][j]: common tokens of old_tokens[i..] and new_tokens[j..]
+	let width = m + 1;
+	let mut lcs = vec![0_u32; (n + 1) * width];
+	for i in (0..n).rev() {
+		for j in (0..m).rev() {
+			lcs[i * width + j] = if token_eq(i, j) {
+				lcs[(i + 1) * width + j + 1] + 1
+			} else {
+				lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+			};
+		}
+	}
+
+	let mut old_common = vec![false; n];
+	let mut new_common = vec![false; m];
+	let (mut i, mut j) = (0, 0);
+	while i < n && j < m {
+		if token_eq(i, j) {
+			old_common[i] = true;
+			new_common[j] = true;
+			i += 1;
+			j += 1;
+		} else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+			i += 1;
+		} else {
+			j += 1;
+		}
+	}
+
+	let shares_content =
+		old_tokens.iter().zip(&old_common).any(|(token, common)| {
+			*common && !old[token.clone()].trim().is_empty()
+		});
+
+	if !shares_content {
+		return None;
+	}
+
+	Some((
+		merge_ranges(old, &old_tokens, &old_common),
+		merge_ranges(new, &new_tokens, &new_common),
+	))
+}
+
+/// joins changed tokens that are only separated by whitespace
+fn merge_ranges(
+	line: &str,
+	tokens: &[Range<usize>],
+	common: &[bool],
+) -> Vec<Range<usize>> {
+	let mut res: Vec<Range<usize>> = Vec::new();
+
+	for (token, _) in
+		tokens.iter().zip(common).filter(|(_, common)| !**common)
+	{
+		match res.last_mut() {
+			Some(last)
+				if line[last.end..token.start].trim().is_empty() =>
+			{
+				last.end = token.end;
+			}
+			_ => res.push(token.clone()),
+		}
+	}
+
+	res
+}
+
+/// pairs each run of deleted lines with the added lines directly
+/// following it and stores the changed ranges in the lines
+pub fn highlight_hunk(lines: &mut [DiffLine]) {
+	let mut idx = 0;
+
+	while idx < lines.len() {
+		let deleted = lines[idx..]
+			.iter()
+			.take_while(|l| l.line_type == DiffLineType::Delete)
+			.count();
+
+		if deleted == 0 {
+			idx += 1;
+			continue;
+		}
+
+		let added = lines[idx + deleted..]
+			.iter()
+			.take_while(|l| l.line_type == DiffLineType::Add)
+			.count();
+
+		for pair in 0..deleted.min(added) {
+			let old = idx + pair;
+			let new = idx + deleted + pair;
+
+			if let Some((old_ranges, new_ranges)) = changed_ranges(
+				&lines[old].content,
+				&lines[new].content,
+			) {
+				lines[old].word_changes = old_ranges;
+				lines[new].word_changes = new_ranges;
+			}
+		}
+
+		idx += deleted + added;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn test_changed_word() {
+		let old = "timeout = 30 # seconds";
+		let new = "timeout = 300 # seconds";
+
+		let (old_ranges, new_ranges) =
+			changed_ranges(old, new).unwrap();
+
+		assert_eq!(&old[old_ranges[0].clone()], "30");
+		assert_eq!(&new[new_ranges[0].clone()], "300");
+		assert_eq!(old_ranges.len(), 1);
+		assert_eq!(new_ranges.len(), 1);
+	}
+
+	#[test]
+	fn test_merge_across_whitespace() {
+		let old = "let a = foo bar;";
+		let new = "let a = baz qux;";
+
+		let (old_ranges, new_ranges) =
+			changed_ranges(old, new).unwrap();
+
+		assert_eq!(&old[old_ranges[0].clone()], "foo bar");
+		assert_eq!(&new[new_ranges[0].clone()], "baz qux");
+	}
+
+	#[test]
+	fn test_unrelated_lines() {
+		assert_eq!(changed_ranges("foo bar", "baz qux"), None);
+		assert_eq!(changed_ranges("", "foo"), None);
+	}
+
+	#[test]
+	fn test_highlight_hunk_pairs() {
+		let line = |content: &str, line_type| DiffLine {
+			content: content.into(),
+			line_type,
+			..DiffLine::default()
+		};
+
+		let mut lines = vec![
+			line("@@ -1,3 +1,3 @@", DiffLineType::Header),
+			line("a = 1", DiffLineType::Delete),
+			line("b = 2", DiffLineType::Delete),
+			line("a = 10", DiffLineType::Add),
+			line("c", DiffLineType::Add),
+			line("b = 3", DiffLineType::None),
+		];
+
+		highlight_hunk(&mut lines);
+
+		assert_eq!(lines[1].word_changes, vec![4..5]);
+		assert_eq!(lines[3].word_changes, vec![4..6]);
+		// nothing in common
+		assert!(lines[2].word_changes.is_empty());
+		assert!(lines[4].word_changes.is_empty());
+		assert!(lines[5].word_changes.is_empty());
+	}
+}
@@ -12,15 +12,17 @@ use crate::{
 	popup_stack::PopupStack,
 	popups::{
 		AppOption, BlameFilePopup, BranchListPopup,
-		CheckoutOptionPopup, CommitPopup, CompareCommitsPopup,
-		ConfirmPopup, CreateBranchPopup, CreateRemotePopup,
-		ExternalEditorPopup, FetchPopup, FileRevlogPopup,
-		FuzzyFindPopup, GotoLinePopup, HelpPopup, InspectCommitPopup,
-		LogSearchPopupPopup, MsgPopup, OptionsPopup, PullPopup,
-		PushPopup, PushTagsPopup, RemoteListPopup, RenameBranchPopup,
+		CheckoutOptionPopup, ClonePopup, CommitPopup,
+		CompareCommitsPopup, ConfirmPopup, ConflictPopup,
+		CreateBranchPopup, CreateRemotePopup, ExternalEditorPopup,
+		FetchPopup, FileRevlogPopup, FuzzyFindPopup, GotoLinePopup,
+		HelpPopup, InspectCommitPopup, LogRevisionsPopup,
+		LogSearchPopupPopup, MsgPopup, NotePopup, OptionsPopup,
+		PullPopup, PushPopup, PushTagsPopup, RebaseInteractivePopup,
+		ReflogPopup, RemoteListPopup, RenameBranchPopup,
 		RenameRemotePopup, ResetPopup, RevisionFilesPopup,
-		StashMsgPopup, SubmodulesListPopup, TagCommitPopup,
-		TagListPopup, UpdateRemoteUrlPopup,
+		StagePathspecPopup, StashMsgPopup, SubmodulesListPopup,
+		TagCommitPopup, TagListPopup, UpdateRemoteUrlPopup,
 	},
 	queue::{
 		Action, AppTabs, InternalEvent, NeedsUpdate, Queue,
@@ -83,11 +85,14 @@ pub struct App {
 	revision_files_popup: RevisionFilesPopup,
 	fuzzy_find_popup: FuzzyFindPopup,
 	log_search_popup: LogSearchPopupPopup,
+	log_revisions_popup: LogRevisionsPopup,
 	push_popup: PushPopup,
 	push_tags_popup: PushTagsPopup,
 	pull_popup: PullPopup,
 	fetch_popup: FetchPopup,
+	clone_popup: ClonePopup,
 	tag_commit_popup: TagCommitPopup,
+	note_popup: NotePopup,
 	create_branch_popup: CreateBranchPopup,
 	create_remote_popup: CreateRemotePopup,
 	rename_remote_popup: RenameRemotePopup,
@@ -98,7 +103,11 @@ pub struct App {
 	options_popup: OptionsPopup,
 	submodule_popup: SubmodulesListPopup,
 	tags_popup: TagListPopup,
+	reflog_popup: ReflogPopup,
+	conflict_popup: ConflictPopup,
+	stage_pathspec_popup: StagePathspecPopup,
 	reset_popup: ResetPopup,
+	rebase_interactive_popup: RebaseInteractivePopup,
 	checkout_option_popup: CheckoutOptionPopup,
 	cmdbar: RefCell<CommandBar>,
 	tab: usize,
@@ -119,6 +128,7 @@ pub struct App {
 	// "Flags"
 	requires_redraw: Cell<bool>,
 	file_to_open: Option<String>,
+	hunk_to_edit: Option<(String, u64)>,
 }
 
 pub struct Environment {
@@ -187,6 +197,8 @@ impl App {
 				}
 			}
 			2
+		} else if cliargs.log_revisions.is_some() {
+			1
 		} else {
 			env.options.borrow().current_tab()
 		};
@@ -208,9 +220,14 @@ impl App {
 			push_popup: PushPopup::new(&env),
 			push_tags_popup: PushTagsPopup::new(&env),
 			reset_popup: ResetPopup::new(&env),
+			rebase_interactive_popup: RebaseInteractivePopup::new(
+				&env,
+			),
 			pull_popup: PullPopup::new(&env),
 			fetch_popup: FetchPopup::new(&env),
+			clone_popup: ClonePopup::new(&env),
 			tag_commit_popup: TagCommitPopup::new(&env),
+			note_popup: NotePopup::new(&env),
 			create_branch_popup: CreateBranchPopup::new(&env),
 			create_remote_popup: CreateRemotePopup::new(&env),
 			rename_remote_popup: RenameRemotePopup::new(&env),
@@ -219,9 +236,13 @@ impl App {
 			rename_branch_popup: RenameBranchPopup::new(&env),
 			select_branch_popup: BranchListPopup::new(&env),
 			tags_popup: TagListPopup::new(&env),
+			reflog_popup: ReflogPopup::new(&env),
+			conflict_popup: ConflictPopup::new(&env),
+			stage_pathspec_popup: StagePathspecPopup::new(&env),
 			options_popup: OptionsPopup::new(&env),
 			submodule_popup: SubmodulesListPopup::new(&env),
 			log_search_popup: LogSearchPopupPopup::new(&env),
+			log_revisions_popup: LogRevisionsPopup::new(&env),
 			fuzzy_find_popup: FuzzyFindPopup::new(&env),
 			do_quit: QuitState::None,
 			cmdbar: RefCell::new(CommandBar::new(
@@ -244,11 +265,13 @@ impl App {
 			key_config: env.key_config,
 			requires_redraw: Cell::new(false),
 			file_to_open: None,
+			hunk_to_edit: None,
 			repo: env.repo,
 			repo_path_text,
 			popup_stack: PopupStack::default(),
 		};
 
+		app.revlog.set_revisions(cliargs.log_revisions);
 		app.set_tab(tab)?;
 
 		Ok(app)
@@ -360,6 +383,12 @@ impl App {
 				) {
 					self.options_popup.show()?;
 					NeedsUpdate::ALL
+				} else if key_match(
+					k,
+					self.key_config.keys.open_clone,
+				) {
+					self.clone_popup.open()?;
+					NeedsUpdate::ALL
 				} else {
 					NeedsUpdate::empty()
 				};
@@ -371,19 +400,25 @@ impl App {
 		} else if let InputEvent::State(polling_state) = ev {
 			self.external_editor_popup.hide();
 			if matches!(polling_state, InputState::Paused) {
-				let result =
-					if let Some(path) = self.file_to_open.take() {
-						ExternalEditorPopup::open_file_in_editor(
-							&self.repo.borrow(),
-							Path::new(&path),
-						)
-					} else {
-						let changes =
-							self.status_tab.get_files_changes()?;
-						self.commit_popup.show_editor(changes)
-					};
-
-				if let Err(e) = result {
+				if let Some((path, hash)) = self.hunk_to_edit.take() {
+					if let Err(e) = ExternalEditorPopup::edit_hunk(
+						&self.repo.borrow(),
+						&self.key_config,
+						&path,
+						hash,
+						self.options.borrow().diff_options(),
+					) {
+						let msg = format!(
+							"failed to stage edited hunk:\n{e}"
+						);
+						log::error!("{}", msg.as_str());
+						self.msg_popup.show_error(msg.as_str())?;
+					}
+
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL,
+					));
+				} else if let Err(e) = self.open_editor() {
 					let msg =
 						format!("failed to launch editor:\n{e}");
 					log::error!("{}", msg.as_str());
@@ -398,6 +433,18 @@ impl App {
 		Ok(())
 	}
 
+	fn open_editor(&mut self) -> Result<()> {
+		if let Some(path) = self.file_to_open.take() {
+			ExternalEditorPopup::open_file_in_editor(
+				&self.repo.borrow(),
+				Path::new(&path),
+			)
+		} else {
+			let changes = self.status_tab.get_files_changes()?;
+			self.commit_popup.show_editor(changes)
+		}
+	}
+
 	//TODO: do we need this?
 	/// forward ticking to components that require it
 	pub fn update(&mut self) -> Result<()> {
@@ -434,9 +481,14 @@ impl App {
 			self.push_tags_popup.update_git(ev)?;
 			self.pull_popup.update_git(ev);
 			self.fetch_popup.update_git(ev);
+			self.clone_popup.update_git(ev);
 			self.select_branch_popup.update_git(ev)?;
 		}
 
+		self.status_tab.update_async(ev);
+		self.file_revlog_popup.update_async(ev);
+		self.inspect_commit_popup.update_async(ev);
+		self.compare_commits_popup.update_async(ev);
 		self.files_tab.update_async(ev)?;
 		self.blame_file_popup.update_async(ev)?;
 		self.revision_files_popup.update(ev)?;
@@ -475,6 +527,7 @@ impl App {
 			|| self.push_tags_popup.any_work_pending()
 			|| self.pull_popup.any_work_pending()
 			|| self.fetch_popup.any_work_pending()
+			|| self.clone_popup.any_work_pending()
 			|| self.revision_files_popup.any_work_pending()
 			|| self.tags_popup.any_work_pending()
 	}
@@ -496,6 +549,7 @@ impl App {
 		self,
 		[
 			log_search_popup,
+			log_revisions_popup,
 			fuzzy_find_popup,
 			msg_popup,
 			confirm_popup,
@@ -511,8 +565,11 @@ impl App {
 			push_tags_popup,
 			pull_popup,
 			fetch_popup,
+			clone_popup,
 			tag_commit_popup,
+			note_popup,
 			reset_popup,
+			rebase_interactive_popup,
 			checkout_option_popup,
 			create_branch_popup,
 			create_remote_popup,
@@ -524,6 +581,9 @@ impl App {
 			revision_files_popup,
 			submodule_popup,
 			tags_popup,
+			reflog_popup,
+			conflict_popup,
+			stage_pathspec_popup,
 			options_popup,
 			help_popup,
 			revlog,
@@ -546,6 +606,7 @@ impl App {
 			file_revlog_popup,
 			external_editor_popup,
 			tag_commit_popup,
+			note_popup,
 			select_branch_popup,
 			remotes_popup,
 			create_remote_popup,
@@ -553,17 +614,23 @@ impl App {
 			update_remote_url_popup,
 			submodule_popup,
 			tags_popup,
+			reflog_popup,
+			conflict_popup,
+			stage_pathspec_popup,
 			reset_popup,
+			rebase_interactive_popup,
 			checkout_option_popup,
 			create_branch_popup,
 			rename_branch_popup,
 			revision_files_popup,
 			fuzzy_find_popup,
 			log_search_popup,
+			log_revisions_popup,
 			push_popup,
 			push_tags_popup,
 			pull_popup,
 			fetch_popup,
+			clone_popup,
 			options_popup,
 			confirm_popup,
 			msg_popup,
@@ -771,6 +838,9 @@ impl App {
 			InternalEvent::TagCommit(id) => {
 				self.tag_commit_popup.open(id)?;
 			}
+			InternalEvent::EditNote(id) => {
+				self.note_popup.open(id)?;
+			}
 			InternalEvent::CreateRemote => {
 				self.create_remote_popup.open()?;
 			}
@@ -787,6 +857,9 @@ impl App {
 			InternalEvent::CreateBranch => {
 				self.create_branch_popup.open()?;
 			}
+			InternalEvent::CreateBranchAt(id) => {
+				self.create_branch_popup.open_at(id)?;
+			}
 			InternalEvent::RenameBranch(branch_ref, cur_name) => {
 				self.rename_branch_popup
 					.open(branch_ref, cur_name)?;
@@ -800,6 +873,15 @@ impl App {
 			InternalEvent::Tags => {
 				self.tags_popup.open()?;
 			}
+			InternalEvent::ViewReflog(refname) => {
+				self.reflog_popup.open(refname)?;
+			}
+			InternalEvent::OpenConflict(path) => {
+				self.conflict_popup.open(path)?;
+			}
+			InternalEvent::StagePathspec => {
+				self.stage_pathspec_popup.open()?;
+			}
 			InternalEvent::TabSwitchStatus => self.set_tab(0)?,
 			InternalEvent::TabSwitch(tab) => {
 				self.switch_to_tab(&tab)?;
@@ -821,6 +903,12 @@ impl App {
 				self.file_to_open = path;
 				flags.insert(NeedsUpdate::COMMANDS);
 			}
+			InternalEvent::OpenHunkEditor(path, hash) => {
+				self.input.set_polling(false);
+				self.external_editor_popup.show()?;
+				self.hunk_to_edit = Some((path, hash));
+				flags.insert(NeedsUpdate::COMMANDS);
+			}
 			InternalEvent::Push(branch, push_type, force, delete) => {
 				self.push_popup
 					.push(branch, push_type, force, delete)?;
@@ -859,6 +947,15 @@ impl App {
 				flags
 					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
 			}
+			InternalEvent::OpenLogRevisionsPopup(revisions) => {
+				self.log_revisions_popup.open(revisions)?;
+				flags
+					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
+			}
+			InternalEvent::SetLogRevisions(revisions) => {
+				self.revlog.set_revisions(revisions);
+				flags.insert(NeedsUpdate::ALL);
+			}
 			InternalEvent::OptionSwitched(o) => {
 				match o {
 					AppOption::StatusShowUntracked => {
@@ -866,9 +963,22 @@ impl App {
 					}
 					AppOption::DiffContextLines
 					| AppOption::DiffIgnoreWhitespaces
-					| AppOption::DiffInterhunkLines => {
+					| AppOption::DiffIgnoreWhitespaceChange
+					| AppOption::DiffIgnoreBlankLines
+					| AppOption::DiffInterhunkLines
+					| AppOption::DiffAlgorithm
+					| AppOption::DiffSyntax => {
 						self.status_tab.update_diff()?;
 					}
+					AppOption::DiffRenames
+					| AppOption::DiffSimilarity => {
+						self.status_tab.update_diff()?;
+						self.revlog.update()?;
+					}
+					AppOption::LogNotesRef
+					| AppOption::LogSignatures => {
+						self.revlog.update()?;
+					}
 				}
 
 				flags.insert(NeedsUpdate::ALL);
@@ -925,6 +1035,9 @@ impl App {
 			InternalEvent::OpenResetPopup(id) => {
 				self.reset_popup.open(id)?;
 			}
+			InternalEvent::OpenRebaseInteractive(id) => {
+				self.rebase_interactive_popup.open(id)?;
+			}
 			InternalEvent::CommitSearch(options) => {
 				self.revlog.search(options);
 			}
@@ -953,6 +1066,9 @@ impl App {
 			Action::Reset(r) => {
 				self.status_tab.reset(&r);
 			}
+			Action::ResetItems(items) => {
+				self.status_tab.reset_all(&items);
+			}
 			Action::StashDrop(_) | Action::StashPop(_) => {
 				if let Err(e) = self
 					.stashlist_tab
@@ -1024,6 +1140,12 @@ impl App {
 			Action::AbortRebase => {
 				self.status_tab.abort_rebase();
 			}
+			Action::AbortCherryPick => {
+				self.status_tab.abort_cherry_pick();
+			}
+			Action::AbortBisect => {
+				self.status_tab.abort_bisect();
+			}
 			Action::UndoCommit => {
 				try_or_popup!(
 					self,
@@ -1031,6 +1153,13 @@ impl App {
 					undo_last_commit(&self.repo.borrow())
 				);
 			}
+			Action::RemoveNote(id) => {
+				try_or_popup!(
+					self,
+					"remove note failed:",
+					self.remove_note(id)
+				);
+			}
 		}
 
 		flags.insert(NeedsUpdate::ALL);
@@ -1038,6 +1167,18 @@ impl App {
 		Ok(())
 	}
 
+	fn remove_note(&self, id: sync::CommitId) -> Result<()> {
+		let notes_ref = self.options.borrow().notes_ref();
+
+		sync::remove_note(
+			&self.repo.borrow(),
+			notes_ref.as_deref(),
+			id,
+		)?;
+
+		Ok(())
+	}
+
 	fn delete_tag(&mut self, tag_name: String) -> Result<()> {
 		if let Err(error) =
 			sync::delete_tag(&self.repo.borrow(), &tag_name)
@@ -1143,6 +1284,15 @@ impl App {
 			)
 			.order(order::NAV),
 		);
+		res.push(
+			CommandInfo::new(
+				strings::commands::open_clone(&self.key_config),
+				true,
+				!self.any_popup_visible(),
+			)
+			.order(order::NAV)
+			.hidden(),
+		);
 
 		res.push(
 			CommandInfo::new(
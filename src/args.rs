@@ -1,6 +1,6 @@
 use crate::bug_report;
 use anyhow::{anyhow, Context, Result};
-use asyncgit::sync::RepoPath;
+use asyncgit::sync::{clone_dir_name, CloneRequest, RepoPath};
 use clap::{
 	builder::ArgPredicate, crate_authors, crate_description,
 	crate_name, Arg, Command as ClapApp,
@@ -22,6 +22,13 @@ const GIT_DIR_FLAG_ID: &str = "directory";
 const WATCHER_FLAG_ID: &str = "watcher";
 const KEY_BINDINGS_FLAG_ID: &str = "key_bindings";
 const KEY_SYMBOLS_FLAG_ID: &str = "key_symbols";
+const LOG_REVISIONS_FLAG_ID: &str = "log_revisions";
+const CLONE_CMD_ID: &str = "clone";
+const CLONE_URL_ARG_ID: &str = "url";
+const CLONE_DIR_ARG_ID: &str = "clone_directory";
+const CLONE_BRANCH_FLAG_ID: &str = "branch";
+const CLONE_DEPTH_FLAG_ID: &str = "depth";
+const CLONE_SUBMODULES_FLAG_ID: &str = "recurse_submodules";
 const DEFAULT_THEME: &str = "theme.ron";
 const DEFAULT_GIT_DIR: &str = ".";
 
@@ -33,6 +40,8 @@ pub struct CliArgs {
 	pub notify_watcher: bool,
 	pub key_bindings_path: Option<PathBuf>,
 	pub key_symbols_path: Option<PathBuf>,
+	pub log_revisions: Option<String>,
+	pub clone: Option<CloneRequest>,
 }
 
 pub fn process_cmdline() -> Result<CliArgs> {
@@ -92,6 +101,15 @@ pub fn process_cmdline() -> Result<CliArgs> {
 		.get_one::<String>(KEY_SYMBOLS_FLAG_ID)
 		.map(PathBuf::from);
 
+	let log_revisions = arg_matches
+		.get_one::<String>(LOG_REVISIONS_FLAG_ID)
+		.cloned();
+
+	let clone = arg_matches
+		.subcommand_matches(CLONE_CMD_ID)
+		.map(clone_request)
+		.transpose()?;
+
 	Ok(CliArgs {
 		theme,
 		select_file,
@@ -99,6 +117,33 @@ pub fn process_cmdline() -> Result<CliArgs> {
 		notify_watcher,
 		key_bindings_path,
 		key_symbols_path,
+		log_revisions,
+		clone,
+	})
+}
+
+fn clone_request(matches: &clap::ArgMatches) -> Result<CloneRequest> {
+	let url = matches
+		.get_one::<String>(CLONE_URL_ARG_ID)
+		.cloned()
+		.unwrap_or_default();
+	let path = matches
+		.get_one::<String>(CLONE_DIR_ARG_ID)
+		.cloned()
+		.or_else(|| clone_dir_name(&url))
+		.ok_or_else(|| {
+			anyhow!("cannot derive a directory from url: {url}")
+		})?;
+
+	Ok(CloneRequest {
+		url,
+		path: PathBuf::from(path),
+		branch: matches
+			.get_one::<String>(CLONE_BRANCH_FLAG_ID)
+			.cloned(),
+		depth: matches.get_one::<i32>(CLONE_DEPTH_FLAG_ID).copied(),
+		recurse_submodules: matches
+			.get_flag(CLONE_SUBMODULES_FLAG_ID),
 	})
 }
 
@@ -174,6 +219,14 @@ fn app() -> ClapApp {
 				.long("file")
 				.num_args(1),
 		)
+		.arg(
+			Arg::new(LOG_REVISIONS_FLAG_ID)
+				.help("Show these revisions in the log, e.g. `main..feature`, `main...feature` or `--all`")
+				.long("log")
+				.value_name("REVISIONS")
+				.allow_hyphen_values(true)
+				.num_args(1),
+		)
 		.arg(
 			Arg::new(GIT_DIR_FLAG_ID)
 				.help("Set the git directory")
@@ -190,6 +243,42 @@ fn app() -> ClapApp {
 				.env("GIT_WORK_TREE")
 				.num_args(1),
 		)
+		.subcommand(clone_command())
+}
+
+fn clone_command() -> ClapApp {
+	ClapApp::new(CLONE_CMD_ID)
+		.about("Clone a repository and open it")
+		.arg(
+			Arg::new(CLONE_URL_ARG_ID)
+				.help("Url or path of the repository to clone")
+				.required(true),
+		)
+		.arg(
+			Arg::new(CLONE_DIR_ARG_ID)
+				.help("Directory to clone into, named after the repository by default")
+				.value_name("DIRECTORY"),
+		)
+		.arg(
+			Arg::new(CLONE_BRANCH_FLAG_ID)
+				.help("Check out this branch instead of the remote HEAD")
+				.short('b')
+				.long("branch")
+				.num_args(1),
+		)
+		.arg(
+			Arg::new(CLONE_DEPTH_FLAG_ID)
+				.help("Only fetch this many commits")
+				.long("depth")
+				.value_parser(clap::value_parser!(i32).range(1..))
+				.num_args(1),
+		)
+		.arg(
+			Arg::new(CLONE_SUBMODULES_FLAG_ID)
+				.help("Clone all submodules recursively")
+				.long("recurse-submodules")
+				.action(clap::ArgAction::SetTrue),
+		)
 }
 
 fn setup_logging(path_override: Option<PathBuf>) -> Result<()> {
@@ -76,24 +76,20 @@ impl ChangesComponent {
 		self.files.is_file_selected()
 	}
 
-	fn index_add_remove(&self) -> Result<bool> {
-		if let Some(tree_item) = self.selection() {
-			if self.is_working_dir {
-				if let FileTreeItemKind::File(i) = tree_item.kind {
-					let path = Path::new(i.path.as_str());
-					match i.status {
-						StatusItemType::Deleted => {
-							sync::stage_addremoved(
-								&self.repo.borrow(),
-								path,
-							)?;
-						}
-						_ => sync::stage_add_file(
-							&self.repo.borrow(),
-							path,
-						)?,
-					}
-				} else {
+	fn index_add_remove(&mut self) -> Result<bool> {
+		let marked = self.files.marked_items();
+
+		if !marked.is_empty() {
+			for item in &marked {
+				self.index_add_remove_file(item)?;
+			}
+			self.files.clear_marked();
+		} else if let Some(tree_item) = self.selection() {
+			match tree_item.kind {
+				FileTreeItemKind::File(i) => {
+					self.index_add_remove_file(&i)?;
+				}
+				FileTreeItemKind::Path(_) if self.is_working_dir => {
 					let config =
 						self.options.borrow().status_show_untracked();
 
@@ -104,28 +100,49 @@ impl ChangesComponent {
 						config,
 					)?;
 				}
-
-				//TODO: this might be slow in big repos,
-				// in theory we should be able to ask the tree structure
-				// if we are currently on a leaf or a lonely branch that
-				// would mean that after staging the workdir becomes empty
-				if sync::is_workdir_clean(
-					&self.repo.borrow(),
-					self.options.borrow().status_show_untracked(),
-				)? {
-					self.queue
-						.push(InternalEvent::StatusLastFileMoved);
+				FileTreeItemKind::Path(_) => {
+					// this is a staged path, so lets unstage it
+					sync::reset_stage(
+						&self.repo.borrow(),
+						tree_item.info.full_path.as_str(),
+					)?;
 				}
-			} else {
-				// this is a staged entry, so lets unstage it
-				let path = tree_item.info.full_path.as_str();
-				sync::reset_stage(&self.repo.borrow(), path)?;
 			}
+		} else {
+			return Ok(false);
+		}
 
-			return Ok(true);
+		//TODO: this might be slow in big repos,
+		// in theory we should be able to ask the tree structure
+		// if we are currently on a leaf or a lonely branch that
+		// would mean that after staging the workdir becomes empty
+		if self.is_working_dir
+			&& sync::is_workdir_clean(
+				&self.repo.borrow(),
+				self.options.borrow().status_show_untracked(),
+			)? {
+			self.queue.push(InternalEvent::StatusLastFileMoved);
 		}
 
-		Ok(false)
+		Ok(true)
+	}
+
+	fn index_add_remove_file(&self, item: &StatusItem) -> Result<()> {
+		let path = Path::new(item.path.as_str());
+
+		if !self.is_working_dir {
+			// this is a staged entry, so lets unstage it
+			sync::reset_stage(
+				&self.repo.borrow(),
+				item.path.as_str(),
+			)?;
+		} else if item.status == StatusItemType::Deleted {
+			sync::stage_addremoved(&self.repo.borrow(), path)?;
+		} else {
+			sync::stage_add_file(&self.repo.borrow(), path)?;
+		}
+
+		Ok(())
 	}
 
 	fn index_add_all(&self) -> Result<()> {
@@ -147,6 +164,21 @@ impl ChangesComponent {
 	}
 
 	fn dispatch_reset_workdir(&self) -> bool {
+		let marked = self.files.marked_items();
+
+		if !marked.is_empty() {
+			self.queue.push(InternalEvent::ConfirmAction(
+				Action::ResetItems(
+					marked
+						.into_iter()
+						.map(|item| ResetItem { path: item.path })
+						.collect(),
+				),
+			));
+
+			return true;
+		}
+
 		if let Some(tree_item) = self.selection() {
 			self.queue.push(InternalEvent::ConfirmAction(
 				Action::Reset(ResetItem {
@@ -159,16 +191,27 @@ impl ChangesComponent {
 		false
 	}
 
-	fn add_to_ignore(&self) -> bool {
-		if let Some(tree_item) = self.selection() {
-			if let Err(e) = sync::add_to_ignore(
+	fn selected_untracked_file(&self) -> Option<String> {
+		self.selection().and_then(|tree_item| match tree_item.kind {
+			FileTreeItemKind::File(i)
+				if self.is_working_dir
+					&& i.status == StatusItemType::New =>
+			{
+				Some(i.path)
+			}
+			_ => None,
+		})
+	}
+
+	fn intent_to_add(&self) -> bool {
+		if let Some(path) = self.selected_untracked_file() {
+			if let Err(e) = sync::stage_intent_to_add(
 				&self.repo.borrow(),
-				&tree_item.info.full_path,
+				Path::new(&path),
 			) {
 				self.queue.push(InternalEvent::ShowErrorMsg(
 					format!(
-						"ignore error:\n{}\nfile:\n{:?}",
-						e, tree_item.info.full_path
+						"intent to add error:\n{e}\nfile:\n{path:?}"
 					),
 				));
 			} else {
@@ -181,6 +224,64 @@ impl ChangesComponent {
 
 		false
 	}
+
+	fn selected_conflicted_file(&self) -> Option<String> {
+		self.selection().and_then(|tree_item| match tree_item.kind {
+			FileTreeItemKind::File(i)
+				if i.status == StatusItemType::Conflicted =>
+			{
+				Some(i.path)
+			}
+			_ => None,
+		})
+	}
+
+	fn open_conflict(&self) -> bool {
+		if let Some(path) = self.selected_conflicted_file() {
+			self.queue.push(InternalEvent::OpenConflict(path));
+
+			return true;
+		}
+
+		false
+	}
+
+	fn add_to_ignore(&mut self) -> bool {
+		let marked = self.files.marked_items();
+
+		let paths = if marked.is_empty() {
+			self.selection()
+				.map(|tree_item| tree_item.info.full_path)
+				.into_iter()
+				.collect()
+		} else {
+			marked
+				.into_iter()
+				.map(|item| item.path)
+				.collect::<Vec<_>>()
+		};
+
+		if paths.is_empty() {
+			return false;
+		}
+
+		for path in &paths {
+			if let Err(e) =
+				sync::add_to_ignore(&self.repo.borrow(), path)
+			{
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("ignore error:\n{e}\nfile:\n{path:?}"),
+				));
+
+				return false;
+			}
+		}
+
+		self.files.clear_marked();
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		true
+	}
 }
 
 impl DrawableComponent for ChangesComponent {
@@ -222,6 +323,22 @@ impl Component for ChangesComponent {
 				true,
 				some_selection && self.focused(),
 			));
+			out.push(CommandInfo::new(
+				strings::commands::intent_to_add_item(
+					&self.key_config,
+				),
+				true,
+				self.selected_untracked_file().is_some()
+					&& self.focused(),
+			));
+			out.push(CommandInfo::new(
+				strings::commands::resolve_conflict_item(
+					&self.key_config,
+				),
+				true,
+				self.selected_conflicted_file().is_some()
+					&& self.focused(),
+			));
 		} else {
 			out.push(CommandInfo::new(
 				strings::commands::unstage_item(&self.key_config),
@@ -235,6 +352,12 @@ impl Component for ChangesComponent {
 			));
 		}
 
+		out.push(CommandInfo::new(
+			strings::commands::stage_pathspec(&self.key_config),
+			true,
+			some_selection && self.focused(),
+		));
+
 		CommandBlocking::PassingOn
 	}
 
@@ -289,6 +412,23 @@ impl Component for ChangesComponent {
 					&& !self.is_empty()
 				{
 					Ok(self.add_to_ignore().into())
+				} else if key_match(
+					e,
+					self.key_config.keys.status_intent_to_add,
+				) && self.is_working_dir
+				{
+					Ok(self.intent_to_add().into())
+				} else if key_match(
+					e,
+					self.key_config.keys.status_resolve_conflict,
+				) {
+					Ok(self.open_conflict().into())
+				} else if key_match(
+					e,
+					self.key_config.keys.status_stage_pathspec,
+				) {
+					self.queue.push(InternalEvent::StagePathspec);
+					Ok(EventState::Consumed)
 				} else {
 					Ok(EventState::NotConsumed)
 				};
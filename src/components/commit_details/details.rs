@@ -8,12 +8,18 @@ use crate::{
 		EventState, ScrollType,
 	},
 	keys::{key_match, SharedKeyConfig},
+	options::SharedOptions,
 	strings::{self, order},
 	ui::style::SharedTheme,
 };
 use anyhow::Result;
-use asyncgit::sync::{
-	self, CommitDetails, CommitId, CommitMessage, RepoPathRef, Tag,
+use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	sync::{
+		self, CommitDetails, CommitId, CommitMessage, RepoPathRef,
+		SignatureStatus, Tag,
+	},
+	AsyncGitNotification, AsyncSignaturesJob,
 };
 use crossterm::event::Event;
 use ratatui::{
@@ -30,6 +36,11 @@ use super::style::Detail;
 pub struct DetailsComponent {
 	repo: RepoPathRef,
 	data: Option<CommitDetails>,
+	note: Option<String>,
+	/// cached, `None` while `gpg` or `ssh-keygen` verify it in the
+	/// background
+	signature: Option<(CommitId, Option<SignatureStatus>)>,
+	git_signature: AsyncSingleJob<AsyncSignaturesJob>,
 	tags: Vec<Tag>,
 	theme: SharedTheme,
 	focused: bool,
@@ -37,6 +48,7 @@ pub struct DetailsComponent {
 	scroll: VerticalScroll,
 	scroll_to_bottom_next_draw: Cell<bool>,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 }
 
 type WrappedCommitMessage<'a> =
@@ -48,6 +60,11 @@ impl DetailsComponent {
 		Self {
 			repo: env.repo.clone(),
 			data: None,
+			note: None,
+			signature: None,
+			git_signature: AsyncSingleJob::new(
+				env.sender_git.clone(),
+			),
 			tags: Vec::new(),
 			theme: env.theme.clone(),
 			focused,
@@ -55,6 +72,7 @@ impl DetailsComponent {
 			current_width: Cell::new(0),
 			scroll: VerticalScroll::new(),
 			key_config: env.key_config.clone(),
+			options: env.options.clone(),
 		}
 	}
 
@@ -69,6 +87,19 @@ impl DetailsComponent {
 			sync::get_commit_details(&self.repo.borrow(), id).ok()
 		});
 
+		let notes_ref = self.options.borrow().notes_ref();
+		self.note = id.and_then(|id| {
+			sync::get_note(
+				&self.repo.borrow(),
+				notes_ref.as_deref(),
+				id,
+			)
+			.ok()
+			.flatten()
+		});
+
+		self.update_signature(id);
+
 		self.scroll.reset();
 
 		if let Some(tags) = tags {
@@ -76,10 +107,63 @@ impl DetailsComponent {
 		}
 	}
 
-	fn wrap_commit_details(
-		message: &CommitMessage,
+	/// verifies the signature in the background, if enabled in the
+	/// options
+	fn update_signature(&mut self, id: Option<CommitId>) {
+		let enabled = self.options.borrow().log_signatures();
+		let Some(id) = id.filter(|_| enabled) else {
+			self.signature = None;
+			return;
+		};
+
+		if self
+			.signature
+			.as_ref()
+			.is_some_and(|(sig_id, _)| *sig_id == id)
+		{
+			return;
+		}
+
+		self.signature = Some((id, None));
+		self.git_signature.spawn(AsyncSignaturesJob::new(
+			self.repo.borrow().clone(),
+			vec![id],
+		));
+	}
+
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.git_signature.is_pending()
+	}
+
+	///
+	pub fn update_git(&mut self, ev: AsyncGitNotification) {
+		if ev != AsyncGitNotification::Signatures {
+			return;
+		}
+
+		let Some(signatures) = self
+			.git_signature
+			.take_last()
+			.and_then(|job| job.result())
+		else {
+			return;
+		};
+
+		for (id, status) in signatures {
+			if let Some((sig_id, sig_status)) = &mut self.signature {
+				if *sig_id == id {
+					*sig_status = Some(status);
+				}
+			}
+		}
+	}
+
+	fn wrap_commit_details<'a>(
+		message: &'a CommitMessage,
+		note: Option<&'a str>,
 		width: usize,
-	) -> WrappedCommitMessage<'_> {
+	) -> WrappedCommitMessage<'a> {
 		let width = width.max(1);
 		let wrapped_title = bwrap::wrap!(&message.subject, width)
 			.lines()
@@ -87,27 +171,43 @@ impl DetailsComponent {
 			.map(Cow::from)
 			.collect();
 
-		if let Some(ref body) = message.body {
-			let wrapped_message: Vec<Cow<'_, str>> =
+		let mut wrapped_message: Vec<Cow<'_, str>> = message
+			.body
+			.as_ref()
+			.map(|body| {
 				bwrap::wrap!(body, width)
 					.lines()
 					.map(String::from)
 					.map(Cow::from)
-					.collect();
-
-			(wrapped_title, wrapped_message)
-		} else {
-			(wrapped_title, vec![])
+					.collect()
+			})
+			.unwrap_or_default();
+
+		// same layout as `git log` shows notes
+		if let Some(note) = note {
+			wrapped_message.push(Cow::from(""));
+			wrapped_message
+				.push(Cow::from(strings::commit::details_notes()));
+			wrapped_message.extend(
+				bwrap::wrap!(note, width.saturating_sub(4).max(1))
+					.lines()
+					.map(|line| Cow::from(format!("    {line}"))),
+			);
 		}
+
+		(wrapped_title, wrapped_message)
 	}
 
-	fn get_wrapped_lines(
-		data: Option<&CommitDetails>,
+	fn get_wrapped_lines<'a>(
+		data: Option<&'a CommitDetails>,
+		note: Option<&'a str>,
 		width: usize,
-	) -> WrappedCommitMessage<'_> {
+	) -> WrappedCommitMessage<'a> {
 		if let Some(data) = data {
 			if let Some(message) = &data.message {
-				return Self::wrap_commit_details(message, width);
+				return Self::wrap_commit_details(
+					message, note, width,
+				);
 			}
 		}
 
@@ -116,10 +216,11 @@ impl DetailsComponent {
 
 	fn get_number_of_lines(
 		details: Option<&CommitDetails>,
+		note: Option<&str>,
 		width: usize,
 	) -> usize {
 		let (wrapped_title, wrapped_message) =
-			Self::get_wrapped_lines(details, width);
+			Self::get_wrapped_lines(details, note, width);
 
 		wrapped_title.len() + wrapped_message.len()
 	}
@@ -138,7 +239,11 @@ impl DetailsComponent {
 		height: usize,
 	) -> Vec<Line<'_>> {
 		let (wrapped_title, wrapped_message) =
-			Self::get_wrapped_lines(self.data.as_ref(), width);
+			Self::get_wrapped_lines(
+				self.data.as_ref(),
+				self.note.as_deref(),
+				width,
+			);
 
 		[&wrapped_title[..], &wrapped_message[..]]
 			.concat()
@@ -217,6 +322,20 @@ impl DetailsComponent {
 				),
 			]));
 
+			if let Some((_, Some(status))) = &self.signature {
+				res.push(Line::from(vec![
+					style_detail(&self.theme, &Detail::Signature),
+					Span::styled(
+						Cow::from(
+							strings::commit::details_signature_status(
+								status,
+							),
+						),
+						self.theme.commit_signature(status, false),
+					),
+				]));
+			}
+
 			if !self.tags.is_empty() {
 				res.push(Line::from(style_detail(
 					&self.theme,
@@ -261,7 +380,7 @@ impl DrawableComponent for DetailsComponent {
 		let chunks = Layout::default()
 			.direction(Direction::Vertical)
 			.constraints(
-				[Constraint::Length(8), Constraint::Min(10)].as_ref(),
+				[Constraint::Length(9), Constraint::Min(10)].as_ref(),
 			)
 			.split(rect);
 
@@ -288,6 +407,7 @@ impl DrawableComponent for DetailsComponent {
 
 		let number_of_lines = Self::get_number_of_lines(
 			self.data.as_ref(),
+			self.note.as_deref(),
 			usize::from(width),
 		);
 
@@ -341,8 +461,11 @@ impl Component for DetailsComponent {
 		force_all: bool,
 	) -> CommandBlocking {
 		let width = usize::from(self.current_width.get());
-		let number_of_lines =
-			Self::get_number_of_lines(self.data.as_ref(), width);
+		let number_of_lines = Self::get_number_of_lines(
+			self.data.as_ref(),
+			self.note.as_deref(),
+			width,
+		);
 
 		out.push(
 			CommandInfo::new(
@@ -425,7 +548,9 @@ mod tests {
 		width: usize,
 	) -> Vec<Cow<'_, str>> {
 		let (wrapped_title, wrapped_message) =
-			DetailsComponent::wrap_commit_details(message, width);
+			DetailsComponent::wrap_commit_details(
+				message, None, width,
+			);
 
 		[&wrapped_title[..], &wrapped_message[..]].concat()
 	}
@@ -486,6 +611,24 @@ mod tests {
 			]
 		);
 	}
+
+	#[test]
+	fn test_textwrap_note() {
+		let message = CommitMessage::from("Commit message");
+
+		let (wrapped_title, wrapped_message) =
+			DetailsComponent::wrap_commit_details(
+				&message,
+				Some("deployed to prod"),
+				14,
+			);
+
+		assert_eq!(wrapped_title, vec!["Commit message"]);
+		assert_eq!(
+			wrapped_message,
+			vec!["", "Notes:", "    deployed", "    to prod"]
+		);
+	}
 }
 
 #[cfg(test)]
@@ -503,12 +646,14 @@ mod test_line_count {
 		};
 		let lines = DetailsComponent::get_number_of_lines(
 			Some(commit.clone()).as_ref(),
+			None,
 			50,
 		);
 		assert_eq!(lines, 2);
 
 		let lines = DetailsComponent::get_number_of_lines(
 			Some(commit).as_ref(),
+			None,
 			8,
 		);
 		assert_eq!(lines, 4);
@@ -10,12 +10,13 @@ use crate::{
 	accessors,
 	app::Environment,
 	keys::{key_match, SharedKeyConfig},
+	options::SharedOptions,
 	strings,
 };
 use anyhow::Result;
 use asyncgit::{
 	sync::{commit_files::OldNew, CommitTags},
-	AsyncCommitFiles, CommitFilesParams,
+	AsyncCommitFiles, AsyncGitNotification, CommitFilesParams,
 };
 use compare_details::CompareDetailsComponent;
 use crossterm::event::Event;
@@ -33,6 +34,7 @@ pub struct CommitDetailsComponent {
 	git_commit_files: AsyncCommitFiles,
 	visible: bool,
 	key_config: SharedKeyConfig,
+	options: SharedOptions,
 }
 
 impl CommitDetailsComponent {
@@ -51,6 +53,7 @@ impl CommitDetailsComponent {
 			visible: false,
 			commit: None,
 			key_config: env.key_config.clone(),
+			options: env.options.clone(),
 		}
 	}
 
@@ -75,6 +78,9 @@ impl CommitDetailsComponent {
 			self.compare_details.set_commits(None);
 		}
 
+		let params = params.map(|params| {
+			params.with_options(self.options.borrow().diff_options())
+		});
 		self.commit = params;
 
 		if let Some(id) = params {
@@ -113,6 +119,12 @@ impl CommitDetailsComponent {
 	///
 	pub fn any_work_pending(&self) -> bool {
 		self.git_commit_files.is_pending()
+			|| self.single_details.any_work_pending()
+	}
+
+	///
+	pub fn update_git(&mut self, ev: AsyncGitNotification) {
+		self.single_details.update_git(ev);
 	}
 
 	///
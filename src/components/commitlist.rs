@@ -15,7 +15,7 @@ use crate::{
 use anyhow::Result;
 use asyncgit::sync::{
 	self, checkout_commit, BranchDetails, BranchInfo, CommitId,
-	RepoPathRef, Tags,
+	GraphRow, GraphSymbol, RepoPathRef, SignatureStatus, Tags,
 };
 use chrono::{DateTime, Local};
 use crossterm::event::Event;
@@ -29,7 +29,11 @@ use ratatui::{
 	Frame,
 };
 use std::{
-	borrow::Cow, cell::Cell, cmp, collections::BTreeMap, rc::Rc,
+	borrow::Cow,
+	cell::Cell,
+	cmp,
+	collections::{BTreeMap, HashMap},
+	rc::Rc,
 	time::Instant,
 };
 
@@ -45,10 +49,15 @@ pub struct CommitList {
 	items: ItemBatch,
 	highlights: Option<Rc<IndexSet<CommitId>>>,
 	commits: IndexSet<CommitId>,
+	/// graph lanes, indexed like `commits` (empty if not available)
+	graph: Vec<GraphRow>,
 	/// The marked commits.
 	/// `self.marked[].0` holds the commit index into `self.items.items` - used for ordering the list.
 	/// `self.marked[].1` is the commit id of the marked commit.
 	marked: Vec<(usize, CommitId)>,
+	/// verified signatures, only rendered if `show_signatures`
+	signatures: HashMap<CommitId, SignatureStatus>,
+	show_signatures: bool,
 	scroll_state: (Instant, f32),
 	tags: Option<Tags>,
 	local_branches: BTreeMap<CommitId, Vec<BranchInfo>>,
@@ -67,9 +76,12 @@ impl CommitList {
 			repo: env.repo.clone(),
 			items: ItemBatch::default(),
 			marked: Vec::with_capacity(2),
+			signatures: HashMap::new(),
+			show_signatures: false,
 			selection: 0,
 			highlighted_selection: None,
 			commits: IndexSet::new(),
+			graph: Vec::new(),
 			highlights: None,
 			scroll_state: (Instant::now(), 0_f32),
 			tags: None,
@@ -84,6 +96,11 @@ impl CommitList {
 		}
 	}
 
+	///
+	pub fn set_title(&mut self, title: &str) {
+		self.title = title.into();
+	}
+
 	///
 	pub const fn tags(&self) -> Option<&Tags> {
 		self.tags.as_ref()
@@ -93,6 +110,7 @@ impl CommitList {
 	pub fn clear(&mut self) {
 		self.items.clear();
 		self.commits.clear();
+		self.graph.clear();
 	}
 
 	///
@@ -209,6 +227,7 @@ impl CommitList {
 	pub fn set_commits(&mut self, commits: IndexSet<CommitId>) {
 		if commits != self.commits {
 			self.items.clear();
+			self.graph.clear();
 			self.commits = commits;
 			self.fetch_commits(false);
 		}
@@ -227,6 +246,39 @@ impl CommitList {
 		}
 	}
 
+	/// append graph rows for commits added via `refresh_extend_data`
+	pub fn extend_graph(&mut self, graph: Vec<GraphRow>) {
+		self.graph.extend(graph);
+	}
+
+	///
+	pub const fn set_show_signatures(&mut self, show: bool) {
+		self.show_signatures = show;
+	}
+
+	///
+	pub fn extend_signatures(
+		&mut self,
+		signatures: Vec<(CommitId, SignatureStatus)>,
+	) {
+		self.signatures.extend(signatures);
+	}
+
+	/// visible commits whose signature was not verified yet
+	pub fn unverified_visible_commits(&self) -> Vec<CommitId> {
+		let height = self
+			.current_size()
+			.map_or(0, |(_, height)| usize::from(height));
+
+		self.items
+			.iter()
+			.skip(self.scroll_top.get())
+			.take(height)
+			.map(|e| e.id)
+			.filter(|id| !self.signatures.contains_key(id))
+			.collect()
+	}
+
 	///
 	pub fn set_highlighting(
 		&mut self,
@@ -451,6 +503,7 @@ impl CommitList {
 		width: usize,
 		now: DateTime<Local>,
 		marked: Option<bool>,
+		graph: Option<(&GraphRow, usize)>,
 	) -> Line<'a> {
 		let mut txt: Vec<Span> = Vec::with_capacity(
 			ELEMENTS_PER_LINE + if marked.is_some() { 2 } else { 0 },
@@ -518,6 +571,20 @@ impl CommitList {
 
 		txt.push(splitter.clone());
 
+		// signature status
+		if self.show_signatures {
+			txt.push(self.signature_span(e, theme, selected, normal));
+			txt.push(splitter.clone());
+		}
+
+		// commit graph
+		if let Some((row, lanes)) = graph {
+			txt.extend(Self::graph_spans(
+				row, lanes, theme, selected, normal,
+			));
+			txt.push(splitter.clone());
+		}
+
 		// commit timestamp
 		txt.push(Span::styled(
 			Cow::from(e.time_to_string(now)),
@@ -552,9 +619,8 @@ impl CommitList {
 
 		txt.push(splitter);
 
-		let message_width = width.saturating_sub(
-			txt.iter().map(|span| span.content.len()).sum(),
-		);
+		let message_width =
+			width.saturating_sub(txt.iter().map(Span::width).sum());
 
 		// commit msg
 		txt.push(Span::styled(
@@ -565,6 +631,106 @@ impl CommitList {
 		Line::from(txt)
 	}
 
+	fn graph_spans<'a>(
+		row: &GraphRow,
+		lanes: usize,
+		theme: &Theme,
+		selected: bool,
+		normal: bool,
+	) -> Vec<Span<'a>> {
+		let style = |color: u8| {
+			if normal {
+				theme.commit_graph(color, selected)
+			} else {
+				theme.commit_unhighlighted()
+			}
+		};
+
+		let mut spans = Vec::with_capacity(lanes * 2);
+
+		for idx in 0..lanes {
+			let cell = row.cells.get(idx);
+
+			let symbol = cell.map_or(symbol::EMPTY_SPACE, |cell| {
+				match cell.symbol {
+					GraphSymbol::Empty => symbol::EMPTY_SPACE,
+					GraphSymbol::Commit => symbol::GRAPH_COMMIT,
+					GraphSymbol::MergeCommit => {
+						symbol::GRAPH_MERGE_COMMIT
+					}
+					GraphSymbol::Vertical => symbol::GRAPH_VERTICAL,
+					GraphSymbol::Horizontal => {
+						symbol::GRAPH_HORIZONTAL
+					}
+					GraphSymbol::Crossing => symbol::GRAPH_CROSSING,
+					GraphSymbol::UpRight => symbol::GRAPH_UP_RIGHT,
+					GraphSymbol::UpLeft => symbol::GRAPH_UP_LEFT,
+					GraphSymbol::DownRight => {
+						symbol::GRAPH_DOWN_RIGHT
+					}
+					GraphSymbol::DownLeft => symbol::GRAPH_DOWN_LEFT,
+					GraphSymbol::VerticalRight => {
+						symbol::GRAPH_VERTICAL_RIGHT
+					}
+					GraphSymbol::VerticalLeft => {
+						symbol::GRAPH_VERTICAL_LEFT
+					}
+				}
+			});
+
+			spans.push(Span::styled(
+				symbol,
+				style(cell.map_or(0, |cell| cell.color)),
+			));
+
+			// no trailing connector after the last lane
+			if idx + 1 < lanes {
+				spans.push(
+					cell.and_then(|cell| cell.connector).map_or_else(
+						|| {
+							Span::styled(
+								symbol::EMPTY_SPACE,
+								style(0),
+							)
+						},
+						|color| {
+							Span::styled(
+								symbol::GRAPH_HORIZONTAL,
+								style(color),
+							)
+						},
+					),
+				);
+			}
+		}
+
+		spans
+	}
+
+	fn signature_span(
+		&self,
+		e: &LogEntry,
+		theme: &Theme,
+		selected: bool,
+		normal: bool,
+	) -> Span<'static> {
+		let Some(status) = self.signatures.get(&e.id) else {
+			return Span::styled(
+				Cow::from(symbol::EMPTY_SPACE),
+				theme.text(true, selected),
+			);
+		};
+
+		Span::styled(
+			Cow::from(status.marker().to_string()),
+			if normal {
+				theme.commit_signature(status, selected)
+			} else {
+				theme.commit_unhighlighted()
+			},
+		)
+	}
+
 	fn get_text(&self, height: usize, width: usize) -> Vec<Line<'_>> {
 		let selection = self.relative_selection();
 
@@ -574,6 +740,20 @@ impl CommitList {
 
 		let any_marked = !self.marked.is_empty();
 
+		let graph_offset =
+			self.items.index_offset() + self.scroll_top.get();
+		let graph_rows = self
+			.graph
+			.get(graph_offset..)
+			.map(|rows| &rows[..height.min(rows.len())])
+			.unwrap_or_default();
+		// align all visible rows to the widest one
+		let graph_lanes = graph_rows
+			.iter()
+			.map(|row| row.cells.len())
+			.max()
+			.unwrap_or_default();
+
 		for (idx, e) in self
 			.items
 			.iter()
@@ -616,6 +796,7 @@ impl CommitList {
 				width,
 				now,
 				marked,
+				graph_rows.get(idx).map(|row| (row, graph_lanes)),
 			));
 		}
 
@@ -910,7 +1091,10 @@ mod tests {
 				tags: Option::None,
 				items: ItemBatch::default(),
 				commits: IndexSet::default(),
+				graph: Vec::default(),
 				marked: Vec::default(),
+				signatures: HashMap::default(),
+				show_signatures: false,
 				scroll_top: Cell::default(),
 				local_branches: BTreeMap::default(),
 				remote_branches: BTreeMap::default(),
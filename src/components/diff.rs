@@ -12,24 +12,118 @@ use crate::{
 	string_utils::tabs_to_spaces,
 	string_utils::trim_offset,
 	strings, try_or_popup,
-	ui::style::SharedTheme,
+	ui::{
+		style::{SharedTheme, Theme},
+		AsyncDiffSyntaxJob, DiffSyntax,
+	},
+	AsyncAppNotification, AsyncNotification,
 };
 use anyhow::Result;
 use asyncgit::{
+	asyncjob::AsyncSingleJob,
 	hash,
-	sync::{self, diff::DiffLinePosition, RepoPathRef},
+	sync::{
+		self, diff::DiffLinePosition, BlobSummary, HexDiff,
+		RepoPathRef, HEX_ROW_LEN,
+	},
 	DiffLine, DiffLineType, FileDiff,
 };
 use bytesize::ByteSize;
 use crossterm::event::Event;
 use ratatui::{
 	layout::Rect,
+	style::Style,
 	symbols,
 	text::{Line, Span},
 	widgets::{Block, Borders, Paragraph},
 	Frame,
 };
-use std::{borrow::Cow, cell::Cell, cmp, path::Path};
+use std::{borrow::Cow, cell::Cell, cmp, ops::Range, path::Path};
+use unicode_truncate::UnicodeTruncateStr;
+use unicode_width::UnicodeWidthStr;
+
+/// glyphs shown for whitespace when `diff_show_whitespace` is on,
+/// a tab keeps the two columns `tabs_to_spaces` gives it
+const GLYPH_TAB: &str = "→ ";
+const GLYPH_TRAILING_SPACE: &str = "·";
+const GLYPH_CARRIAGE_RETURN: &str = "␍";
+const GLYPH_NO_NEWLINE: &str = "∅ ";
+
+/// describes one side of a binary diff, `None` if the file does not
+/// exist on that side
+fn describe_blob(summary: Option<&BlobSummary>) -> String {
+	match summary {
+		None => String::from("none"),
+		// hash shortened like a commit id, keeping its `sha256:` prefix
+		Some(BlobSummary::LfsPointer { oid, size }) => format!(
+			"lfs {} ({})",
+			oid.get(..oid.find(':').map_or(0, |i| i + 1) + 8)
+				.unwrap_or(oid),
+			ByteSize::b(*size)
+		),
+		Some(BlobSummary::Image {
+			format,
+			width,
+			height,
+		}) => format!("{format} image {width}x{height}"),
+		Some(BlobSummary::Binary) => String::from("binary"),
+	}
+}
+
+/// hexdump rows of both sides, rows that are equal on both sides are
+/// shown once as context
+fn hex_rows(hex: &HexDiff) -> Vec<(DiffLineType, String)> {
+	let row = |prefix: char, offset: usize, bytes: &[u8]| {
+		let hex_bytes: String = (0..HEX_ROW_LEN)
+			.map(|i| {
+				bytes.get(i).map_or_else(
+					|| String::from("   "),
+					|b| format!("{b:02x} "),
+				)
+			})
+			.collect();
+		let ascii: String = bytes
+			.iter()
+			.map(|b| {
+				if b.is_ascii_graphic() || *b == b' ' {
+					char::from(*b)
+				} else {
+					'.'
+				}
+			})
+			.collect();
+
+		format!("{prefix}{offset:08x}  {hex_bytes} |{ascii}|")
+	};
+
+	let old_rows: Vec<&[u8]> = hex.old.chunks(HEX_ROW_LEN).collect();
+	let new_rows: Vec<&[u8]> = hex.new.chunks(HEX_ROW_LEN).collect();
+
+	let mut res = Vec::new();
+	for i in 0..cmp::max(old_rows.len(), new_rows.len()) {
+		let offset = hex.offset + i * HEX_ROW_LEN;
+		let (old, new) = (old_rows.get(i), new_rows.get(i));
+
+		if old == new {
+			if let Some(bytes) = old {
+				res.push((
+					DiffLineType::None,
+					row(' ', offset, bytes),
+				));
+			}
+			continue;
+		}
+
+		if let Some(bytes) = old {
+			res.push((DiffLineType::Delete, row('-', offset, bytes)));
+		}
+		if let Some(bytes) = new {
+			res.push((DiffLineType::Add, row('+', offset, bytes)));
+		}
+	}
+
+	res
+}
 
 #[derive(Default)]
 struct Current {
@@ -101,6 +195,118 @@ impl Selection {
 	}
 }
 
+/// row of the side-by-side layout, holding indices into the diff lines
+/// of all hunks. headers and context lines use the same line on both
+/// sides, a missing side is rendered as a filler
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct SplitRow {
+	old: Option<usize>,
+	new: Option<usize>,
+	hunk: usize,
+	end_of_hunk: bool,
+}
+
+/// side-by-side layout of a diff
+#[derive(Default)]
+struct SplitLayout {
+	rows: Vec<SplitRow>,
+	/// row of each diff line
+	line_rows: Vec<usize>,
+}
+
+impl SplitLayout {
+	fn new(diff: Option<&FileDiff>) -> Self {
+		let Some(diff) = diff else {
+			return Self::default();
+		};
+
+		let mut rows: Vec<SplitRow> = Vec::with_capacity(diff.lines);
+		let mut line_rows = Vec::with_capacity(diff.lines);
+		let mut base = 0;
+
+		for (hunk_idx, hunk) in diff.hunks.iter().enumerate() {
+			let lines = &hunk.lines;
+			let mut idx = 0;
+
+			while idx < lines.len() {
+				// a missing newline marker ends the run of its side
+				let count = |from: usize,
+				             typ: DiffLineType,
+				             eofnl: DiffLineType| {
+					let run = lines[from..]
+						.iter()
+						.take_while(|l| l.line_type == typ)
+						.count();
+					run + usize::from(
+						lines
+							.get(from + run)
+							.is_some_and(|l| l.line_type == eofnl),
+					)
+				};
+				let deleted = |from: usize| {
+					count(
+						from,
+						DiffLineType::Delete,
+						DiffLineType::AddEOFNL,
+					)
+				};
+				let added = |from: usize| {
+					count(
+						from,
+						DiffLineType::Add,
+						DiffLineType::DeleteEOFNL,
+					)
+				};
+
+				let (deleted, added) = match lines[idx].line_type {
+					DiffLineType::Delete | DiffLineType::AddEOFNL => {
+						let deleted = deleted(idx);
+						(deleted, added(idx + deleted))
+					}
+					DiffLineType::Add | DiffLineType::DeleteEOFNL => {
+						(0, added(idx))
+					}
+					DiffLineType::Header | DiffLineType::None => {
+						line_rows.push(rows.len());
+						rows.push(SplitRow {
+							old: Some(base + idx),
+							new: Some(base + idx),
+							hunk: hunk_idx,
+							end_of_hunk: false,
+						});
+						idx += 1;
+						continue;
+					}
+				};
+
+				let first_row = rows.len();
+				for pair in 0..deleted.max(added) {
+					rows.push(SplitRow {
+						old: (pair < deleted)
+							.then_some(base + idx + pair),
+						new: (pair < added)
+							.then_some(base + idx + deleted + pair),
+						hunk: hunk_idx,
+						end_of_hunk: false,
+					});
+				}
+				line_rows.extend(first_row..first_row + deleted);
+				line_rows.extend(first_row..first_row + added);
+
+				idx += deleted + added;
+			}
+
+			if let Some(last) = rows.last_mut() {
+				last.end_of_hunk = true;
+			}
+
+			base += lines.len();
+		}
+
+		Self { rows, line_rows }
+	}
+}
+
 ///
 pub struct DiffComponent {
 	repo: RepoPathRef,
@@ -109,6 +315,12 @@ pub struct DiffComponent {
 	pending: bool,
 	selection: Selection,
 	selected_hunk: Option<usize>,
+	/// side-by-side layout, selection and scrolling then work on its
+	/// rows instead of diff lines
+	split: Option<SplitLayout>,
+	/// highlighting of `diff`, if its hash matches
+	syntax: Option<DiffSyntax>,
+	syntax_job: AsyncSingleJob<AsyncDiffSyntaxJob>,
 	current_size: Cell<(u16, u16)>,
 	focused: bool,
 	current: Current,
@@ -130,6 +342,13 @@ impl DiffComponent {
 			current: Current::default(),
 			pending: false,
 			selected_hunk: None,
+			split: env
+				.options
+				.borrow()
+				.diff_split()
+				.then(SplitLayout::default),
+			syntax: None,
+			syntax_job: AsyncSingleJob::new(env.sender_app.clone()),
 			diff: None,
 			longest_line: 0,
 			current_size: Cell::new((0, 0)),
@@ -155,10 +374,32 @@ impl DiffComponent {
 	const fn can_edit_file(&self) -> bool {
 		!self.is_immutable && !self.current.path.is_empty()
 	}
+
+	fn can_edit_hunk(&self) -> bool {
+		!self.is_immutable
+			&& !self.is_stage()
+			&& self.selected_hunk.is_some()
+			&& self.diff.as_ref().is_some_and(|diff| !diff.untracked)
+	}
+
+	fn edit_hunk(&self) {
+		if let (Some(diff), Some(hunk)) =
+			(&self.diff, self.selected_hunk)
+		{
+			self.queue.push(InternalEvent::OpenHunkEditor(
+				self.current.path.clone(),
+				diff.hunks[hunk].header_hash,
+			));
+		}
+	}
 	///
 	pub fn clear(&mut self, pending: bool) {
 		self.current = Current::default();
 		self.diff = None;
+		if let Some(split) = &mut self.split {
+			*split = SplitLayout::default();
+		}
+		self.syntax = None;
 		self.longest_line = 0;
 		self.vertical_scroll.reset();
 		self.horizontal_scroll.reset();
@@ -175,6 +416,9 @@ impl DiffComponent {
 	) {
 		self.pending = false;
 
+		let split = self.options.borrow().diff_split();
+		self.set_split(split);
+
 		let hash = hash(&diff);
 
 		if self.current.hash != hash {
@@ -186,6 +430,10 @@ impl DiffComponent {
 				hash,
 			};
 
+			if let Some(split) = &mut self.split {
+				*split = SplitLayout::new(Some(&diff));
+			}
+			self.highlight_syntax(&diff);
 			self.diff = Some(diff);
 
 			self.longest_line = self
@@ -218,12 +466,17 @@ impl DiffComponent {
 				};
 				self.update_selection(old_selection);
 			}
+		} else if self.syntax.is_none()
+			&& !self.syntax_job.is_pending()
+		{
+			// highlighting was switched on since
+			self.highlight_syntax(&diff);
 		}
 	}
 
 	fn move_selection(&mut self, move_type: ScrollType) {
-		if let Some(diff) = &self.diff {
-			let max = diff.lines.saturating_sub(1);
+		if self.diff.is_some() {
+			let max = self.lines_count().saturating_sub(1);
 
 			let new_start = match move_type {
 				ScrollType::Down => {
@@ -254,16 +507,119 @@ impl DiffComponent {
 
 	fn update_selection(&mut self, new_start: usize) {
 		if let Some(diff) = &self.diff {
-			let max = diff.lines.saturating_sub(1);
+			let max = self.lines_count().saturating_sub(1);
 			let new_start = cmp::min(max, new_start);
 			self.selection = Selection::Single(new_start);
-			self.selected_hunk =
-				Self::find_selected_hunk(diff, new_start);
+			self.selected_hunk = Self::find_selected_hunk(
+				diff,
+				self.row_to_line(new_start),
+			);
 		}
 	}
 
+	///
+	pub fn update_async(&mut self, ev: AsyncNotification) {
+		if ev
+			== AsyncNotification::App(
+				AsyncAppNotification::DiffHighlighting,
+			) {
+			if let Some(syntax) = self
+				.syntax_job
+				.take_last()
+				.and_then(|job| job.result())
+			{
+				if syntax.hash() == self.current.hash {
+					self.syntax = Some(syntax);
+				}
+			}
+		}
+	}
+
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.syntax_job.is_pending()
+	}
+
+	fn highlight_syntax(&mut self, diff: &FileDiff) {
+		self.syntax = None;
+
+		if diff.hunks.is_empty()
+			|| !self.options.borrow().diff_syntax()
+		{
+			return;
+		}
+
+		let lines = diff
+			.hunks
+			.iter()
+			.flat_map(|hunk| hunk.lines.iter())
+			.map(|line| (line.line_type, line.content.to_string()))
+			.collect();
+
+		self.syntax_job.spawn(AsyncDiffSyntaxJob::new(
+			self.current.hash,
+			self.current.path.clone(),
+			lines,
+			self.theme.get_syntax(),
+		));
+	}
+
+	fn line_syntax(&self, line: usize) -> &[(Style, Range<usize>)] {
+		self.syntax
+			.as_ref()
+			.filter(|syntax| {
+				syntax.hash() == self.current.hash
+					&& self.options.borrow().diff_syntax()
+			})
+			.map_or(&[], |syntax| syntax.line(line))
+	}
+
+	/// number of selectable rows, diff lines or split rows
 	fn lines_count(&self) -> usize {
-		self.diff.as_ref().map_or(0, |diff| diff.lines)
+		self.split.as_ref().map_or_else(
+			|| self.diff.as_ref().map_or(0, |diff| diff.lines),
+			|split| split.rows.len(),
+		)
+	}
+
+	/// first diff line shown in `row`
+	fn row_to_line(&self, row: usize) -> usize {
+		self.split.as_ref().map_or(row, |split| {
+			split
+				.rows
+				.get(row)
+				.and_then(|row| row.old.or(row.new))
+				.unwrap_or_default()
+		})
+	}
+
+	fn line_to_row(&self, line: usize) -> usize {
+		self.split.as_ref().map_or(line, |split| {
+			split.line_rows.get(line).copied().unwrap_or_default()
+		})
+	}
+
+	/// in split layout both lines of a selected row count as selected,
+	/// a paired removal and addition can't be picked one at a time
+	fn is_line_selected(&self, line: usize) -> bool {
+		self.selection.contains(self.line_to_row(line))
+	}
+
+	/// switches the layout keeping the selected line
+	fn set_split(&mut self, split: bool) {
+		if self.split.is_some() != split {
+			let line = self.row_to_line(self.selection.get_start());
+			self.split =
+				split.then(|| SplitLayout::new(self.diff.as_ref()));
+			self.vertical_scroll.reset();
+			self.update_selection(self.line_to_row(line));
+		}
+	}
+
+	fn toggle_split(&mut self) {
+		self.options.borrow_mut().diff_toggle_split();
+		let split = self.options.borrow().diff_split();
+		self.set_split(split);
 	}
 
 	fn max_scroll_right(&self) -> usize {
@@ -285,7 +641,7 @@ impl DiffComponent {
 					.flat_map(|hunk| hunk.lines.iter())
 					.enumerate()
 					.filter_map(|(i, line)| {
-						if self.selection.contains(i) {
+						if self.is_line_selected(i) {
 							Some(line.content.trim_matches(|c| {
 								c == '\n' || c == '\r'
 							}))
@@ -332,6 +688,8 @@ impl DiffComponent {
 		if let Some(diff) = &self.diff {
 			return if diff.hunks.is_empty() {
 				self.get_text_binary(diff)
+			} else if let Some(split) = &self.split {
+				self.get_text_split(diff, split, height)
 			} else {
 				let mut res: Vec<Line> = Vec::new();
 
@@ -341,6 +699,9 @@ impl DiffComponent {
 				let mut line_cursor = 0_usize;
 				let mut lines_added = 0_usize;
 
+				let whitespace =
+					self.options.borrow().diff_show_whitespace();
+
 				for (i, hunk) in diff.hunks.iter().enumerate() {
 					let hunk_selected = self.focused()
 						&& self.selected_hunk.is_some_and(|s| s == i);
@@ -364,6 +725,7 @@ impl DiffComponent {
 								res.push(Self::get_line_to_add(
 									width,
 									line,
+									self.line_syntax(line_cursor),
 									self.focused()
 										&& self
 											.selection
@@ -373,6 +735,7 @@ impl DiffComponent {
 									&self.theme,
 									self.horizontal_scroll
 										.get_right(),
+									whitespace,
 								));
 								lines_added += 1;
 							}
@@ -396,7 +759,7 @@ impl DiffComponent {
 		let delta_byte_size =
 			ByteSize::b(diff.size_delta.unsigned_abs());
 		let sign = if is_positive { "+" } else { "-" };
-		vec![Line::from(vec![
+		let size = Line::from(vec![
 			Span::raw(Cow::from("size: ")),
 			Span::styled(
 				Cow::from(format!("{}", ByteSize::b(diff.sizes.0))),
@@ -420,17 +783,70 @@ impl DiffComponent {
 				),
 			),
 			Span::raw(Cow::from(")")),
-		])]
+		]);
+
+		let Some(binary) = &diff.binary else {
+			return vec![size];
+		};
+
+		let blob_line =
+			|typ: DiffLineType, summary: Option<&BlobSummary>| {
+				let (prefix, label) = if typ == DiffLineType::Delete {
+					("-", "old: ")
+				} else {
+					("+", "new: ")
+				};
+
+				Line::from(vec![
+					Span::raw(Cow::from(label)),
+					Span::styled(
+						Cow::from(format!(
+							"{prefix}{}",
+							describe_blob(summary)
+						)),
+						self.theme.diff_line(typ, false),
+					),
+				])
+			};
+
+		let mut res = vec![
+			size,
+			blob_line(DiffLineType::Delete, binary.old.as_ref()),
+			blob_line(DiffLineType::Add, binary.new.as_ref()),
+		];
+
+		if let Some(hex) = &binary.hex {
+			res.push(Line::from(format!(
+				"first change at offset {:#x}:",
+				hex.first_change
+			)));
+			res.extend(hex_rows(hex).into_iter().map(
+				|(typ, row)| {
+					Line::from(Span::styled(
+						row,
+						self.theme.diff_line(typ, false),
+					))
+				},
+			));
+		}
+
+		res
 	}
 
-	fn get_line_to_add<'a>(
+	#[allow(
+		clippy::too_many_arguments,
+		clippy::fn_params_excessive_bools
+	)]
+	pub(super) fn get_line_to_add<'a>(
 		width: u16,
 		line: &'a DiffLine,
+		syntax: &[(Style, Range<usize>)],
 		selected: bool,
 		selected_hunk: bool,
 		end_of_hunk: bool,
 		theme: &SharedTheme,
 		scrolled_right: usize,
+		whitespace: bool,
 	) -> Line<'a> {
 		let style = theme.diff_hunk_marker(selected_hunk);
 
@@ -452,6 +868,23 @@ impl DiffComponent {
 			}
 		};
 
+		if whitespace
+			|| !line.word_changes.is_empty()
+			|| !syntax.is_empty()
+		{
+			let mut spans = vec![left_side_of_line];
+			spans.extend(Self::highlighted_spans(
+				width,
+				line,
+				syntax,
+				selected,
+				theme,
+				scrolled_right,
+				whitespace,
+			));
+			return Line::from(spans);
+		}
+
 		let content =
 			if !is_content_line && line.content.as_ref().is_empty() {
 				theme.line_break()
@@ -477,6 +910,308 @@ impl DiffComponent {
 		])
 	}
 
+	/// splits the line by `DiffLine::word_changes` and its syntax
+	fn highlighted_spans<'a>(
+		width: u16,
+		line: &DiffLine,
+		syntax: &[(Style, Range<usize>)],
+		selected: bool,
+		theme: &SharedTheme,
+		scrolled_right: usize,
+		whitespace: bool,
+	) -> Vec<Span<'a>> {
+		let (mut spans, used_width) = Self::content_spans(
+			line,
+			syntax,
+			selected,
+			theme,
+			scrolled_right,
+			None,
+			whitespace,
+		);
+
+		let filler = if selected {
+			" ".repeat((width as usize).saturating_sub(used_width))
+		} else {
+			String::new()
+		};
+		spans.push(Span::styled(
+			Cow::from(format!("{filler}\n")),
+			theme.diff_line(line.line_type, selected),
+		));
+
+		spans
+	}
+
+	/// content of `line` scrolled by `scrolled_right` and clipped to
+	/// `max_width`, returns the spans and their width
+	fn content_spans<'a>(
+		line: &DiffLine,
+		syntax: &[(Style, Range<usize>)],
+		selected: bool,
+		theme: &SharedTheme,
+		scrolled_right: usize,
+		max_width: Option<usize>,
+		whitespace: bool,
+	) -> (Vec<Span<'a>>, usize) {
+		let style = theme.diff_line(line.line_type, selected);
+
+		let mut segments: Vec<(Cow<str>, Style)> = Vec::new();
+		if line.content.is_empty()
+			&& line.line_type != DiffLineType::None
+		{
+			segments.push((Cow::from(theme.line_break()), style));
+		} else {
+			// split wherever the word diff or the syntax changes
+			let mut bounds = vec![0, line.content.len()];
+			bounds.extend(
+				line.word_changes
+					.iter()
+					.chain(syntax.iter().map(|(_, range)| range))
+					.flat_map(|range| [range.start, range.end]),
+			);
+			bounds.sort_unstable();
+			bounds.dedup();
+
+			for window in bounds.windows(2) {
+				let (start, end) = (window[0], window[1]);
+				let Some(text) = line.content.get(start..end) else {
+					continue;
+				};
+
+				let base = syntax
+					.iter()
+					.find(|(_, range)| range.contains(&start))
+					.map_or(style, |(syntax, _)| {
+						theme.diff_line_syntax(
+							line.line_type,
+							*syntax,
+							selected,
+						)
+					});
+				let segment_style = if line
+					.word_changes
+					.iter()
+					.any(|range| range.contains(&start))
+				{
+					Theme::diff_word_change(base)
+				} else {
+					base
+				};
+
+				match segments.last_mut() {
+					Some((last, last_style))
+						if *last_style == segment_style =>
+					{
+						last.to_mut().push_str(text);
+					}
+					_ => segments
+						.push((Cow::from(text), segment_style)),
+				}
+			}
+		}
+
+		if whitespace {
+			segments = Self::whitespace_glyphs(
+				line, segments, theme, selected,
+			);
+		}
+
+		let mut spans = Vec::with_capacity(segments.len() + 1);
+		let mut offset = scrolled_right;
+		let mut used_width = 0;
+
+		for (text, segment_style) in segments {
+			let text = tabs_to_spaces(text.into_owned());
+			let mut visible = trim_offset(&text, offset);
+			offset = offset.saturating_sub(text.width());
+
+			if let Some(max_width) = max_width {
+				visible = visible
+					.unicode_truncate(
+						max_width.saturating_sub(used_width),
+					)
+					.0;
+			}
+
+			if visible.is_empty() {
+				continue;
+			}
+
+			used_width += visible.width();
+			spans.push(Span::styled(
+				Cow::from(visible.to_string()),
+				segment_style,
+			));
+		}
+
+		(spans, used_width)
+	}
+
+	/// replaces tabs and trailing whitespace in the `segments` of
+	/// `line` by glyphs and marks a carriage return or a missing
+	/// newline at the end of the file
+	fn whitespace_glyphs<'a>(
+		line: &DiffLine,
+		segments: Vec<(Cow<'a, str>, Style)>,
+		theme: &SharedTheme,
+		selected: bool,
+	) -> Vec<(Cow<'a, str>, Style)> {
+		let glyph_style = theme.diff_whitespace(selected);
+		let trailing = line.content.trim_end().len();
+
+		let mut res = Vec::with_capacity(segments.len());
+		if matches!(
+			line.line_type,
+			DiffLineType::AddEOFNL | DiffLineType::DeleteEOFNL
+		) {
+			res.push((Cow::from(GLYPH_NO_NEWLINE), glyph_style));
+		}
+
+		let mut offset = 0;
+		for (text, style) in segments {
+			let mut run = String::new();
+			for (idx, c) in text.char_indices() {
+				let glyph = if c == '\t' {
+					GLYPH_TAB
+				} else if c.is_whitespace()
+					&& offset + idx >= trailing
+				{
+					GLYPH_TRAILING_SPACE
+				} else {
+					run.push(c);
+					continue;
+				};
+
+				if !run.is_empty() {
+					res.push((
+						Cow::from(std::mem::take(&mut run)),
+						style,
+					));
+				}
+				res.push((Cow::from(glyph), glyph_style));
+			}
+			offset += text.len();
+
+			if !run.is_empty() {
+				res.push((Cow::from(run), style));
+			}
+		}
+
+		if line.carriage_return {
+			res.push((Cow::from(GLYPH_CARRIAGE_RETURN), glyph_style));
+		}
+
+		res
+	}
+
+	fn get_text_split<'a>(
+		&'a self,
+		diff: &'a FileDiff,
+		split: &'a SplitLayout,
+		height: u16,
+	) -> Vec<Line<'a>> {
+		let lines: Vec<&DiffLine> =
+			diff.hunks.iter().flat_map(|h| h.lines.iter()).collect();
+
+		let width = usize::from(self.current_size.get().0);
+		// hunk marker and separator take one column each
+		let half = width.saturating_sub(2) / 2;
+		let scrolled_right = self.horizontal_scroll.get_right();
+		let whitespace = self.options.borrow().diff_show_whitespace();
+
+		let half_spans = |line: Option<usize>, selected: bool| {
+			let (mut spans, used) = line
+				.and_then(|idx| {
+					lines.get(idx).map(|line| (idx, line))
+				})
+				.map(|(idx, line)| {
+					Self::content_spans(
+						line,
+						self.line_syntax(idx),
+						selected,
+						&self.theme,
+						scrolled_right,
+						Some(half),
+						whitespace,
+					)
+				})
+				.unwrap_or_default();
+			spans.push(Span::styled(
+				Cow::from(" ".repeat(half.saturating_sub(used))),
+				self.theme.diff_line(DiffLineType::None, selected),
+			));
+			spans
+		};
+
+		split
+			.rows
+			.iter()
+			.enumerate()
+			.skip(self.vertical_scroll.get_top())
+			.take(height as usize)
+			.map(|(idx, row)| {
+				let selected =
+					self.focused() && self.selection.contains(idx);
+				let marker_style = self.theme.diff_hunk_marker(
+					self.focused()
+						&& self.selected_hunk == Some(row.hunk),
+				);
+
+				let line_type = row
+					.old
+					.or(row.new)
+					.and_then(|idx| lines.get(idx))
+					.map(|line| line.line_type)
+					.unwrap_or_default();
+
+				let marker = if row.end_of_hunk {
+					symbols::line::BOTTOM_LEFT
+				} else if line_type == DiffLineType::Header {
+					symbols::line::TOP_LEFT
+				} else {
+					symbols::line::VERTICAL
+				};
+
+				let mut spans = vec![Span::styled(
+					Cow::from(marker),
+					marker_style,
+				)];
+
+				if line_type == DiffLineType::Header {
+					let (header, used) = Self::content_spans(
+						lines[row.old.unwrap_or_default()],
+						&[],
+						selected,
+						&self.theme,
+						0,
+						Some(width.saturating_sub(1)),
+						false,
+					);
+					spans.extend(header);
+					spans.push(Span::styled(
+						Cow::from(
+							" ".repeat(
+								width.saturating_sub(1 + used),
+							),
+						),
+						self.theme
+							.diff_line(DiffLineType::None, selected),
+					));
+				} else {
+					spans.extend(half_spans(row.old, selected));
+					spans.push(Span::styled(
+						Cow::from(symbols::line::VERTICAL),
+						self.theme.diff_hunk_marker(false),
+					));
+					spans.extend(half_spans(row.new, selected));
+				}
+
+				Line::from(spans)
+			})
+			.collect()
+	}
+
 	const fn hunk_visible(
 		hunk_min: usize,
 		hunk_max: usize,
@@ -569,24 +1304,21 @@ impl DiffComponent {
 	}
 
 	fn stage_lines(&self) {
-		if let Some(diff) = &self.diff {
-			//TODO: support untracked files as well
-			if !diff.untracked {
-				let selected_lines = self.selected_lines();
-
-				try_or_popup!(
-					self,
-					"(un)stage lines:",
-					sync::stage_lines(
-						&self.repo.borrow(),
-						&self.current.path,
-						self.is_stage(),
-						&selected_lines,
-					)
-				);
+		if self.diff.is_some() {
+			let selected_lines = self.selected_lines();
 
-				self.queue_update();
-			}
+			try_or_popup!(
+				self,
+				"(un)stage lines:",
+				sync::stage_lines(
+					&self.repo.borrow(),
+					&self.current.path,
+					self.is_stage(),
+					&selected_lines,
+				)
+			);
+
+			self.queue_update();
 		}
 	}
 
@@ -602,7 +1334,7 @@ impl DiffComponent {
 						let is_add_or_delete = line.line_type
 							== DiffLineType::Add
 							|| line.line_type == DiffLineType::Delete;
-						if self.selection.contains(i)
+						if self.is_line_selected(i)
 							&& is_add_or_delete
 						{
 							Some(line.position)
@@ -666,13 +1398,17 @@ impl DiffComponent {
 				.iter()
 				.take(hunk_index)
 				.fold(0, |sum, hunk| sum + hunk.lines.len());
-			let hunk = &diff.hunks[hunk_index];
-			self.selection = Selection::Single(line_index);
+			let hunk_end = line_index
+				.saturating_add(diff.hunks[hunk_index].lines.len());
+			let row = self.line_to_row(line_index);
+			let row_end =
+				self.line_to_row(hunk_end.saturating_sub(1)) + 1;
+			self.selection = Selection::Single(row);
 			self.selected_hunk = Some(hunk_index);
 			self.vertical_scroll.move_area_to_visible(
 				self.current_size.get().1 as usize,
-				line_index,
-				line_index.saturating_add(hunk.lines.len()),
+				row,
+				row_end,
 			);
 		}
 	}
@@ -764,6 +1500,16 @@ impl Component for DiffComponent {
 			self.calc_hunk_move_target(-1) != self.selected_hunk,
 			self.focused(),
 		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_toggle_split(&self.key_config),
+			true,
+			self.focused(),
+		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_show_whitespace(&self.key_config),
+			true,
+			self.focused(),
+		));
 		out.push(
 			CommandInfo::new(
 				strings::commands::diff_home_end(&self.key_config),
@@ -789,6 +1535,11 @@ impl Component for DiffComponent {
 				self.selected_hunk.is_some(),
 				self.focused() && !self.is_stage(),
 			));
+			out.push(CommandInfo::new(
+				strings::commands::diff_hunk_edit(&self.key_config),
+				self.can_edit_hunk(),
+				self.focused() && !self.is_stage(),
+			));
 			out.push(CommandInfo::new(
 				strings::commands::diff_hunk_revert(&self.key_config),
 				self.selected_hunk.is_some(),
@@ -885,6 +1636,20 @@ impl Component for DiffComponent {
 				) {
 					self.diff_hunk_move_up_down(-1);
 					Ok(EventState::Consumed)
+				} else if key_match(
+					e,
+					self.key_config.keys.diff_toggle_split,
+				) {
+					self.toggle_split();
+					Ok(EventState::Consumed)
+				} else if key_match(
+					e,
+					self.key_config.keys.diff_show_whitespace,
+				) {
+					self.options
+						.borrow_mut()
+						.diff_toggle_show_whitespace();
+					Ok(EventState::Consumed)
 				} else if key_match(e, self.key_config.keys.edit_file)
 					&& self.can_edit_file()
 				{
@@ -894,6 +1659,13 @@ impl Component for DiffComponent {
 						)),
 					);
 					Ok(EventState::Consumed)
+				} else if key_match(
+					e,
+					self.key_config.keys.diff_edit_hunk,
+				) && self.can_edit_hunk()
+				{
+					self.edit_hunk();
+					Ok(EventState::Consumed)
 				} else if key_match(
 					e,
 					self.key_config.keys.stage_unstage_item,
@@ -966,17 +1738,287 @@ mod tests {
 	use crate::{
 		app::Environment, queue::InternalEvent, ui::style::Theme,
 	};
+	use asyncgit::sync::diff::Hunk;
 	use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+	use ratatui::style::Color;
 	use std::io::Write;
 	use std::rc::Rc;
 	use tempfile::NamedTempFile;
 
+	#[test]
+	fn test_split_rows() {
+		let line = |line_type| DiffLine {
+			line_type,
+			..DiffLine::default()
+		};
+		let diff = FileDiff {
+			hunks: vec![asyncgit::sync::diff::Hunk {
+				header_hash: 0,
+				lines: vec![
+					line(DiffLineType::Header),
+					line(DiffLineType::None),
+					line(DiffLineType::Delete),
+					line(DiffLineType::Delete),
+					line(DiffLineType::Add),
+					line(DiffLineType::None),
+					line(DiffLineType::Add),
+				],
+			}],
+			lines: 7,
+			..FileDiff::default()
+		};
+
+		let SplitLayout { rows, line_rows } =
+			SplitLayout::new(Some(&diff));
+		let sides: Vec<_> =
+			rows.iter().map(|row| (row.old, row.new)).collect();
+
+		assert_eq!(
+			sides,
+			vec![
+				(Some(0), Some(0)),
+				(Some(1), Some(1)),
+				(Some(2), Some(4)),
+				(Some(3), None),
+				(Some(5), Some(5)),
+				(None, Some(6)),
+			]
+		);
+		assert_eq!(line_rows, vec![0, 1, 2, 3, 2, 4, 5]);
+		assert!(rows[5].end_of_hunk);
+		assert!(!rows[4].end_of_hunk);
+	}
+
+	#[test]
+	fn test_split_rows_eofnl() {
+		let line = |line_type| DiffLine {
+			line_type,
+			..DiffLine::default()
+		};
+		let diff = FileDiff {
+			hunks: vec![asyncgit::sync::diff::Hunk {
+				header_hash: 0,
+				lines: vec![
+					line(DiffLineType::Header),
+					line(DiffLineType::Delete),
+					line(DiffLineType::AddEOFNL),
+					line(DiffLineType::Add),
+					line(DiffLineType::Add),
+					line(DiffLineType::DeleteEOFNL),
+				],
+			}],
+			lines: 6,
+			..FileDiff::default()
+		};
+
+		let SplitLayout { rows, .. } = SplitLayout::new(Some(&diff));
+		let sides: Vec<_> =
+			rows.iter().map(|row| (row.old, row.new)).collect();
+
+		assert_eq!(
+			sides,
+			vec![
+				(Some(0), Some(0)),
+				(Some(1), Some(3)),
+				(Some(2), Some(4)),
+				(None, Some(5)),
+			]
+		);
+	}
+
+	#[test]
+	fn test_word_changes() {
+		let diff_line = DiffLine {
+			content: "a = 10".into(),
+			line_type: DiffLineType::Add,
+			position: Default::default(),
+			word_changes: vec![4..6],
+			carriage_return: false,
+		};
+		let theme = Rc::new(Theme::default());
+
+		let line = DiffComponent::get_line_to_add(
+			10,
+			&diff_line,
+			&[],
+			false,
+			false,
+			false,
+			&theme,
+			2,
+			false,
+		);
+
+		assert_eq!(
+			line.spans[1..],
+			[
+				Span::styled(
+					Cow::from("= "),
+					theme.diff_line(DiffLineType::Add, false)
+				),
+				Span::styled(
+					Cow::from("10"),
+					Theme::diff_word_change(
+						theme.diff_line(DiffLineType::Add, false)
+					)
+				),
+				Span::styled(
+					Cow::from("\n"),
+					theme.diff_line(DiffLineType::Add, false)
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn test_syntax_spans() {
+		let diff_line = DiffLine {
+			content: "let x".into(),
+			line_type: DiffLineType::Add,
+			..DiffLine::default()
+		};
+		let keyword = Style::default().fg(Color::Red);
+		let line_to_add = |theme: &SharedTheme| {
+			DiffComponent::get_line_to_add(
+				10,
+				&diff_line,
+				&[(keyword, 0..3)],
+				false,
+				false,
+				false,
+				theme,
+				0,
+				false,
+			)
+		};
+
+		// without a background added lines keep their plain color
+		let theme = Rc::new(Theme::default());
+		assert_eq!(
+			line_to_add(&theme).spans[1..],
+			[
+				Span::styled(
+					Cow::from("let x"),
+					theme.diff_line(DiffLineType::Add, false)
+				),
+				Span::styled(
+					Cow::from("\n"),
+					theme.diff_line(DiffLineType::Add, false)
+				),
+			]
+		);
+
+		let mut file = NamedTempFile::new().unwrap();
+		writeln!(file, r##"(diff_line_add_bg: Some("#1f3a24"))"##)
+			.unwrap();
+		let theme = Rc::new(Theme::init(&file.path().to_path_buf()));
+
+		assert_eq!(
+			line_to_add(&theme).spans[1..],
+			[
+				Span::styled(
+					Cow::from("let"),
+					theme.diff_line_syntax(
+						DiffLineType::Add,
+						keyword,
+						false
+					)
+				),
+				Span::styled(
+					Cow::from(" x"),
+					theme.diff_line(DiffLineType::Add, false)
+				),
+				Span::styled(
+					Cow::from("\n"),
+					theme.diff_line(DiffLineType::Add, false)
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn test_binary_summary() {
+		assert_eq!(
+			describe_blob(Some(&BlobSummary::LfsPointer {
+				oid: String::from("sha256:4d7a214614ab2935"),
+				size: 2048,
+			})),
+			"lfs sha256:4d7a2146 (2.0 KiB)"
+		);
+
+		let old: Vec<u8> = (b'a'..=b'z').collect();
+		let mut new = old.clone();
+		new[20] = 0;
+		let rows = hex_rows(&HexDiff {
+			first_change: 20,
+			offset: 0,
+			old,
+			new,
+		});
+
+		assert_eq!(
+			rows,
+			vec![
+				(
+					DiffLineType::None,
+					String::from(" 00000000  61 62 63 64 65 66 67 68 69 6a 6b 6c 6d 6e 6f 70  |abcdefghijklmnop|")
+				),
+				(
+					DiffLineType::Delete,
+					String::from("-00000010  71 72 73 74 75 76 77 78 79 7a                    |qrstuvwxyz|")
+				),
+				(
+					DiffLineType::Add,
+					String::from("+00000010  71 72 73 74 00 76 77 78 79 7a                    |qrst.vwxyz|")
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn test_whitespace_glyphs() {
+		let diff_line = DiffLine {
+			content: "\ta ".into(),
+			line_type: DiffLineType::Add,
+			carriage_return: true,
+			..DiffLine::default()
+		};
+		let theme = Rc::new(Theme::default());
+		let glyph = theme.diff_whitespace(false);
+		let add = theme.diff_line(DiffLineType::Add, false);
+
+		let line = DiffComponent::get_line_to_add(
+			10,
+			&diff_line,
+			&[],
+			false,
+			false,
+			false,
+			&theme,
+			0,
+			true,
+		);
+
+		assert_eq!(
+			line.spans[1..],
+			[
+				Span::styled(Cow::from(GLYPH_TAB), glyph),
+				Span::styled(Cow::from("a"), add),
+				Span::styled(Cow::from(GLYPH_TRAILING_SPACE), glyph),
+				Span::styled(Cow::from(GLYPH_CARRIAGE_RETURN), glyph),
+				Span::styled(Cow::from("\n"), add),
+			]
+		);
+	}
+
 	#[test]
 	fn test_line_break() {
 		let diff_line = DiffLine {
 			content: "".into(),
 			line_type: DiffLineType::Add,
 			position: Default::default(),
+			word_changes: Vec::new(),
+			carriage_return: false,
 		};
 
 		{
@@ -986,11 +2028,13 @@ mod tests {
 				DiffComponent::get_line_to_add(
 					4,
 					&diff_line,
+					&[],
 					false,
 					false,
 					false,
 					&default_theme,
-					0
+					0,
+					false
 				)
 				.spans
 				.last()
@@ -1021,7 +2065,15 @@ mod tests {
 
 			assert_eq!(
 				DiffComponent::get_line_to_add(
-					4, &diff_line, false, false, false, &theme, 0
+					4,
+					&diff_line,
+					&[],
+					false,
+					false,
+					false,
+					&theme,
+					0,
+					false
 				)
 				.spans
 				.last()
@@ -1059,4 +2111,41 @@ mod tests {
 				if path == "src/main.rs"
 		));
 	}
+
+	#[test]
+	fn diff_component_opens_hunk_editor() {
+		let env = Environment::test_env();
+		let mut diff = DiffComponent::new(&env, false);
+
+		diff.focus(true);
+		diff.current.path = String::from("src/main.rs");
+		diff.diff = Some(FileDiff {
+			hunks: vec![Hunk {
+				header_hash: 42,
+				lines: vec![DiffLine {
+					line_type: DiffLineType::Header,
+					..DiffLine::default()
+				}],
+			}],
+			..FileDiff::default()
+		});
+		diff.selected_hunk = Some(0);
+
+		let event = Event::Key(KeyEvent::new(
+			KeyCode::Char('E'),
+			KeyModifiers::SHIFT,
+		));
+
+		assert!(matches!(
+			diff.event(&event).unwrap(),
+			EventState::Consumed
+		));
+
+		let event = env.queue.pop();
+		assert!(matches!(
+			event,
+			Some(InternalEvent::OpenHunkEditor(path, 42))
+				if path == "src/main.rs"
+		));
+	}
 }
@@ -0,0 +1,622 @@
+use super::{
+	utils::scroll_horizontal::HorizontalScroll,
+	utils::scroll_vertical::VerticalScroll, CommandBlocking,
+	CommandInfo, Component, DiffComponent, DrawableComponent,
+	EventState, HorizontalScrollType, ScrollType,
+};
+use crate::{
+	app::Environment,
+	keys::{key_match, SharedKeyConfig},
+	options::SharedOptions,
+	queue::{InternalEvent, Queue},
+	string_utils::{tabs_to_spaces, trim_offset},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::{
+	asyncjob::AsyncSingleJob, sync::RepoPathRef, AsyncDiffFilesJob,
+	AsyncGitNotification, DiffFilesParams, DiffLineType, FileDiff,
+};
+use bytesize::ByteSize;
+use crossterm::event::Event;
+use ratatui::{
+	layout::Rect,
+	text::{Line, Span},
+	widgets::{Block, Borders, Paragraph},
+	Frame,
+};
+use std::{borrow::Cow, cell::Cell};
+
+struct FileSection {
+	path: String,
+	diff: FileDiff,
+	collapsed: bool,
+}
+
+impl FileSection {
+	/// header line summing up the changes of the file
+	fn header(&self) -> String {
+		let fold = if self.collapsed { "▸" } else { "▾" };
+
+		if self.diff.hunks.is_empty() {
+			return format!(
+				"{fold} {} ({} -> {})",
+				self.path,
+				ByteSize::b(self.diff.sizes.0),
+				ByteSize::b(self.diff.sizes.1),
+			);
+		}
+
+		let count = |typ: DiffLineType| {
+			self.diff
+				.hunks
+				.iter()
+				.flat_map(|hunk| hunk.lines.iter())
+				.filter(|line| line.line_type == typ)
+				.count()
+		};
+
+		format!(
+			"{fold} {} (+{} -{})",
+			self.path,
+			count(DiffLineType::Add),
+			count(DiffLineType::Delete),
+		)
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Row {
+	/// header of the file at this index
+	File(usize),
+	/// file, hunk and line index of a diff line
+	Line(usize, usize, usize),
+}
+
+impl Row {
+	const fn file(self) -> usize {
+		match self {
+			Self::File(file) | Self::Line(file, _, _) => file,
+		}
+	}
+}
+
+/// diffs of all files of a commit in one scrollable view, each file
+/// under a header that folds it
+pub struct DiffFilesComponent {
+	repo: RepoPathRef,
+	queue: Queue,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+	options: SharedOptions,
+	job: AsyncSingleJob<AsyncDiffFilesJob>,
+	params: Option<DiffFilesParams>,
+	pending: bool,
+	files: Vec<FileSection>,
+	rows: Vec<Row>,
+	selection: usize,
+	/// file to select once the diffs arrive
+	select_path: Option<String>,
+	longest_line: usize,
+	current_size: Cell<(u16, u16)>,
+	vertical_scroll: VerticalScroll,
+	horizontal_scroll: HorizontalScroll,
+	focused: bool,
+}
+
+impl DiffFilesComponent {
+	///
+	pub fn new(env: &Environment) -> Self {
+		Self {
+			repo: env.repo.clone(),
+			queue: env.queue.clone(),
+			theme: env.theme.clone(),
+			key_config: env.key_config.clone(),
+			options: env.options.clone(),
+			job: AsyncSingleJob::new(env.sender_git.clone()),
+			params: None,
+			pending: false,
+			files: Vec::new(),
+			rows: Vec::new(),
+			selection: 0,
+			select_path: None,
+			longest_line: 0,
+			current_size: Cell::new((0, 0)),
+			vertical_scroll: VerticalScroll::new(),
+			horizontal_scroll: HorizontalScroll::new(),
+			focused: false,
+		}
+	}
+
+	/// loads the diffs of all files unless they are loaded already
+	/// and selects the header of `path`
+	pub fn request(
+		&mut self,
+		params: DiffFilesParams,
+		path: Option<String>,
+	) {
+		if self.params == Some(params) {
+			if let Some(path) = path {
+				self.select_file(&path);
+			}
+			return;
+		}
+
+		self.clear();
+		self.params = Some(params);
+		self.pending = true;
+		self.select_path = path;
+		self.job.spawn(AsyncDiffFilesJob::new(
+			self.repo.borrow().clone(),
+			params,
+		));
+	}
+
+	///
+	pub fn clear(&mut self) {
+		self.params = None;
+		self.pending = false;
+		self.files.clear();
+		self.rows.clear();
+		self.selection = 0;
+		self.select_path = None;
+		self.longest_line = 0;
+		self.vertical_scroll.reset();
+		self.horizontal_scroll.reset();
+	}
+
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.job.is_pending()
+	}
+
+	///
+	pub fn update_git(&mut self, ev: AsyncGitNotification) {
+		if ev != AsyncGitNotification::DiffFiles {
+			return;
+		}
+
+		let Some(job) = self.job.take_last() else {
+			return;
+		};
+		if Some(job.params()) != self.params {
+			return;
+		}
+
+		match job.result() {
+			Some(Ok(files)) => self.set_files(files),
+			Some(Err(e)) => {
+				self.clear();
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("diff files error:\n{e}"),
+				));
+			}
+			None => (),
+		}
+	}
+
+	fn set_files(&mut self, files: Vec<(String, FileDiff)>) {
+		self.pending = false;
+		self.files = files
+			.into_iter()
+			.map(|(path, diff)| FileSection {
+				path,
+				diff,
+				collapsed: false,
+			})
+			.collect();
+		self.longest_line = self
+			.files
+			.iter()
+			.flat_map(|file| file.diff.hunks.iter())
+			.flat_map(|hunk| hunk.lines.iter())
+			.map(|line| {
+				tabs_to_spaces(line.content.to_string()).len()
+			})
+			.max()
+			.map_or(0, |len| len + 1);
+		self.update_rows();
+
+		if let Some(path) = self.select_path.take() {
+			self.select_file(&path);
+		}
+	}
+
+	fn update_rows(&mut self) {
+		self.rows.clear();
+
+		for (idx, file) in self.files.iter().enumerate() {
+			self.rows.push(Row::File(idx));
+
+			if !file.collapsed {
+				for (hunk_idx, hunk) in
+					file.diff.hunks.iter().enumerate()
+				{
+					self.rows.extend(
+						(0..hunk.lines.len()).map(|line| {
+							Row::Line(idx, hunk_idx, line)
+						}),
+					);
+				}
+			}
+		}
+
+		self.selection =
+			self.selection.min(self.rows.len().saturating_sub(1));
+	}
+
+	/// moves the selection to the header of the file at `path`
+	pub fn select_file(&mut self, path: &str) {
+		if let Some(file) =
+			self.files.iter().position(|file| file.path == path)
+		{
+			self.select_row(self.file_row(file));
+		}
+	}
+
+	fn file_row(&self, file: usize) -> usize {
+		self.rows
+			.iter()
+			.position(|row| *row == Row::File(file))
+			.unwrap_or_default()
+	}
+
+	fn select_row(&mut self, row: usize) {
+		self.selection = row.min(self.rows.len().saturating_sub(1));
+
+		let height = usize::from(self.current_size.get().1);
+		self.vertical_scroll.move_area_to_visible(
+			height,
+			self.selection,
+			self.selection + height,
+		);
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let page =
+			usize::from(self.current_size.get().1).saturating_sub(1);
+
+		let row = match move_type {
+			ScrollType::Down => self.selection.saturating_add(1),
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Home => 0,
+			ScrollType::End => self.rows.len(),
+			ScrollType::PageDown => {
+				self.selection.saturating_add(page)
+			}
+			ScrollType::PageUp => self.selection.saturating_sub(page),
+		};
+
+		self.selection = row.min(self.rows.len().saturating_sub(1));
+	}
+
+	/// selects the header of the next or, going `back`, the
+	/// previous file
+	fn move_file(&mut self, back: bool) {
+		let Some(current) = self.rows.get(self.selection) else {
+			return;
+		};
+
+		let file = current.file();
+		let target = if back {
+			// the header of the current file unless it is selected
+			if *current == Row::File(file) {
+				file.checked_sub(1)
+			} else {
+				Some(file)
+			}
+		} else {
+			Some(file + 1).filter(|next| *next < self.files.len())
+		};
+
+		if let Some(target) = target {
+			self.select_row(self.file_row(target));
+		}
+	}
+
+	/// selects the header of the next or, going `back`, the
+	/// previous hunk across all unfolded files
+	fn move_hunk(&mut self, back: bool) {
+		let is_hunk_start =
+			|row: &Row| matches!(row, Row::Line(_, _, 0));
+
+		let target = if back {
+			self.rows[..self.selection]
+				.iter()
+				.rposition(is_hunk_start)
+		} else {
+			self.rows
+				.iter()
+				.skip(self.selection + 1)
+				.position(is_hunk_start)
+				.map(|idx| idx + self.selection + 1)
+		};
+
+		if let Some(target) = target {
+			self.select_row(target);
+		}
+	}
+
+	/// folds or unfolds the file of the selected row
+	fn toggle_fold(&mut self) {
+		let Some(file) =
+			self.rows.get(self.selection).map(|r| r.file())
+		else {
+			return;
+		};
+
+		self.files[file].collapsed = !self.files[file].collapsed;
+		self.update_rows();
+		self.select_row(self.file_row(file));
+	}
+
+	fn get_text(&self, width: u16, height: u16) -> Vec<Line<'_>> {
+		let whitespace = self.options.borrow().diff_show_whitespace();
+		let scrolled_right = self.horizontal_scroll.get_right();
+
+		self.rows
+			.iter()
+			.enumerate()
+			.skip(self.vertical_scroll.get_top())
+			.take(height.into())
+			.map(|(idx, row)| {
+				let selected = self.focused && idx == self.selection;
+
+				match *row {
+					Row::File(file) => {
+						let header = self.files[file].header();
+						let header =
+							trim_offset(&header, scrolled_right);
+						let filled = if selected {
+							format!("{header:w$}", w = width.into())
+						} else {
+							header.to_string()
+						};
+
+						Line::from(Span::styled(
+							Cow::from(filled),
+							self.theme.diff_file_header(selected),
+						))
+					}
+					Row::Line(file, hunk, line) => {
+						let lines =
+							&self.files[file].diff.hunks[hunk].lines;
+
+						DiffComponent::get_line_to_add(
+							width,
+							&lines[line],
+							&[],
+							selected,
+							false,
+							line == lines.len() - 1,
+							&self.theme,
+							scrolled_right,
+							whitespace,
+						)
+					}
+				}
+			})
+			.collect()
+	}
+}
+
+impl DrawableComponent for DiffFilesComponent {
+	fn draw(&self, f: &mut Frame, r: Rect) -> Result<()> {
+		self.current_size.set((
+			r.width.saturating_sub(2),
+			r.height.saturating_sub(2),
+		));
+
+		let (width, height) = self.current_size.get();
+
+		self.vertical_scroll.update(
+			self.selection,
+			self.rows.len(),
+			usize::from(height),
+		);
+		self.horizontal_scroll
+			.update_no_selection(self.longest_line, width.into());
+
+		let title = format!(
+			"{}{}",
+			strings::title_diff(&self.key_config),
+			strings::diff_files(self.files.len())
+		);
+
+		let txt = if self.pending {
+			vec![Line::from(Span::styled(
+				Cow::from(strings::loading_text(&self.key_config)),
+				self.theme.text(false, false),
+			))]
+		} else {
+			self.get_text(r.width, height)
+		};
+
+		f.render_widget(
+			Paragraph::new(txt).block(
+				Block::default()
+					.title(Span::styled(
+						title.as_str(),
+						self.theme.title(self.focused),
+					))
+					.borders(Borders::ALL)
+					.border_style(self.theme.block(self.focused)),
+			),
+			r,
+		);
+
+		if self.focused {
+			self.vertical_scroll.draw(f, r, &self.theme);
+
+			if self.longest_line > usize::from(width) {
+				self.horizontal_scroll.draw(f, r, &self.theme);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for DiffFilesComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		_force_all: bool,
+	) -> CommandBlocking {
+		let has_files = !self.files.is_empty();
+
+		out.push(CommandInfo::new(
+			strings::commands::scroll(&self.key_config),
+			has_files,
+			self.focused,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_file_next(&self.key_config),
+			has_files,
+			self.focused,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_file_prev(&self.key_config),
+			has_files,
+			self.focused,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_hunk_next(&self.key_config),
+			has_files,
+			self.focused,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_hunk_prev(&self.key_config),
+			has_files,
+			self.focused,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_fold_file(&self.key_config),
+			has_files,
+			self.focused,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::diff_show_whitespace(&self.key_config),
+			true,
+			self.focused,
+		));
+
+		CommandBlocking::PassingOn
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if !self.focused {
+			return Ok(EventState::NotConsumed);
+		}
+
+		if let Event::Key(e) = ev {
+			let keys = &self.key_config.keys;
+
+			if key_match(e, keys.move_down) {
+				self.move_selection(ScrollType::Down);
+			} else if key_match(e, keys.move_up) {
+				self.move_selection(ScrollType::Up);
+			} else if key_match(e, keys.home) {
+				self.move_selection(ScrollType::Home);
+			} else if key_match(e, keys.end) {
+				self.move_selection(ScrollType::End);
+			} else if key_match(e, keys.page_down) {
+				self.move_selection(ScrollType::PageDown);
+			} else if key_match(e, keys.page_up) {
+				self.move_selection(ScrollType::PageUp);
+			} else if key_match(e, keys.move_right) {
+				self.horizontal_scroll
+					.move_right(HorizontalScrollType::Right);
+			} else if key_match(e, keys.move_left) {
+				self.horizontal_scroll
+					.move_right(HorizontalScrollType::Left);
+			} else if key_match(e, keys.diff_file_next) {
+				self.move_file(false);
+			} else if key_match(e, keys.diff_file_prev) {
+				self.move_file(true);
+			} else if key_match(e, keys.diff_hunk_next) {
+				self.move_hunk(false);
+			} else if key_match(e, keys.diff_hunk_prev) {
+				self.move_hunk(true);
+			} else if key_match(e, keys.enter) {
+				self.toggle_fold();
+			} else if key_match(e, keys.diff_show_whitespace) {
+				self.options
+					.borrow_mut()
+					.diff_toggle_show_whitespace();
+			} else {
+				return Ok(EventState::NotConsumed);
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn focused(&self) -> bool {
+		self.focused
+	}
+
+	fn focus(&mut self, focus: bool) {
+		self.focused = focus;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use asyncgit::{sync::diff::Hunk, DiffLine};
+
+	fn file_diff() -> FileDiff {
+		let line = |line_type| DiffLine {
+			line_type,
+			..DiffLine::default()
+		};
+
+		FileDiff {
+			hunks: vec![Hunk {
+				header_hash: 0,
+				lines: vec![
+					line(DiffLineType::Header),
+					line(DiffLineType::Delete),
+					line(DiffLineType::Add),
+				],
+			}],
+			lines: 3,
+			..FileDiff::default()
+		}
+	}
+
+	#[test]
+	fn test_fold_and_jump() {
+		let env = Environment::test_env();
+		let mut diff = DiffFilesComponent::new(&env);
+
+		diff.set_files(vec![
+			(String::from("a.txt"), file_diff()),
+			(String::from("b.txt"), file_diff()),
+		]);
+		assert_eq!(diff.rows.len(), 8);
+
+		diff.move_file(false);
+		assert!(diff.rows[diff.selection] == Row::File(1));
+
+		diff.move_hunk(true);
+		assert!(diff.rows[diff.selection] == Row::Line(0, 0, 0));
+
+		diff.toggle_fold();
+		assert_eq!(diff.rows.len(), 5);
+		assert_eq!(diff.selection, 0);
+
+		diff.move_selection(ScrollType::Down);
+		diff.move_selection(ScrollType::Down);
+		assert!(diff.rows[diff.selection] == Row::Line(1, 0, 0));
+
+		diff.move_file(true);
+		assert!(diff.rows[diff.selection] == Row::File(1));
+		diff.move_file(true);
+		assert!(diff.rows[diff.selection] == Row::File(0));
+	}
+}
@@ -41,6 +41,7 @@ mod commit_details;
 mod commitlist;
 mod cred;
 mod diff;
+mod diff_files;
 mod revision_files;
 mod status_tree;
 mod syntax_text;
@@ -54,13 +55,14 @@ pub use commit_details::CommitDetailsComponent;
 pub use commitlist::CommitList;
 pub use cred::CredComponent;
 pub use diff::DiffComponent;
+pub use diff_files::DiffFilesComponent;
 pub use revision_files::RevisionFilesComponent;
 pub use syntax_text::SyntaxTextComponent;
 pub use textinput::{InputType, TextInputComponent};
 pub use utils::{
-	filetree::FileTreeItemKind, logitems::ItemBatch,
-	scroll_vertical::VerticalScroll, string_width_align,
-	time_to_string,
+	date_range::parse_date_range, filetree::FileTreeItemKind,
+	logitems::ItemBatch, scroll_vertical::VerticalScroll,
+	string_width_align, time_to_string,
 };
 
 use crate::ui::style::Theme;
@@ -11,7 +11,7 @@ use crate::{
 	keys::{key_match, SharedKeyConfig},
 	popups::{BlameFileOpen, FileRevOpen},
 	queue::{InternalEvent, NeedsUpdate, Queue, StackablePopupOpen},
-	strings::{self, order},
+	strings::{self, order, symbol},
 	ui::{self, style::SharedTheme},
 };
 use anyhow::Result;
@@ -91,6 +91,16 @@ impl StatusTreeComponent {
 		})
 	}
 
+	/// marked files, in tree order
+	pub fn marked_items(&self) -> Vec<StatusItem> {
+		self.tree.marked_items()
+	}
+
+	///
+	pub fn clear_marked(&mut self) {
+		self.tree.clear_marked();
+	}
+
 	///
 	pub const fn show_selection(&mut self, show: bool) {
 		self.show_selection = show;
@@ -138,7 +148,7 @@ impl StatusTreeComponent {
 		changed
 	}
 
-	const fn item_status_char(item_type: StatusItemType) -> char {
+	pub const fn item_status_char(item_type: StatusItemType) -> char {
 		match item_type {
 			StatusItemType::Modified => 'M',
 			StatusItemType::New => '+',
@@ -150,28 +160,32 @@ impl StatusTreeComponent {
 	}
 
 	fn item_to_text<'b>(
-		string: &str,
-		indent: usize,
-		visible: bool,
-		file_item_kind: &FileTreeItemKind,
+		draw_info: &TextDrawInfo,
 		width: u16,
 		selected: bool,
 		theme: &'b SharedTheme,
 	) -> Option<Span<'b>> {
+		let string = &draw_info.name;
+		let indent = draw_info.indent as usize;
 		let indent_str = if indent == 0 {
 			String::new()
 		} else {
 			format!("{:w$}", " ", w = indent * 2)
 		};
 
-		if !visible {
+		if !draw_info.visible {
 			return None;
 		}
 
-		match file_item_kind {
+		match draw_info.item_kind {
 			FileTreeItemKind::File(status_item) => {
 				let status_char =
 					Self::item_status_char(status_item.status);
+				let marker = if draw_info.marked {
+					symbol::CHECKMARK
+				} else {
+					symbol::EMPTY_SPACE
+				};
 				let file = Path::new(&status_item.path)
 					.file_name()
 					.and_then(std::ffi::OsStr::to_str)
@@ -179,14 +193,15 @@ impl StatusTreeComponent {
 
 				let txt = if selected {
 					format!(
-						"{} {}{:w$}",
+						"{}{}{}{:w$}",
 						status_char,
+						marker,
 						indent_str,
 						file,
 						w = width as usize
 					)
 				} else {
-					format!("{status_char} {indent_str}{file}")
+					format!("{status_char}{marker}{indent_str}{file}")
 				};
 
 				Some(Span::styled(
@@ -247,6 +262,7 @@ impl StatusTreeComponent {
 				name: item.info.path.clone(),
 				indent: item.info.indent,
 				visible: item.info.visible,
+				marked: matches!(&item.kind, FileTreeItemKind::File(file) if self.tree.marked.contains(&file.path)),
 				item_kind: &item.kind,
 			});
 
@@ -334,6 +350,7 @@ struct TextDrawInfo<'a> {
 	name: String,
 	indent: u8,
 	visible: bool,
+	marked: bool,
 	item_kind: &'a FileTreeItemKind,
 }
 
@@ -383,10 +400,7 @@ impl DrawableComponent for StatusTreeComponent {
 				.enumerate()
 				.filter_map(|(index, draw_text_info)| {
 					Self::item_to_text(
-						&draw_text_info.name,
-						draw_text_info.indent as usize,
-						draw_text_info.visible,
-						draw_text_info.item_kind,
+						draw_text_info,
 						r.width,
 						self.show_selection && select == index,
 						&self.theme,
@@ -430,6 +444,18 @@ impl Component for StatusTreeComponent {
 			.order(order::NAV),
 		);
 
+		out.push(CommandInfo::new(
+			strings::commands::mark_item(&self.key_config),
+			true,
+			available && !self.is_empty(),
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::mark_range(&self.key_config),
+			true,
+			available && !self.is_empty(),
+		));
+
 		out.push(
 			CommandInfo::new(
 				strings::commands::blame_file(&self.key_config),
@@ -515,6 +541,16 @@ impl Component for StatusTreeComponent {
 						);
 					}
 					Ok(EventState::Consumed)
+				} else if key_match(
+					e,
+					self.key_config.keys.status_mark_item,
+				) {
+					Ok(self.tree.toggle_mark().into())
+				} else if key_match(
+					e,
+					self.key_config.keys.status_mark_range,
+				) {
+					Ok(self.tree.mark_range().into())
 				} else if key_match(e, self.key_config.keys.copy) {
 					self.copy_file_path();
 					Ok(EventState::Consumed)
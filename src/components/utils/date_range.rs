@@ -0,0 +1,248 @@
+use asyncgit::sync::DateRange;
+use chrono::{
+	DateTime, Datelike, Days, Local, NaiveDate, NaiveDateTime,
+	NaiveTime, TimeDelta, TimeZone, Weekday,
+};
+
+/// a point in time the user typed, days cover all of the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Moment {
+	Day(NaiveDate),
+	Instant(i64),
+}
+
+impl Moment {
+	fn start(self) -> Option<i64> {
+		match self {
+			Self::Day(day) => {
+				local_timestamp(day.and_time(NaiveTime::MIN))
+			}
+			Self::Instant(time) => Some(time),
+		}
+	}
+
+	fn end(self) -> Option<i64> {
+		match self {
+			Self::Day(day) => {
+				local_timestamp(day.and_hms_opt(23, 59, 59)?)
+			}
+			Self::Instant(time) => Some(time),
+		}
+	}
+}
+
+/// parses `since..until` where either side can be left out. a single
+/// date without `..` means only that day (or everything since that
+/// point for relative times like `3 days ago`).
+///
+/// dates can be `YYYY-MM-DD`, `YYYY-MM-DD HH:MM`, `today`,
+/// `yesterday`, a weekday like `tuesday` (the last one up to today),
+/// `now` or `<n> <minutes|hours|days|weeks|months|years> ago`.
+pub fn parse_date_range(
+	text: &str,
+	now: DateTime<Local>,
+) -> Option<DateRange> {
+	let text = text.trim();
+
+	if let Some((since, until)) = text.split_once("..") {
+		let since = since.trim();
+		let until = until.trim();
+
+		if since.is_empty() && until.is_empty() {
+			return None;
+		}
+
+		let since = if since.is_empty() {
+			None
+		} else {
+			Some(parse_moment(since, now)?.start()?)
+		};
+		let until = if until.is_empty() {
+			None
+		} else {
+			Some(parse_moment(until, now)?.end()?)
+		};
+
+		return Some(DateRange { since, until });
+	}
+
+	match parse_moment(text, now)? {
+		moment @ Moment::Day(_) => Some(DateRange {
+			since: moment.start(),
+			until: moment.end(),
+		}),
+		Moment::Instant(time) => Some(DateRange {
+			since: Some(time),
+			until: None,
+		}),
+	}
+}
+
+fn parse_moment(text: &str, now: DateTime<Local>) -> Option<Moment> {
+	let text = text.to_lowercase();
+	let today = now.date_naive();
+
+	match text.as_str() {
+		"now" => return Some(Moment::Instant(now.timestamp())),
+		"today" => return Some(Moment::Day(today)),
+		"yesterday" => return today.pred_opt().map(Moment::Day),
+		_ => (),
+	}
+
+	if let Ok(weekday) = text.parse::<Weekday>() {
+		let days_back = (today.weekday().num_days_from_monday() + 7
+			- weekday.num_days_from_monday())
+			% 7;
+		return today
+			.checked_sub_days(Days::new(days_back.into()))
+			.map(Moment::Day);
+	}
+
+	if let Ok(day) = NaiveDate::parse_from_str(&text, "%Y-%m-%d") {
+		return Some(Moment::Day(day));
+	}
+
+	if let Ok(time) =
+		NaiveDateTime::parse_from_str(&text, "%Y-%m-%d %H:%M")
+	{
+		return local_timestamp(time).map(Moment::Instant);
+	}
+
+	let since = now.checked_sub_signed(parse_relative(&text)?)?;
+
+	Some(Moment::Instant(since.timestamp()))
+}
+
+/// `<n> <unit> ago`, the `ago` is optional and `n` positive
+fn parse_relative(text: &str) -> Option<TimeDelta> {
+	let mut parts = text.split_whitespace();
+
+	let amount = parts
+		.next()?
+		.parse::<i64>()
+		.ok()
+		.filter(|amount| *amount > 0)?;
+	let unit = parts.next()?;
+
+	match parts.next() {
+		None | Some("ago") => (),
+		Some(_) => return None,
+	}
+
+	if parts.next().is_some() {
+		return None;
+	}
+
+	let unit = unit.strip_suffix('s').unwrap_or(unit);
+
+	match unit {
+		"minute" | "min" => TimeDelta::try_minutes(amount),
+		"hour" => TimeDelta::try_hours(amount),
+		"day" => TimeDelta::try_days(amount),
+		"week" => TimeDelta::try_weeks(amount),
+		"month" => TimeDelta::try_days(amount.checked_mul(30)?),
+		"year" => TimeDelta::try_days(amount.checked_mul(365)?),
+		_ => None,
+	}
+}
+
+fn local_timestamp(time: NaiveDateTime) -> Option<i64> {
+	Local
+		.from_local_datetime(&time)
+		.earliest()
+		.map(|time| time.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	fn now() -> DateTime<Local> {
+		// a thursday
+		Local
+			.from_local_datetime(
+				&NaiveDate::from_ymd_opt(2024, 5, 9)
+					.unwrap()
+					.and_hms_opt(12, 0, 0)
+					.unwrap(),
+			)
+			.unwrap()
+	}
+
+	fn day(y: i32, m: u32, d: u32) -> Moment {
+		Moment::Day(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+	}
+
+	#[test]
+	fn test_weekday_range() {
+		assert_eq!(
+			parse_date_range("tuesday..thursday", now()),
+			Some(DateRange {
+				since: day(2024, 5, 7).start(),
+				until: day(2024, 5, 9).end(),
+			})
+		);
+	}
+
+	#[test]
+	fn test_open_ranges() {
+		assert_eq!(
+			parse_date_range("2024-01-02..", now()),
+			Some(DateRange {
+				since: day(2024, 1, 2).start(),
+				until: None,
+			})
+		);
+		assert_eq!(
+			parse_date_range("..yesterday", now()),
+			Some(DateRange {
+				since: None,
+				until: day(2024, 5, 8).end(),
+			})
+		);
+		assert_eq!(parse_date_range("..", now()), None);
+	}
+
+	#[test]
+	fn test_relative() {
+		assert_eq!(
+			parse_date_range("2 days ago", now()),
+			Some(DateRange {
+				since: Some(now().timestamp() - 2 * 24 * 60 * 60),
+				until: None,
+			})
+		);
+		assert_eq!(
+			parse_date_range("1 week..today", now()),
+			Some(DateRange {
+				since: Some(now().timestamp() - 7 * 24 * 60 * 60),
+				until: day(2024, 5, 9).end(),
+			})
+		);
+	}
+
+	#[test]
+	fn test_single_day() {
+		assert_eq!(
+			parse_date_range("2024-05-01", now()),
+			Some(DateRange {
+				since: day(2024, 5, 1).start(),
+				until: day(2024, 5, 1).end(),
+			})
+		);
+	}
+
+	#[test]
+	fn test_invalid() {
+		assert_eq!(parse_date_range("someday", now()), None);
+		assert_eq!(parse_date_range("2 parsecs ago", now()), None);
+		assert_eq!(parse_date_range("2024-13-01", now()), None);
+		assert_eq!(parse_date_range("-5 days ago", now()), None);
+		assert_eq!(parse_date_range("0 days ago", now()), None);
+		assert_eq!(
+			parse_date_range("100000000 days ago", now()),
+			None
+		);
+	}
+}
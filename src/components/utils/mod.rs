@@ -1,6 +1,7 @@
 use chrono::{DateTime, Local, Utc};
 use unicode_width::UnicodeWidthStr;
 
+pub mod date_range;
 #[cfg(feature = "ghemoji")]
 pub mod emoji;
 pub mod filetree;
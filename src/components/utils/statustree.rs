@@ -18,6 +18,12 @@ pub struct StatusTree {
 	pub available_selections: Vec<usize>,
 
 	pub window_height: Cell<Option<usize>>,
+
+	// paths of the files marked for batch actions
+	pub marked: BTreeSet<String>,
+
+	// item the last mark toggle happened on, start of a range mark
+	mark_anchor: Option<String>,
 }
 
 ///
@@ -53,6 +59,11 @@ impl StatusTree {
 			self.selected_item().map(|e| e.info.full_path);
 
 		self.tree = FileTreeItems::new(list, &last_collapsed)?;
+
+		let paths =
+			list.iter().map(|e| &e.path).collect::<BTreeSet<_>>();
+		self.marked.retain(|path| paths.contains(path));
+
 		self.selection = last_selection.as_ref().map_or_else(
 			|| self.tree.items().first().map(|_| 0),
 			|last_selection| {
@@ -173,6 +184,104 @@ impl StatusTree {
 		self.tree.items().is_empty()
 	}
 
+	/// marks the selected file or every file in the selected folder,
+	/// unmarks them if they are all marked already
+	pub fn toggle_mark(&mut self) -> bool {
+		let Some(selection) = self.selection else {
+			return false;
+		};
+
+		let files = self.files_at(selection);
+
+		if files.iter().all(|path| self.marked.contains(path)) {
+			for path in &files {
+				self.marked.remove(path);
+			}
+		} else {
+			self.marked.extend(files);
+		}
+
+		self.mark_anchor =
+			Some(self.tree[selection].info.full_path.clone());
+
+		true
+	}
+
+	/// marks every file between the last toggled item and the
+	/// selection
+	pub fn mark_range(&mut self) -> bool {
+		let (Some(selection), Some(anchor)) = (
+			self.selection,
+			self.mark_anchor
+				.as_deref()
+				.and_then(|anchor| self.find_item(anchor)),
+		) else {
+			return self.toggle_mark();
+		};
+
+		let files = (selection.min(anchor)..=selection.max(anchor))
+			.flat_map(|idx| self.files_at(idx))
+			.collect::<Vec<_>>();
+
+		self.marked.extend(files);
+
+		true
+	}
+
+	///
+	pub fn clear_marked(&mut self) {
+		self.marked.clear();
+		self.mark_anchor = None;
+	}
+
+	/// marked files in tree order
+	pub fn marked_items(&self) -> Vec<StatusItem> {
+		self.tree
+			.items()
+			.iter()
+			.filter_map(|item| match &item.kind {
+				FileTreeItemKind::File(file)
+					if self.marked.contains(&file.path) =>
+				{
+					Some(file.clone())
+				}
+				_ => None,
+			})
+			.collect()
+	}
+
+	/// the file at `idx` or all files inside the folder at `idx`
+	fn files_at(&self, idx: usize) -> Vec<String> {
+		let item = &self.tree[idx];
+
+		match &item.kind {
+			FileTreeItemKind::File(file) => vec![file.path.clone()],
+			FileTreeItemKind::Path(_) => {
+				let prefix = format!("{}/", item.info.full_path);
+
+				self.tree.items()[idx + 1..]
+					.iter()
+					.take_while(|e| {
+						e.info.full_path.starts_with(&prefix)
+					})
+					.filter_map(|e| match &e.kind {
+						FileTreeItemKind::File(file) => {
+							Some(file.path.clone())
+						}
+						FileTreeItemKind::Path(_) => None,
+					})
+					.collect()
+			}
+		}
+	}
+
+	fn find_item(&self, path: &str) -> Option<usize> {
+		self.tree
+			.items()
+			.binary_search_by(|e| e.info.full_path.as_str().cmp(path))
+			.ok()
+	}
+
 	fn all_collapsed(&self) -> BTreeSet<&String> {
 		let mut res = BTreeSet::new();
 
@@ -497,6 +606,48 @@ mod tests {
 		assert_eq!(res.selection, Some(0));
 	}
 
+	#[test]
+	fn test_mark() {
+		let mut res = StatusTree::default();
+		res.update(&string_vec_to_status(&["a/b", "a/c", "d", "e"]))
+			.unwrap();
+
+		//0 a/
+		//1   b
+		//2   c
+		//3 d
+		//4 e
+
+		assert!(res.toggle_mark());
+		assert_eq!(
+			res.marked.iter().collect::<Vec<_>>(),
+			vec!["a/b", "a/c"]
+		);
+
+		res.selection = Some(2);
+		res.toggle_mark();
+		assert_eq!(
+			res.marked.iter().collect::<Vec<_>>(),
+			vec!["a/b"]
+		);
+
+		res.selection = Some(4);
+		res.mark_range();
+		assert_eq!(
+			res.marked_items()
+				.iter()
+				.map(|e| e.path.as_str())
+				.collect::<Vec<_>>(),
+			vec!["a/b", "a/c", "d", "e"]
+		);
+
+		res.update(&string_vec_to_status(&["a/c", "e"])).unwrap();
+		assert_eq!(
+			res.marked.iter().collect::<Vec<_>>(),
+			vec!["a/c", "e"]
+		);
+	}
+
 	#[test]
 	fn test_keep_selected_item() {
 		let mut res = StatusTree::default();
@@ -244,6 +244,8 @@ mod tests {
 			notify_watcher: false,
 			key_bindings_path: None,
 			key_symbols_path: None,
+			log_revisions: None,
+			clone: None,
 		};
 
 		let theme = Theme::init(&PathBuf::new());
@@ -73,7 +73,18 @@ pub struct KeysList {
 	pub status_stage_all: GituiKeyEvent,
 	pub status_reset_item: GituiKeyEvent,
 	pub status_ignore_file: GituiKeyEvent,
+	pub status_intent_to_add: GituiKeyEvent,
+	pub status_resolve_conflict: GituiKeyEvent,
+	pub status_mark_item: GituiKeyEvent,
+	pub status_mark_range: GituiKeyEvent,
+	pub status_stage_pathspec: GituiKeyEvent,
+	pub pathspec_unstage: GituiKeyEvent,
+	pub pathspec_reset: GituiKeyEvent,
+	pub conflict_take_ours: GituiKeyEvent,
+	pub conflict_take_theirs: GituiKeyEvent,
+	pub conflict_take_both: GituiKeyEvent,
 	pub diff_stage_lines: GituiKeyEvent,
+	pub diff_edit_hunk: GituiKeyEvent,
 	pub diff_reset_lines: GituiKeyEvent,
 	pub stashing_save: GituiKeyEvent,
 	pub stashing_toggle_untracked: GituiKeyEvent,
@@ -87,7 +98,18 @@ pub struct KeysList {
 	pub log_checkout_commit: GituiKeyEvent,
 	pub log_reset_commit: GituiKeyEvent,
 	pub log_reword_commit: GituiKeyEvent,
+	pub log_rebase_interactive: GituiKeyEvent,
+	pub log_cherry_pick: GituiKeyEvent,
+	pub view_reflog: GituiKeyEvent,
+	pub log_bisect_bad: GituiKeyEvent,
+	pub log_bisect_good: GituiKeyEvent,
+	pub log_bisect_skip: GituiKeyEvent,
 	pub log_find: GituiKeyEvent,
+	pub log_revisions: GituiKeyEvent,
+	pub log_first_parent: GituiKeyEvent,
+	pub log_hide_merges: GituiKeyEvent,
+	pub log_edit_note: GituiKeyEvent,
+	pub log_remove_note: GituiKeyEvent,
 	pub find_commit_sha: GituiKeyEvent,
 	pub commit_amend: GituiKeyEvent,
 	pub toggle_signoff: GituiKeyEvent,
@@ -99,6 +121,12 @@ pub struct KeysList {
 	pub delete_branch: GituiKeyEvent,
 	pub merge_branch: GituiKeyEvent,
 	pub rebase_branch: GituiKeyEvent,
+	pub rebase_pick: GituiKeyEvent,
+	pub rebase_reword: GituiKeyEvent,
+	pub rebase_edit: GituiKeyEvent,
+	pub rebase_squash: GituiKeyEvent,
+	pub rebase_fixup: GituiKeyEvent,
+	pub rebase_drop: GituiKeyEvent,
 	pub reset_branch: GituiKeyEvent,
 	pub compare_commits: GituiKeyEvent,
 	pub tags: GituiKeyEvent,
@@ -112,9 +140,15 @@ pub struct KeysList {
 	pub fetch: GituiKeyEvent,
 	pub pull: GituiKeyEvent,
 	pub abort_merge: GituiKeyEvent,
+	pub skip_cherry_pick: GituiKeyEvent,
 	pub undo_commit: GituiKeyEvent,
 	pub diff_hunk_next: GituiKeyEvent,
 	pub diff_hunk_prev: GituiKeyEvent,
+	pub diff_toggle_split: GituiKeyEvent,
+	pub diff_show_whitespace: GituiKeyEvent,
+	pub diff_toggle_all_files: GituiKeyEvent,
+	pub diff_file_next: GituiKeyEvent,
+	pub diff_file_prev: GituiKeyEvent,
 	pub stage_unstage_item: GituiKeyEvent,
 	pub tag_annotate: GituiKeyEvent,
 	pub view_submodules: GituiKeyEvent,
@@ -125,6 +159,8 @@ pub struct KeysList {
 	pub delete_remote: GituiKeyEvent,
 	pub view_submodule_parent: GituiKeyEvent,
 	pub update_submodule: GituiKeyEvent,
+	pub open_clone: GituiKeyEvent,
+	pub clone_recurse_submodules: GituiKeyEvent,
 	pub commit_history_next: GituiKeyEvent,
 	pub commit: GituiKeyEvent,
 	pub newline: GituiKeyEvent,
@@ -133,6 +169,7 @@ pub struct KeysList {
 
 #[rustfmt::skip]
 impl Default for KeysList {
+	#[allow(clippy::too_many_lines)]
 	fn default() -> Self {
 		Self {
 			tab_status: GituiKeyEvent::new(KeyCode::Char('1'), KeyModifiers::empty()),
@@ -172,7 +209,18 @@ impl Default for KeysList {
 			status_reset_item: GituiKeyEvent::new(KeyCode::Char('D'),  KeyModifiers::SHIFT),
 			diff_reset_lines: GituiKeyEvent::new(KeyCode::Char('d'),  KeyModifiers::empty()),
 			status_ignore_file: GituiKeyEvent::new(KeyCode::Char('i'),  KeyModifiers::empty()),
+			status_intent_to_add: GituiKeyEvent::new(KeyCode::Char('I'),  KeyModifiers::SHIFT),
+			status_resolve_conflict: GituiKeyEvent::new(KeyCode::Char('m'),  KeyModifiers::empty()),
+			status_mark_item: GituiKeyEvent::new(KeyCode::Char(' '),  KeyModifiers::empty()),
+			status_mark_range: GituiKeyEvent::new(KeyCode::Char('V'),  KeyModifiers::SHIFT),
+			status_stage_pathspec: GituiKeyEvent::new(KeyCode::Char('g'),  KeyModifiers::CONTROL),
+			pathspec_unstage: GituiKeyEvent::new(KeyCode::Char('u'),  KeyModifiers::CONTROL),
+			pathspec_reset: GituiKeyEvent::new(KeyCode::Char('d'),  KeyModifiers::CONTROL),
+			conflict_take_ours: GituiKeyEvent::new(KeyCode::Char('o'),  KeyModifiers::empty()),
+			conflict_take_theirs: GituiKeyEvent::new(KeyCode::Char('t'),  KeyModifiers::empty()),
+			conflict_take_both: GituiKeyEvent::new(KeyCode::Char('b'),  KeyModifiers::empty()),
 			diff_stage_lines: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::empty()),
+			diff_edit_hunk: GituiKeyEvent::new(KeyCode::Char('E'),  KeyModifiers::SHIFT),
 			stashing_save: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::empty()),
 			stashing_toggle_untracked: GituiKeyEvent::new(KeyCode::Char('u'),  KeyModifiers::empty()),
 			stashing_toggle_index: GituiKeyEvent::new(KeyCode::Char('i'),  KeyModifiers::empty()),
@@ -185,7 +233,18 @@ impl Default for KeysList {
 			log_checkout_commit: GituiKeyEvent { code: KeyCode::Char('S'), modifiers: KeyModifiers::SHIFT },
 			log_reset_commit: GituiKeyEvent { code: KeyCode::Char('R'), modifiers: KeyModifiers::SHIFT },
 			log_reword_commit: GituiKeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::empty() },
+			log_rebase_interactive: GituiKeyEvent { code: KeyCode::Char('i'), modifiers: KeyModifiers::empty() },
+			log_cherry_pick: GituiKeyEvent { code: KeyCode::Char('c'), modifiers: KeyModifiers::empty() },
+			view_reflog: GituiKeyEvent { code: KeyCode::Char('L'), modifiers: KeyModifiers::SHIFT },
+			log_bisect_bad: GituiKeyEvent { code: KeyCode::Char('b'), modifiers: KeyModifiers::CONTROL },
+			log_bisect_good: GituiKeyEvent { code: KeyCode::Char('g'), modifiers: KeyModifiers::CONTROL },
+			log_bisect_skip: GituiKeyEvent { code: KeyCode::Char('k'), modifiers: KeyModifiers::CONTROL },
 			log_find: GituiKeyEvent { code: KeyCode::Char('f'), modifiers: KeyModifiers::empty() },
+			log_revisions: GituiKeyEvent { code: KeyCode::Char('v'), modifiers: KeyModifiers::empty() },
+			log_first_parent: GituiKeyEvent { code: KeyCode::Char('P'), modifiers: KeyModifiers::SHIFT },
+			log_hide_merges: GituiKeyEvent { code: KeyCode::Char('M'), modifiers: KeyModifiers::SHIFT },
+			log_edit_note: GituiKeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::empty() },
+			log_remove_note: GituiKeyEvent { code: KeyCode::Char('N'), modifiers: KeyModifiers::SHIFT },
 			find_commit_sha: GituiKeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL),
 			commit_amend: GituiKeyEvent::new(KeyCode::Char('a'),  KeyModifiers::CONTROL),
 			toggle_signoff: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::CONTROL),
@@ -197,6 +256,12 @@ impl Default for KeysList {
 			delete_branch: GituiKeyEvent::new(KeyCode::Char('D'),  KeyModifiers::SHIFT),
 			merge_branch: GituiKeyEvent::new(KeyCode::Char('m'),  KeyModifiers::empty()),
 			rebase_branch: GituiKeyEvent::new(KeyCode::Char('R'),  KeyModifiers::SHIFT),
+			rebase_pick: GituiKeyEvent::new(KeyCode::Char('p'),  KeyModifiers::empty()),
+			rebase_reword: GituiKeyEvent::new(KeyCode::Char('r'),  KeyModifiers::empty()),
+			rebase_edit: GituiKeyEvent::new(KeyCode::Char('e'),  KeyModifiers::empty()),
+			rebase_squash: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::empty()),
+			rebase_fixup: GituiKeyEvent::new(KeyCode::Char('f'),  KeyModifiers::empty()),
+			rebase_drop: GituiKeyEvent::new(KeyCode::Char('d'),  KeyModifiers::empty()),
 			reset_branch: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::empty()),
 			compare_commits: GituiKeyEvent::new(KeyCode::Char('C'),  KeyModifiers::SHIFT),
 			tags: GituiKeyEvent::new(KeyCode::Char('T'),  KeyModifiers::SHIFT),
@@ -208,11 +273,17 @@ impl Default for KeysList {
 			fetch: GituiKeyEvent::new(KeyCode::Char('F'),  KeyModifiers::SHIFT),
 			pull: GituiKeyEvent::new(KeyCode::Char('f'),  KeyModifiers::empty()),
 			abort_merge: GituiKeyEvent::new(KeyCode::Char('A'),  KeyModifiers::SHIFT),
+			skip_cherry_pick: GituiKeyEvent::new(KeyCode::Char('N'),  KeyModifiers::SHIFT),
 			open_file_tree: GituiKeyEvent::new(KeyCode::Char('F'),  KeyModifiers::SHIFT),
 			file_find: GituiKeyEvent::new(KeyCode::Char('f'),  KeyModifiers::empty()),
 			branch_find: GituiKeyEvent::new(KeyCode::Char('f'),  KeyModifiers::empty()),
 			diff_hunk_next: GituiKeyEvent::new(KeyCode::Char('n'),  KeyModifiers::empty()),
 			diff_hunk_prev: GituiKeyEvent::new(KeyCode::Char('p'),  KeyModifiers::empty()),
+			diff_toggle_split: GituiKeyEvent::new(KeyCode::Char('v'),  KeyModifiers::empty()),
+			diff_show_whitespace: GituiKeyEvent::new(KeyCode::Char('W'),  KeyModifiers::SHIFT),
+			diff_toggle_all_files: GituiKeyEvent::new(KeyCode::Char('A'),  KeyModifiers::SHIFT),
+			diff_file_next: GituiKeyEvent::new(KeyCode::Char('N'),  KeyModifiers::SHIFT),
+			diff_file_prev: GituiKeyEvent::new(KeyCode::Char('P'),  KeyModifiers::SHIFT),
 			stage_unstage_item: GituiKeyEvent::new(KeyCode::Enter,  KeyModifiers::empty()),
 			tag_annotate: GituiKeyEvent::new(KeyCode::Char('a'),  KeyModifiers::CONTROL),
 			view_submodules: GituiKeyEvent::new(KeyCode::Char('S'),  KeyModifiers::SHIFT),
@@ -223,6 +294,8 @@ impl Default for KeysList {
 			delete_remote: GituiKeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE),
 			view_submodule_parent: GituiKeyEvent::new(KeyCode::Char('p'),  KeyModifiers::empty()),
 			update_submodule: GituiKeyEvent::new(KeyCode::Char('u'),  KeyModifiers::empty()),
+			open_clone: GituiKeyEvent::new(KeyCode::Char('O'),  KeyModifiers::SHIFT),
+			clone_recurse_submodules: GituiKeyEvent::new(KeyCode::Char('s'),  KeyModifiers::CONTROL),
 			commit_history_next: GituiKeyEvent::new(KeyCode::Char('n'),  KeyModifiers::CONTROL),
 			commit: GituiKeyEvent::new(KeyCode::Char('d'),  KeyModifiers::CONTROL),
 			newline: GituiKeyEvent::new(KeyCode::Enter,  KeyModifiers::empty()),
@@ -253,6 +326,178 @@ mod tests {
 	use std::io::Write;
 	use tempfile::NamedTempFile;
 
+	/// `(name, key)` of the given fields of `KeysList::default()`
+	macro_rules! context_keys {
+		($($key:ident),* $(,)?) => {{
+			let keys = KeysList::default();
+			vec![$((stringify!($key), keys.$key)),*]
+		}};
+	}
+
+	fn assert_unique_keys(
+		context: &str,
+		keys: &[(&str, GituiKeyEvent)],
+		with_global: bool,
+	) {
+		let global = context_keys!(
+			cmd_bar_toggle,
+			exit,
+			open_clone,
+			open_options,
+			quit,
+			tab_files,
+			tab_log,
+			tab_stashes,
+			tab_stashing,
+			tab_status,
+			tab_toggle,
+			tab_toggle_reverse,
+		);
+		let keys = keys
+			.iter()
+			.chain(global.iter().filter(|_| with_global))
+			.collect::<Vec<_>>();
+
+		for (i, (name, key)) in keys.iter().enumerate() {
+			for (other, other_key) in &keys[i + 1..] {
+				assert!(
+					name == other || key != other_key,
+					"{context}: `{name}` and `{other}` share {key:?}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_unique_keys_per_context() {
+		assert_unique_keys(
+			"status",
+			&context_keys!(
+				stage_unstage_item,
+				status_ignore_file,
+				status_intent_to_add,
+				status_reset_item,
+				status_resolve_conflict,
+				status_stage_all,
+				status_stage_pathspec,
+				blame,
+				copy,
+				edit_file,
+				end,
+				file_history,
+				home,
+				move_down,
+				move_left,
+				move_right,
+				move_up,
+				page_down,
+				page_up,
+				shift_down,
+				shift_up,
+				status_mark_item,
+				status_mark_range,
+				abort_merge,
+				fetch,
+				force_push,
+				open_commit,
+				pull,
+				push,
+				rebase_branch,
+				select_branch,
+				skip_cherry_pick,
+				toggle_workarea,
+				undo_commit,
+				view_submodules,
+			),
+			true,
+		);
+		assert_unique_keys(
+			"diff",
+			&context_keys!(
+				copy,
+				diff_edit_hunk,
+				diff_hunk_next,
+				diff_hunk_prev,
+				diff_reset_lines,
+				diff_show_whitespace,
+				diff_stage_lines,
+				diff_toggle_split,
+				edit_file,
+				end,
+				home,
+				move_down,
+				move_left,
+				move_right,
+				move_up,
+				page_down,
+				page_up,
+				shift_down,
+				shift_up,
+				stage_unstage_item,
+				status_reset_item,
+			),
+			true,
+		);
+		assert_unique_keys(
+			"diff files",
+			&context_keys!(
+				diff_file_next,
+				diff_file_prev,
+				diff_hunk_next,
+				diff_hunk_prev,
+				diff_show_whitespace,
+				diff_toggle_all_files,
+				end,
+				enter,
+				exit_popup,
+				home,
+				move_down,
+				move_left,
+				move_right,
+				move_up,
+				open_file_tree,
+				page_down,
+				page_up,
+			),
+			false,
+		);
+		assert_unique_keys(
+			"stage pathspec",
+			&context_keys!(
+				enter,
+				exit_popup,
+				pathspec_reset,
+				pathspec_unstage,
+			),
+			false,
+		);
+		assert_unique_keys(
+			"clone",
+			&context_keys!(
+				clone_recurse_submodules,
+				enter,
+				exit_popup,
+				tab_toggle,
+				tab_toggle_reverse,
+			),
+			false,
+		);
+		assert_unique_keys(
+			"conflict",
+			&context_keys!(
+				conflict_take_both,
+				conflict_take_ours,
+				conflict_take_theirs,
+				edit_file,
+				enter,
+				exit_popup,
+				move_down,
+				move_up,
+			),
+			false,
+		);
+	}
+
 	#[test]
 	fn test_apply_vim_style_example() {
 		let mut keys_list = KeysList::default();
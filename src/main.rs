@@ -81,15 +81,25 @@ mod ui;
 mod watcher;
 
 use crate::{
-	app::App,
+	app::{App, Environment},
 	args::{process_cmdline, CliArgs},
+	components::{Component, DrawableComponent},
+	keys::key_match,
+	options::Options,
+	popups::ClonePopup,
+	queue::{InternalEvent, Queue},
 };
 use anyhow::{anyhow, bail, Result};
 use app::QuitState;
-use asyncgit::{sync::RepoPath, AsyncGitNotification};
+use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	sync::{cred::extract_cred_from_url, CloneRequest, RepoPath},
+	AsyncCloneJob, AsyncGitNotification,
+};
 use backtrace::Backtrace;
-use crossbeam_channel::{Receiver, Select};
+use crossbeam_channel::{unbounded, Receiver, Select};
 use crossterm::{
+	event::Event,
 	terminal::{
 		disable_raw_mode, enable_raw_mode, EnterAlternateScreen,
 		LeaveAlternateScreen,
@@ -97,14 +107,17 @@ use crossterm::{
 	ExecutableCommand,
 };
 use gitui::Gitui;
-use input::InputEvent;
+use input::{Input, InputEvent};
 use keys::KeyConfig;
+use popups::PushPopup;
 use ratatui::backend::CrosstermBackend;
 use scopeguard::defer;
 use std::{
+	cell::RefCell,
 	io::{self, Stdout},
 	panic,
 	path::Path,
+	rc::Rc,
 	time::{Duration, Instant},
 };
 use ui::style::Theme;
@@ -134,6 +147,8 @@ pub enum SyntaxHighlightProgress {
 pub enum AsyncAppNotification {
 	///
 	SyntaxHighlighting(SyntaxHighlightProgress),
+	///
+	DiffHighlighting,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -161,10 +176,13 @@ macro_rules! log_eprintln {
 fn main() -> Result<()> {
 	let app_start = Instant::now();
 
-	let cliargs = process_cmdline()?;
+	let mut cliargs = process_cmdline()?;
 
 	asyncgit::register_tracing_logging();
-	ensure_valid_path(&cliargs.repo_path)?;
+	if let Some(request) = cliargs.clone.take() {
+		cliargs.repo_path = clone_repo(request)?;
+	}
+	ensure_valid_dir(&cliargs.repo_path)?;
 
 	let key_config = KeyConfig::init(
 		cliargs.key_bindings_path.as_ref(),
@@ -184,6 +202,22 @@ fn main() -> Result<()> {
 	let mut terminal =
 		start_terminal(io::stdout(), &cliargs.repo_path)?;
 
+	if let Some(e) =
+		asyncgit::sync::repo_open_error(&cliargs.repo_path)
+	{
+		log::info!("not a repository, offering to clone: {e}");
+
+		match clone_in_terminal(
+			&mut terminal,
+			&cliargs.repo_path,
+			theme.clone(),
+			&key_config,
+		)? {
+			Some(path) => cliargs.repo_path = path,
+			None => return Ok(()),
+		}
+	}
+
 	let updater = if cliargs.notify_watcher {
 		Updater::NotifyWatcher
 	} else {
@@ -211,6 +245,8 @@ fn main() -> Result<()> {
 					notify_watcher: args.notify_watcher,
 					key_bindings_path: args.key_bindings_path,
 					key_symbols_path: args.key_symbols_path,
+					log_revisions: None,
+					clone: None,
 				}
 			}
 			_ => break,
@@ -273,13 +309,115 @@ fn draw<B: ratatui::backend::Backend>(
 	Ok(())
 }
 
-fn ensure_valid_path(repo_path: &RepoPath) -> Result<()> {
-	match asyncgit::sync::repo_open_error(repo_path) {
-		Some(e) => {
-			log::error!("invalid repo path: {e}");
-			bail!("invalid repo path: {e}")
+/// directories that are no repository open the clone popup instead
+fn ensure_valid_dir(repo_path: &RepoPath) -> Result<()> {
+	if repo_path.gitpath().is_dir() {
+		Ok(())
+	} else {
+		log::error!(
+			"invalid repo path: {}",
+			repo_path.gitpath().display()
+		);
+		bail!(
+			"invalid repo path: '{}' is no directory",
+			repo_path.gitpath().display()
+		)
+	}
+}
+
+/// shows the clone popup when gitui is started outside a repository,
+/// returns the cloned repository or `None` if the user quits
+fn clone_in_terminal(
+	terminal: &mut Terminal,
+	repo_path: &RepoPath,
+	theme: Theme,
+	key_config: &KeyConfig,
+) -> Result<Option<RepoPath>> {
+	let (sender_git, rx_git) = unbounded();
+	let input = Input::new();
+	let rx_input = input.receiver();
+
+	let repo = RefCell::new(repo_path.clone());
+	let env = Environment {
+		queue: Queue::new(),
+		theme: Rc::new(theme),
+		key_config: Rc::new(key_config.clone()),
+		options: Options::new(repo.clone()),
+		repo,
+		sender_git,
+		sender_app: unbounded().0,
+	};
+
+	let mut popup = ClonePopup::new(&env);
+	popup.open()?;
+
+	let res = loop {
+		terminal.draw(|f| {
+			if let Err(e) = popup.draw(f, f.area()) {
+				log::error!("failed to draw: {e:?}");
+			}
+		})?;
+
+		let mut sel = Select::new();
+		sel.recv(&rx_input);
+		sel.recv(&rx_git);
+
+		let oper = sel.select();
+		if oper.index() == 0 {
+			if let InputEvent::Input(ev) = oper.recv(&rx_input)? {
+				if let Event::Key(key) = &ev {
+					if key_match(key, key_config.keys.exit) {
+						break None;
+					}
+				}
+
+				popup.event(&ev)?;
+			}
+		} else {
+			popup.update_git(oper.recv(&rx_git)?);
+		}
+
+		if let Some(InternalEvent::OpenRepo { path }) =
+			env.queue.pop()
+		{
+			break Some(RepoPath::Path(path));
+		}
+		if !popup.is_visible() {
+			break None;
+		}
+	};
+
+	input.set_polling(false);
+
+	Ok(res)
+}
+
+/// clones before the terminal is set up, printing the progress
+fn clone_repo(request: CloneRequest) -> Result<RepoPath> {
+	let (sender, receiver) = unbounded();
+	let job = AsyncSingleJob::new(sender);
+
+	let cred = extract_cred_from_url(&request.url);
+	let cred = cred.username.is_some().then_some(cred);
+
+	eprintln!("cloning into '{}'...", request.path.display());
+	job.spawn(AsyncCloneJob::new(request, cred));
+
+	loop {
+		receiver.recv()?;
+
+		if let Some(result) =
+			job.take_last().and_then(|job| job.result())
+		{
+			eprintln!();
+			return Ok(RepoPath::Path(result?));
+		}
+
+		if let Some(progress) = job.progress().flatten() {
+			let (state, percent) =
+				PushPopup::get_progress(Some(&progress));
+			eprint!("\r{state}: {percent:>3}%");
 		}
-		None => Ok(()),
 	}
 }
 
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use asyncgit::sync::{
-	diff::DiffOptions, repo_dir, RepoPathRef,
+	diff::{DiffAlgorithm, DiffOptions, RenameDetection},
+	repo_dir, LogWalkerOptions, RepoPathRef,
 	ShowUntrackedFilesConfig,
 };
 use ron::{
@@ -17,14 +18,30 @@ use std::{
 };
 
 #[derive(Default, Clone, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
 struct OptionsData {
 	pub tab: usize,
 	pub diff: DiffOptions,
 	pub status_show_untracked: Option<ShowUntrackedFilesConfig>,
 	pub commit_msgs: Vec<String>,
+	#[serde(default)]
+	pub log: LogWalkerOptions,
+	#[serde(default)]
+	pub notes_ref: Option<String>,
+	#[serde(default)]
+	pub log_signatures: bool,
+	#[serde(default)]
+	pub diff_split: bool,
+	#[serde(default)]
+	pub diff_show_whitespace: bool,
+	#[serde(default)]
+	pub diff_all_files: bool,
+	#[serde(default)]
+	pub diff_no_syntax: bool,
 }
 
 const COMMIT_MSG_HISTORY_LENGTH: usize = 20;
+const SIMILARITY_STEP: u16 = 10;
 
 #[derive(Clone)]
 pub struct Options {
@@ -107,6 +124,126 @@ impl Options {
 		self.save();
 	}
 
+	pub fn diff_toggle_whitespace_change(&mut self) {
+		self.data.diff.ignore_whitespace_change =
+			!self.data.diff.ignore_whitespace_change;
+
+		self.save();
+	}
+
+	pub fn diff_toggle_blank_lines(&mut self) {
+		self.data.diff.ignore_blank_lines =
+			!self.data.diff.ignore_blank_lines;
+
+		self.save();
+	}
+
+	pub fn set_diff_algorithm(&mut self, algorithm: DiffAlgorithm) {
+		self.data.diff.algorithm = algorithm;
+
+		self.save();
+	}
+
+	pub fn set_diff_renames(&mut self, renames: RenameDetection) {
+		self.data.diff.renames = renames;
+
+		self.save();
+	}
+
+	pub fn diff_similarity_change(&mut self, increase: bool) {
+		self.data.diff.similarity_threshold = if increase {
+			self.data
+				.diff
+				.similarity_threshold
+				.saturating_add(SIMILARITY_STEP)
+				.min(100)
+		} else {
+			self.data
+				.diff
+				.similarity_threshold
+				.saturating_sub(SIMILARITY_STEP)
+		};
+
+		self.save();
+	}
+
+	pub const fn diff_split(&self) -> bool {
+		self.data.diff_split
+	}
+
+	pub fn diff_toggle_split(&mut self) {
+		self.data.diff_split = !self.data.diff_split;
+
+		self.save();
+	}
+
+	pub const fn diff_show_whitespace(&self) -> bool {
+		self.data.diff_show_whitespace
+	}
+
+	pub fn diff_toggle_show_whitespace(&mut self) {
+		self.data.diff_show_whitespace =
+			!self.data.diff_show_whitespace;
+
+		self.save();
+	}
+
+	pub const fn diff_all_files(&self) -> bool {
+		self.data.diff_all_files
+	}
+
+	pub fn diff_toggle_all_files(&mut self) {
+		self.data.diff_all_files = !self.data.diff_all_files;
+
+		self.save();
+	}
+
+	pub const fn diff_syntax(&self) -> bool {
+		!self.data.diff_no_syntax
+	}
+
+	pub fn diff_toggle_syntax(&mut self) {
+		self.data.diff_no_syntax = !self.data.diff_no_syntax;
+
+		self.save();
+	}
+
+	pub const fn log_options(&self) -> LogWalkerOptions {
+		self.data.log
+	}
+
+	pub fn log_toggle_first_parent(&mut self) {
+		self.data.log.first_parent = !self.data.log.first_parent;
+
+		self.save();
+	}
+
+	pub fn log_toggle_hide_merges(&mut self) {
+		self.data.log.hide_merges = !self.data.log.hide_merges;
+
+		self.save();
+	}
+
+	pub const fn log_signatures(&self) -> bool {
+		self.data.log_signatures
+	}
+
+	pub fn log_toggle_signatures(&mut self) {
+		self.data.log_signatures = !self.data.log_signatures;
+
+		self.save();
+	}
+
+	pub fn notes_ref(&self) -> Option<String> {
+		self.data.notes_ref.clone()
+	}
+
+	pub fn set_notes_ref(&mut self, notes_ref: Option<String>) {
+		self.data.notes_ref = notes_ref;
+
+		self.save();
+	}
+
 	pub fn add_commit_msg(&mut self, msg: &str) {
 		self.data.commit_msgs.push(msg.to_owned());
 		while self.data.commit_msgs.len() > COMMIT_MSG_HISTORY_LENGTH
@@ -201,6 +201,10 @@ impl Component for BranchListPopup {
 						commit_id,
 					));
 				}
+			} else if key_match(e, self.key_config.keys.view_reflog)
+				&& self.valid_selection()
+			{
+				self.view_reflog();
 			} else if key_match(
 				e,
 				self.key_config.keys.cmd_bar_toggle,
@@ -676,6 +680,14 @@ impl BranchListPopup {
 		));
 	}
 
+	fn view_reflog(&mut self) {
+		let reference =
+			self.branches[self.selection as usize].reference.clone();
+
+		self.hide();
+		self.queue.push(InternalEvent::ViewReflog(reference));
+	}
+
 	fn delete_branch(&self) {
 		let reference =
 			self.branches[self.selection as usize].reference.clone();
@@ -781,6 +793,12 @@ impl BranchListPopup {
 			true,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::view_reflog(&self.key_config),
+			self.valid_selection(),
+			true,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::view_remotes(&self.key_config),
 			true,
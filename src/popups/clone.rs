@@ -0,0 +1,438 @@
+use crate::components::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, InputType, TextInputComponent,
+};
+use crate::{
+	app::Environment,
+	keys::{key_match, SharedKeyConfig},
+	popups::PushPopup,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::{anyhow, Result};
+use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	sync::{
+		clone_dir_name, cred::extract_cred_from_url,
+		utils::repo_work_dir, CloneRequest, RepoPathRef,
+	},
+	AsyncCloneJob, AsyncGitNotification, RemoteProgress,
+};
+use crossterm::event::Event;
+use ratatui::{
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Line, Span},
+	widgets::{Block, Borders, Clear, Gauge, Paragraph},
+	Frame,
+};
+use std::path::{Path, PathBuf};
+
+const FIELD_LABELS: [&str; 4] =
+	["url:", "directory:", "branch:", "depth:"];
+const LABEL_WIDTH: u16 = 11;
+
+/// clones a repository and opens it once done
+pub struct ClonePopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+	visible: bool,
+	inputs: [TextInputComponent; 4],
+	field: usize,
+	recurse_submodules: bool,
+	async_clone: AsyncSingleJob<AsyncCloneJob>,
+	progress: Option<RemoteProgress>,
+	pending: bool,
+	error: Option<String>,
+}
+
+impl ClonePopup {
+	///
+	pub fn new(env: &Environment) -> Self {
+		let input = |default_msg: &str| {
+			let mut input =
+				TextInputComponent::new(env, "", default_msg, false)
+					.with_input_type(InputType::Singleline);
+			input.embed();
+			input
+		};
+
+		Self {
+			repo: env.repo.clone(),
+			queue: env.queue.clone(),
+			theme: env.theme.clone(),
+			key_config: env.key_config.clone(),
+			visible: false,
+			inputs: [
+				input("https://host/repo.git or git@host:repo.git"),
+				input("name of the repository"),
+				input("remote HEAD"),
+				input("full history"),
+			],
+			field: 0,
+			recurse_submodules: false,
+			async_clone: AsyncSingleJob::new(env.sender_git.clone()),
+			progress: None,
+			pending: false,
+			error: None,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		for input in &mut self.inputs {
+			input.set_text(String::new());
+			input.hide();
+		}
+		self.inputs[1].set_default_msg(format!(
+			"name of the repository in {}",
+			self.base_dir().display()
+		));
+		self.field = 0;
+		self.recurse_submodules = false;
+		self.error = None;
+
+		self.show()?;
+		self.inputs[self.field].show()?;
+
+		Ok(())
+	}
+
+	///
+	pub const fn any_work_pending(&self) -> bool {
+		self.pending
+	}
+
+	/// a clone keeps running when the popup is closed and opens the
+	/// repository once done
+	pub fn update_git(&mut self, ev: AsyncGitNotification) {
+		if self.pending && ev == AsyncGitNotification::Clone {
+			self.progress = self.async_clone.progress().flatten();
+
+			if let Some(result) = self
+				.async_clone
+				.take_last()
+				.and_then(|job| job.result())
+			{
+				self.pending = false;
+
+				match result {
+					Ok(path) => {
+						self.hide();
+						self.queue
+							.push(InternalEvent::OpenRepo { path });
+					}
+					Err(e) if self.is_visible() => {
+						self.error = Some(e.to_string());
+					}
+					Err(e) => {
+						self.queue.push(InternalEvent::ShowErrorMsg(
+							format!("clone failed:\n{e}"),
+						));
+					}
+				}
+			}
+		}
+	}
+
+	/// relative directories are cloned next to the open repository,
+	/// or into the directory gitui was started in outside of one
+	fn base_dir(&self) -> PathBuf {
+		let repo = self.repo.borrow();
+
+		repo_work_dir(&repo)
+			.ok()
+			.and_then(|work_dir| {
+				Path::new(&work_dir).parent().map(Path::to_path_buf)
+			})
+			.unwrap_or_else(|| repo.gitpath().to_path_buf())
+	}
+
+	fn select_field(&mut self, back: bool) -> Result<()> {
+		let count = self.inputs.len();
+		self.inputs[self.field].hide();
+		self.field = if back {
+			(self.field + count - 1) % count
+		} else {
+			(self.field + 1) % count
+		};
+		self.inputs[self.field].show()
+	}
+
+	fn request(&self) -> Result<CloneRequest> {
+		let text = |field: usize| {
+			let text = self.inputs[field].get_text().trim();
+			(!text.is_empty()).then(|| text.to_string())
+		};
+
+		let url = text(0).ok_or_else(|| anyhow!("url missing"))?;
+		let dir = text(1)
+			.or_else(|| clone_dir_name(&url))
+			.ok_or_else(|| anyhow!("directory missing"))?;
+		let depth = text(3)
+			.map(|depth| {
+				depth
+					.parse()
+					.ok()
+					.filter(|depth| *depth > 0)
+					.ok_or_else(|| anyhow!("invalid depth: {depth}"))
+			})
+			.transpose()?;
+
+		Ok(CloneRequest {
+			url,
+			path: self.base_dir().join(dir),
+			branch: text(2),
+			depth,
+			recurse_submodules: self.recurse_submodules,
+		})
+	}
+
+	fn start_clone(&mut self) {
+		match self.request() {
+			Ok(request) => {
+				let cred = extract_cred_from_url(&request.url);
+				let cred = cred.username.is_some().then_some(cred);
+
+				self.error = None;
+				self.pending = true;
+				self.progress = None;
+				self.async_clone
+					.spawn(AsyncCloneJob::new(request, cred));
+			}
+			Err(e) => self.error = Some(e.to_string()),
+		}
+	}
+
+	fn draw_fields(
+		&self,
+		f: &mut Frame,
+		rows: &[Rect],
+	) -> Result<()> {
+		for (i, label) in FIELD_LABELS.iter().enumerate() {
+			let [label_area, input_area] = *Layout::default()
+				.direction(Direction::Horizontal)
+				.constraints([
+					Constraint::Length(LABEL_WIDTH),
+					Constraint::Min(0),
+				])
+				.split(rows[i])
+			else {
+				continue;
+			};
+
+			f.render_widget(
+				Paragraph::new(Span::styled(
+					*label,
+					self.theme.text(i == self.field, false),
+				)),
+				label_area,
+			);
+
+			if i == self.field {
+				self.inputs[i].draw(f, input_area)?;
+			} else {
+				f.render_widget(
+					Paragraph::new(Span::styled(
+						self.inputs[i].get_text(),
+						self.theme.text(false, false),
+					)),
+					input_area,
+				);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn draw_status(&self, f: &mut Frame, area: Rect) {
+		if self.pending {
+			let (state, progress) =
+				PushPopup::get_progress(self.progress.as_ref());
+
+			f.render_widget(
+				Gauge::default()
+					.label(state.as_str())
+					.gauge_style(self.theme.push_gauge())
+					.percent(u16::from(progress)),
+				area,
+			);
+		} else if let Some(error) = &self.error {
+			f.render_widget(
+				Paragraph::new(Line::from(Span::styled(
+					error.as_str(),
+					self.theme.text_danger(),
+				))),
+				area,
+			);
+		}
+	}
+}
+
+impl DrawableComponent for ClonePopup {
+	fn draw(&self, f: &mut Frame, area: Rect) -> Result<()> {
+		if self.is_visible() {
+			const SIZE: (u16, u16) = (70, 9);
+
+			let area =
+				ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.style(self.theme.title(true))
+					.title(Span::styled(
+						strings::POPUP_TITLE_CLONE,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let rows = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints([Constraint::Length(1); 7])
+				.split(area.inner(Margin {
+					horizontal: 1,
+					vertical: 1,
+				}));
+
+			self.draw_fields(f, &rows)?;
+
+			f.render_widget(
+				Paragraph::new(Span::styled(
+					strings::clone_recurse_submodules(
+						self.recurse_submodules,
+					),
+					self.theme.text(true, false),
+				)),
+				rows[4],
+			);
+
+			self.draw_status(f, rows[6]);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for ClonePopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::clone_confirm(&self.key_config),
+				!self.pending,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::clone_next_field(&self.key_config),
+				!self.pending,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::clone_recurse_submodules(
+					&self.key_config,
+				),
+				!self.pending,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys.exit_popup) {
+					self.hide();
+					return Ok(EventState::Consumed);
+				} else if self.pending {
+					return Ok(EventState::Consumed);
+				} else if key_match(key, self.key_config.keys.enter) {
+					self.start_clone();
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					key,
+					self.key_config.keys.tab_toggle,
+				) || key_match(
+					key,
+					self.key_config.keys.tab_toggle_reverse,
+				) {
+					self.select_field(key_match(
+						key,
+						self.key_config.keys.tab_toggle_reverse,
+					))?;
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					key,
+					self.key_config.keys.clone_recurse_submodules,
+				) {
+					self.recurse_submodules =
+						!self.recurse_submodules;
+					return Ok(EventState::Consumed);
+				}
+			}
+
+			if !self.pending {
+				self.inputs[self.field].event(event)?;
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use asyncgit::sync::RepoPath;
+	use git2_testing::repo_init_empty;
+
+	#[test]
+	fn test_relative_dir_next_to_repo() {
+		let (td, _repo) = repo_init_empty();
+		let env = Environment::test_env();
+		env.repo.replace(RepoPath::Path(td.path().to_path_buf()));
+
+		let mut popup = ClonePopup::new(&env);
+		popup.inputs[0].set_text(String::from("../foo/bar.git"));
+
+		let work_dir = td.path().canonicalize().unwrap();
+		let parent = work_dir.parent().unwrap();
+		assert_eq!(popup.request().unwrap().path, parent.join("bar"));
+
+		popup.inputs[1].set_text(String::from("/tmp/baz"));
+		assert_eq!(
+			popup.request().unwrap().path,
+			PathBuf::from("/tmp/baz")
+		);
+	}
+}
@@ -1,7 +1,7 @@
 use crate::components::{
 	command_pump, event_pump, visibility_blocking, CommandBlocking,
 	CommandInfo, CommitDetailsComponent, Component, DiffComponent,
-	DrawableComponent, EventState,
+	DiffFilesComponent, DrawableComponent, EventState,
 };
 use crate::{
 	accessors,
@@ -10,13 +10,13 @@ use crate::{
 	options::SharedOptions,
 	popups::InspectCommitOpen,
 	queue::{InternalEvent, Queue, StackablePopupOpen},
-	strings,
+	strings, AsyncNotification,
 };
 use anyhow::Result;
 use asyncgit::{
 	sync::{self, commit_files::OldNew, CommitId, RepoPathRef},
-	AsyncDiff, AsyncGitNotification, CommitFilesParams, DiffParams,
-	DiffType,
+	AsyncDiff, AsyncGitNotification, CommitFilesParams,
+	DiffFilesParams, DiffParams, DiffType,
 };
 use crossterm::event::Event;
 use ratatui::{
@@ -29,6 +29,7 @@ pub struct CompareCommitsPopup {
 	repo: RepoPathRef,
 	open_request: Option<InspectCommitOpen>,
 	diff: DiffComponent,
+	diff_files: DiffFilesComponent,
 	details: CommitDetailsComponent,
 	git_diff: AsyncDiff,
 	visible: bool,
@@ -40,7 +41,7 @@ pub struct CompareCommitsPopup {
 impl DrawableComponent for CompareCommitsPopup {
 	fn draw(&self, f: &mut Frame, rect: Rect) -> Result<()> {
 		if self.is_visible() {
-			let percentages = if self.diff.focused() {
+			let percentages = if self.diff_focused() {
 				(0, 100)
 			} else {
 				(50, 50)
@@ -60,7 +61,11 @@ impl DrawableComponent for CompareCommitsPopup {
 			f.render_widget(Clear, rect);
 
 			self.details.draw(f, chunks[0])?;
-			self.diff.draw(f, chunks[1])?;
+			if self.all_files() {
+				self.diff_files.draw(f, chunks[1])?;
+			} else {
+				self.diff.draw(f, chunks[1])?;
+			}
 		}
 
 		Ok(())
@@ -92,13 +97,21 @@ impl Component for CompareCommitsPopup {
 			out.push(CommandInfo::new(
 				strings::commands::diff_focus_right(&self.key_config),
 				self.can_focus_diff(),
-				!self.diff.focused() || force_all,
+				!self.diff_focused() || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::diff_toggle_all_files(
+					&self.key_config,
+				),
+				true,
+				true,
 			));
 
 			out.push(CommandInfo::new(
 				strings::commands::diff_focus_left(&self.key_config),
 				true,
-				self.diff.focused() || force_all,
+				self.diff_focused() || force_all,
 			));
 		}
 
@@ -118,9 +131,8 @@ impl Component for CompareCommitsPopup {
 
 			if let Event::Key(e) = ev {
 				if key_match(e, self.key_config.keys.exit_popup) {
-					if self.diff.focused() {
-						self.details.focus(true);
-						self.diff.focus(false);
+					if self.diff_focused() {
+						self.focus_diff(false);
 					} else {
 						self.hide_stacked(false);
 					}
@@ -129,11 +141,15 @@ impl Component for CompareCommitsPopup {
 					self.key_config.keys.move_right,
 				) && self.can_focus_diff()
 				{
-					self.details.focus(false);
-					self.diff.focus(true);
+					self.focus_diff(true);
 				} else if key_match(e, self.key_config.keys.move_left)
 				{
 					self.hide_stacked(false);
+				} else if key_match(
+					e,
+					self.key_config.keys.diff_toggle_all_files,
+				) {
+					self.toggle_all_files()?;
 				}
 
 				return Ok(EventState::Consumed);
@@ -152,15 +168,14 @@ impl Component for CompareCommitsPopup {
 	fn show(&mut self) -> Result<()> {
 		self.visible = true;
 		self.details.show()?;
-		self.details.focus(true);
-		self.diff.focus(false);
+		self.focus_diff(false);
 		self.update()?;
 		Ok(())
 	}
 }
 
 impl CompareCommitsPopup {
-	accessors!(self, [diff, details]);
+	accessors!(self, [diff, diff_files, details]);
 
 	///
 	pub fn new(env: &Environment) -> Self {
@@ -168,6 +183,7 @@ impl CompareCommitsPopup {
 			repo: env.repo.clone(),
 			details: CommitDetailsComponent::new(env),
 			diff: DiffComponent::new(env, true),
+			diff_files: DiffFilesComponent::new(env),
 			open_request: None,
 			git_diff: AsyncDiff::new(
 				env.repo.borrow().clone(),
@@ -199,7 +215,15 @@ impl CompareCommitsPopup {
 
 	///
 	pub fn any_work_pending(&self) -> bool {
-		self.git_diff.is_pending() || self.details.any_work_pending()
+		self.git_diff.is_pending()
+			|| self.details.any_work_pending()
+			|| self.diff.any_work_pending()
+			|| self.diff_files.any_work_pending()
+	}
+
+	///
+	pub fn update_async(&mut self, ev: AsyncNotification) {
+		self.diff.update_async(ev);
 	}
 
 	///
@@ -212,6 +236,8 @@ impl CompareCommitsPopup {
 				self.update()?;
 			} else if ev == AsyncGitNotification::Diff {
 				self.update_diff()?;
+			} else {
+				self.diff_files.update_git(ev);
 			}
 		}
 
@@ -235,7 +261,21 @@ impl CompareCommitsPopup {
 
 	/// called when any tree component changed selection
 	pub fn update_diff(&mut self) -> Result<()> {
-		if self.is_visible() {
+		if self.is_visible() && self.all_files() {
+			if let Some(ids) = self.get_ids() {
+				self.diff_files.request(
+					DiffFilesParams {
+						id: ids.new,
+						compare: Some(ids.old),
+						options: self.options.borrow().diff_options(),
+					},
+					self.details
+						.files()
+						.selection_file()
+						.map(|f| f.path),
+				);
+			}
+		} else if self.is_visible() {
 			if let Some(ids) = self.get_ids() {
 				if let Some(f) = self.details.files().selection_file()
 				{
@@ -280,6 +320,30 @@ impl CompareCommitsPopup {
 		self.details.files().selection_file().is_some()
 	}
 
+	fn all_files(&self) -> bool {
+		self.options.borrow().diff_all_files()
+	}
+
+	fn diff_focused(&self) -> bool {
+		self.diff.focused() || self.diff_files.focused()
+	}
+
+	fn focus_diff(&mut self, focus: bool) {
+		let all_files = self.all_files();
+
+		self.details.focus(!focus);
+		self.diff.focus(focus && !all_files);
+		self.diff_files.focus(focus && all_files);
+	}
+
+	fn toggle_all_files(&mut self) -> Result<()> {
+		let focused = self.diff_focused();
+
+		self.options.borrow_mut().diff_toggle_all_files();
+		self.focus_diff(focused);
+		self.update_diff()
+	}
+
 	fn hide_stacked(&mut self, stack: bool) {
 		self.hide();
 		if stack {
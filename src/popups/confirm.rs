@@ -125,6 +125,7 @@ impl ConfirmPopup {
 		self.hide();
 	}
 
+	#[allow(clippy::too_many_lines)]
 	fn get_text(&self) -> (String, String) {
 		if let Some(ref a) = self.target {
 			return match a {
@@ -132,6 +133,10 @@ impl ConfirmPopup {
                     strings::confirm_title_reset(),
                     strings::confirm_msg_reset(),
                 ),
+                Action::ResetItems(items) => (
+                    strings::confirm_title_reset(),
+                    strings::confirm_msg_reset_items(items.len()),
+                ),
                 Action::StashDrop(ids) => (
                     strings::confirm_title_stashdrop(
                         &self.key_config,ids.len()>1
@@ -209,11 +214,23 @@ impl ConfirmPopup {
 				Action::AbortRevert => (
                     strings::confirm_title_abortrevert(),
                     strings::confirm_msg_revertchanges(),
+                ),
+				Action::AbortCherryPick => (
+                    strings::confirm_title_abortcherrypick(),
+                    strings::confirm_msg_abortcherrypick(),
+                ),
+				Action::AbortBisect => (
+                    strings::confirm_title_abortbisect(),
+                    strings::confirm_msg_abortbisect(),
                 ),
                 Action::UndoCommit => (
                     strings::confirm_title_undo_commit(),
                     strings::confirm_msg_undo_commit(),
                 ),
+                Action::RemoveNote(_) => (
+                    strings::confirm_title_remove_note(),
+                    strings::confirm_msg_remove_note(),
+                ),
             };
 		}
 
@@ -0,0 +1,437 @@
+use crate::components::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	app::Environment,
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	string_utils::tabs_to_spaces,
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme, Size},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{
+		self, ConflictChunk, ConflictLine, ConflictRegion,
+		ConflictResolution, FileConflict, RepoPathRef,
+	},
+	DiffLineType,
+};
+use crossterm::event::Event;
+use ratatui::{
+	layout::{Margin, Rect},
+	style::Style,
+	text::{Line, Span},
+	widgets::{Block, BorderType, Borders, Clear, Paragraph},
+	Frame,
+};
+use std::cell::Cell;
+
+/// shows the conflict regions of a conflicted file and lets the user
+/// resolve each one with our lines, their lines or both
+pub struct ConflictPopup {
+	repo: RepoPathRef,
+	theme: SharedTheme,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+	visible: bool,
+	path: String,
+	conflict: FileConflict,
+	resolutions: Vec<Option<ConflictResolution>>,
+	selection: usize,
+	scroll: Cell<usize>,
+}
+
+impl DrawableComponent for ConflictPopup {
+	fn draw(&self, f: &mut Frame, rect: Rect) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(80, 70);
+			const MIN_SIZE: Size = Size::new(60, 20);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.area(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.area().into(), area);
+			let area = area.intersection(rect);
+
+			let (lines, selected_start) = self.get_lines();
+			let number_of_lines = lines.len();
+
+			let height = usize::from(area.height.saturating_sub(2));
+			let scroll = Self::scroll_to(
+				self.scroll.get(),
+				selected_start,
+				height,
+			);
+			self.scroll.set(scroll);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(lines)
+					.block(
+						Block::default()
+							.borders(Borders::ALL)
+							.title(Span::styled(
+								strings::title_conflict(
+									&self.path,
+									self.resolved(),
+									self.resolutions.len(),
+								),
+								self.theme.title(true),
+							))
+							.border_style(self.theme.block(true))
+							.border_type(BorderType::Thick),
+					)
+					.scroll((u16::try_from(scroll)?, 0)),
+				area,
+			);
+
+			ui::draw_scrollbar(
+				f,
+				area.inner(Margin {
+					vertical: 1,
+					horizontal: 0,
+				}),
+				&self.theme,
+				number_of_lines,
+				scroll,
+				ui::Orientation::Vertical,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for ConflictPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			let has_conflicts = !self.resolutions.is_empty();
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::conflict_take_ours(
+					&self.key_config,
+				),
+				has_conflicts,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::conflict_take_theirs(
+					&self.key_config,
+				),
+				has_conflicts,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::conflict_take_both(
+					&self.key_config,
+				),
+				has_conflicts,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::conflict_edit(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::conflict_mark_resolved(
+					&self.key_config,
+				),
+				self.all_resolved(),
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys.exit_popup) {
+					self.hide();
+				} else if key_match(key, self.key_config.keys.move_up)
+				{
+					self.selection = self.selection.saturating_sub(1);
+				} else if key_match(
+					key,
+					self.key_config.keys.move_down,
+				) {
+					self.selection =
+						self.selection.saturating_add(1).min(
+							self.resolutions.len().saturating_sub(1),
+						);
+				} else if key_match(
+					key,
+					self.key_config.keys.conflict_take_ours,
+				) {
+					self.resolve_selected(ConflictResolution::Ours);
+				} else if key_match(
+					key,
+					self.key_config.keys.conflict_take_theirs,
+				) {
+					self.resolve_selected(ConflictResolution::Theirs);
+				} else if key_match(
+					key,
+					self.key_config.keys.conflict_take_both,
+				) {
+					self.resolve_selected(ConflictResolution::Both);
+				} else if key_match(
+					key,
+					self.key_config.keys.edit_file,
+				) {
+					self.edit();
+				} else if key_match(key, self.key_config.keys.enter) {
+					self.mark_resolved();
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl ConflictPopup {
+	pub fn new(env: &Environment) -> Self {
+		Self {
+			repo: env.repo.clone(),
+			theme: env.theme.clone(),
+			queue: env.queue.clone(),
+			key_config: env.key_config.clone(),
+			visible: false,
+			path: String::new(),
+			conflict: FileConflict::default(),
+			resolutions: Vec::new(),
+			selection: 0,
+			scroll: Cell::new(0),
+		}
+	}
+
+	/// open the conflict regions of the conflicted file at `path`
+	pub fn open(&mut self, path: String) -> Result<()> {
+		self.conflict =
+			sync::get_file_conflict(&self.repo.borrow(), &path)?;
+		self.resolutions = vec![None; self.conflict.conflicts()];
+		self.path = path;
+		self.selection = 0;
+		self.scroll.set(0);
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn resolved(&self) -> usize {
+		self.resolutions.iter().flatten().count()
+	}
+
+	fn all_resolved(&self) -> bool {
+		self.resolved() == self.resolutions.len()
+	}
+
+	fn resolve_selected(&mut self, resolution: ConflictResolution) {
+		if let Some(selected) =
+			self.resolutions.get_mut(self.selection)
+		{
+			*selected = Some(resolution);
+			self.selection = self
+				.selection
+				.saturating_add(1)
+				.min(self.resolutions.len().saturating_sub(1));
+		}
+	}
+
+	/// writes the picked resolutions, unresolved regions keep their
+	/// markers, and hands the file to the external editor
+	fn edit(&mut self) {
+		try_or_popup!(
+			self,
+			"write conflict error:",
+			sync::write_conflict(
+				&self.repo.borrow(),
+				&self.path,
+				&self.conflict,
+				&self.resolutions,
+			)
+		);
+
+		self.hide();
+		self.queue.push(InternalEvent::OpenExternalEditor(Some(
+			self.path.clone(),
+		)));
+	}
+
+	fn mark_resolved(&mut self) {
+		if !self.all_resolved() {
+			return;
+		}
+
+		try_or_popup!(
+			self,
+			"resolve conflict error:",
+			sync::resolve_conflict(
+				&self.repo.borrow(),
+				&self.path,
+				&self.conflict,
+				&self.resolutions,
+			)
+		);
+
+		self.hide();
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+	}
+
+	/// keeps `target` within the `height` lines shown from `scroll`
+	const fn scroll_to(
+		scroll: usize,
+		target: usize,
+		height: usize,
+	) -> usize {
+		if target < scroll {
+			target
+		} else if target >= scroll + height {
+			target.saturating_sub(height / 3)
+		} else {
+			scroll
+		}
+	}
+
+	/// lines to draw and the index of the selected region's header
+	fn get_lines(&self) -> (Vec<Line<'_>>, usize) {
+		let mut lines = Vec::new();
+		let mut selected_start = 0;
+		let mut idx = 0;
+
+		for chunk in &self.conflict.chunks {
+			match chunk {
+				ConflictChunk::Merged(merged) => {
+					push_lines(
+						&mut lines,
+						merged,
+						self.theme
+							.diff_line(DiffLineType::None, false),
+					);
+				}
+				ConflictChunk::Conflict(region) => {
+					let selected = idx == self.selection;
+					if selected {
+						selected_start = lines.len();
+					}
+					self.push_region(
+						&mut lines, region, idx, selected,
+					);
+					idx += 1;
+				}
+			}
+		}
+
+		(lines, selected_start)
+	}
+
+	fn push_region(
+		&self,
+		lines: &mut Vec<Line<'_>>,
+		region: &ConflictRegion,
+		idx: usize,
+		selected: bool,
+	) {
+		let resolution = self.resolutions.get(idx).copied().flatten();
+		let marker = self.theme.diff_hunk_marker(selected);
+		let ours = self.theme.diff_line(DiffLineType::Delete, false);
+		let theirs = self.theme.diff_line(DiffLineType::Add, false);
+
+		let title = match resolution {
+			None => "unresolved",
+			Some(ConflictResolution::Ours) => "ours",
+			Some(ConflictResolution::Theirs) => "theirs",
+			Some(ConflictResolution::Both) => "both",
+		};
+		lines.push(Line::from(Span::styled(
+			format!(
+				"── conflict {}/{}: {title}",
+				idx + 1,
+				self.resolutions.len()
+			),
+			marker,
+		)));
+
+		match resolution {
+			Some(ConflictResolution::Ours) => {
+				push_lines(lines, &region.ours, ours);
+			}
+			Some(ConflictResolution::Theirs) => {
+				push_lines(lines, &region.theirs, theirs);
+			}
+			Some(ConflictResolution::Both) => {
+				push_lines(lines, &region.ours, ours);
+				push_lines(lines, &region.theirs, theirs);
+			}
+			None => {
+				lines.push(Line::from(Span::styled(
+					"<<< ours", marker,
+				)));
+				push_lines(lines, &region.ours, ours);
+				lines.push(Line::from(Span::styled(
+					"=== theirs",
+					marker,
+				)));
+				push_lines(lines, &region.theirs, theirs);
+				lines.push(Line::from(Span::styled(">>>", marker)));
+			}
+		}
+	}
+}
+
+fn push_lines(
+	lines: &mut Vec<Line<'_>>,
+	content: &[ConflictLine],
+	style: Style,
+) {
+	lines.extend(content.iter().map(|line| {
+		Line::from(Span::styled(
+			tabs_to_spaces(
+				String::from_utf8_lossy(line)
+					.trim_end_matches(['\n', '\r'])
+					.to_string(),
+			),
+			style,
+		))
+	}));
+}
@@ -10,7 +10,7 @@ use crate::{
 	ui::style::SharedTheme,
 };
 use anyhow::Result;
-use asyncgit::sync::{self, RepoPathRef};
+use asyncgit::sync::{self, CommitId, RepoPathRef};
 use crossterm::event::{Event, KeyCode, KeyEvent};
 use easy_cast::Cast;
 use ratatui::{layout::Rect, widgets::Paragraph, Frame};
@@ -19,6 +19,7 @@ use std::borrow::Cow;
 pub struct CreateBranchPopup {
 	repo: RepoPathRef,
 	input: TextInputComponent,
+	commit: Option<CommitId>,
 	queue: Queue,
 	key_config: SharedKeyConfig,
 	theme: SharedTheme,
@@ -117,6 +118,7 @@ impl CreateBranchPopup {
 				true,
 			)
 			.with_input_type(InputType::Singleline),
+			commit: None,
 			theme: env.theme.clone(),
 			key_config: env.key_config.clone(),
 			repo: env.repo.clone(),
@@ -125,6 +127,16 @@ impl CreateBranchPopup {
 
 	///
 	pub fn open(&mut self) -> Result<()> {
+		self.commit = None;
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// create the branch at `commit` instead of `HEAD`, without
+	/// checking it out
+	pub fn open_at(&mut self, commit: CommitId) -> Result<()> {
+		self.commit = Some(commit);
 		self.show()?;
 
 		Ok(())
@@ -132,9 +144,20 @@ impl CreateBranchPopup {
 
 	///
 	pub fn create_branch(&mut self) {
-		let res = sync::create_branch(
-			&self.repo.borrow(),
-			self.input.get_text(),
+		let res = self.commit.map_or_else(
+			|| {
+				sync::create_branch(
+					&self.repo.borrow(),
+					self.input.get_text(),
+				)
+			},
+			|commit| {
+				sync::create_branch_at(
+					&self.repo.borrow(),
+					self.input.get_text(),
+					commit,
+				)
+			},
 		);
 
 		self.input.clear();
@@ -10,7 +10,8 @@ use crate::{
 };
 use anyhow::{anyhow, bail, Result};
 use asyncgit::sync::{
-	get_config_string, utils::repo_work_dir, RepoPath,
+	self, diff::DiffOptions, get_config_string, utils::repo_work_dir,
+	RepoPath,
 };
 use crossterm::{
 	event::Event,
@@ -25,7 +26,7 @@ use ratatui::{
 };
 use scopeguard::defer;
 use std::ffi::OsStr;
-use std::{env, io, path::Path, process::Command};
+use std::{env, fs, io, path::Path, process::Command};
 
 ///
 pub struct ExternalEditorPopup {
@@ -117,6 +118,48 @@ impl ExternalEditorPopup {
 
 		Ok(())
 	}
+
+	/// opens the unstaged hunk `hunk_hash` of `file_path` as a patch in an
+	/// available editor and stages the edited patch
+	pub fn edit_hunk(
+		repo: &RepoPath,
+		key_config: &SharedKeyConfig,
+		file_path: &str,
+		hunk_hash: u64,
+		options: DiffOptions,
+	) -> Result<()> {
+		let patch = sync::hunk_patch(
+			repo,
+			file_path,
+			hunk_hash,
+			Some(options),
+		)?;
+
+		let patch_path = sync::repo_dir(repo)?.join("ADD_EDIT.patch");
+		fs::write(
+			&patch_path,
+			format!(
+				"{patch}{}\n",
+				strings::edit_hunk_msg(key_config)
+			),
+		)?;
+		defer! {
+			fs::remove_file(&patch_path).ok();
+		}
+
+		Self::open_file_in_editor(repo, &patch_path)?;
+
+		let edited = fs::read_to_string(&patch_path)?;
+		if edited.lines().all(|line| {
+			line.trim().is_empty() || line.starts_with('#')
+		}) {
+			return Ok(());
+		}
+
+		sync::stage_edited_hunk(repo, file_path, &edited)?;
+
+		Ok(())
+	}
 }
 
 impl DrawableComponent for ExternalEditorPopup {
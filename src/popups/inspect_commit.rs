@@ -1,7 +1,7 @@
 use crate::components::{
 	command_pump, event_pump, visibility_blocking, CommandBlocking,
 	CommandInfo, CommitDetailsComponent, Component, DiffComponent,
-	DrawableComponent, EventState,
+	DiffFilesComponent, DrawableComponent, EventState,
 };
 use crate::{
 	accessors,
@@ -9,12 +9,13 @@ use crate::{
 	keys::{key_match, SharedKeyConfig},
 	options::SharedOptions,
 	queue::{InternalEvent, Queue, StackablePopupOpen},
-	strings,
+	strings, AsyncNotification,
 };
 use anyhow::Result;
 use asyncgit::{
 	sync::{CommitId, CommitTags},
-	AsyncDiff, AsyncGitNotification, DiffParams, DiffType,
+	AsyncDiff, AsyncGitNotification, DiffFilesParams, DiffParams,
+	DiffType,
 };
 use crossterm::event::Event;
 use ratatui::{
@@ -58,6 +59,7 @@ pub struct InspectCommitPopup {
 	queue: Queue,
 	open_request: Option<InspectCommitOpen>,
 	diff: DiffComponent,
+	diff_files: DiffFilesComponent,
 	details: CommitDetailsComponent,
 	git_diff: AsyncDiff,
 	visible: bool,
@@ -68,7 +70,7 @@ pub struct InspectCommitPopup {
 impl DrawableComponent for InspectCommitPopup {
 	fn draw(&self, f: &mut Frame, rect: Rect) -> Result<()> {
 		if self.is_visible() {
-			let percentages = if self.diff.focused() {
+			let percentages = if self.diff_focused() {
 				(0, 100)
 			} else {
 				(50, 50)
@@ -88,7 +90,11 @@ impl DrawableComponent for InspectCommitPopup {
 			f.render_widget(Clear, rect);
 
 			self.details.draw(f, chunks[0])?;
-			self.diff.draw(f, chunks[1])?;
+			if self.all_files() {
+				self.diff_files.draw(f, chunks[1])?;
+			} else {
+				self.diff.draw(f, chunks[1])?;
+			}
 		}
 
 		Ok(())
@@ -120,13 +126,21 @@ impl Component for InspectCommitPopup {
 			out.push(CommandInfo::new(
 				strings::commands::diff_focus_right(&self.key_config),
 				self.can_focus_diff(),
-				!self.diff.focused() || force_all,
+				!self.diff_focused() || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::diff_toggle_all_files(
+					&self.key_config,
+				),
+				true,
+				true,
 			));
 
 			out.push(CommandInfo::new(
 				strings::commands::close_popup(&self.key_config),
 				true,
-				self.diff.focused() || force_all,
+				self.diff_focused() || force_all,
 			));
 
 			out.push(CommandInfo::new(
@@ -155,9 +169,8 @@ impl Component for InspectCommitPopup {
 
 			if let Event::Key(e) = ev {
 				if key_match(e, self.key_config.keys.exit_popup) {
-					if self.diff.focused() {
-						self.details.focus(true);
-						self.diff.focus(false);
+					if self.diff_focused() {
+						self.focus_diff(false);
 					} else {
 						self.hide_stacked(false);
 					}
@@ -166,11 +179,15 @@ impl Component for InspectCommitPopup {
 					self.key_config.keys.move_right,
 				) && self.can_focus_diff()
 				{
-					self.details.focus(false);
-					self.diff.focus(true);
+					self.focus_diff(true);
 				} else if key_match(e, self.key_config.keys.move_left)
 				{
 					self.hide_stacked(false);
+				} else if key_match(
+					e,
+					self.key_config.keys.diff_toggle_all_files,
+				) {
+					self.toggle_all_files()?;
 				} else if key_match(
 					e,
 					self.key_config.keys.open_file_tree,
@@ -207,15 +224,14 @@ impl Component for InspectCommitPopup {
 	fn show(&mut self) -> Result<()> {
 		self.visible = true;
 		self.details.show()?;
-		self.details.focus(true);
-		self.diff.focus(false);
+		self.focus_diff(false);
 		self.update()?;
 		Ok(())
 	}
 }
 
 impl InspectCommitPopup {
-	accessors!(self, [diff, details]);
+	accessors!(self, [diff, diff_files, details]);
 
 	///
 	pub fn new(env: &Environment) -> Self {
@@ -223,6 +239,7 @@ impl InspectCommitPopup {
 			queue: env.queue.clone(),
 			details: CommitDetailsComponent::new(env),
 			diff: DiffComponent::new(env, true),
+			diff_files: DiffFilesComponent::new(env),
 			open_request: None,
 			git_diff: AsyncDiff::new(
 				env.repo.borrow().clone(),
@@ -244,7 +261,15 @@ impl InspectCommitPopup {
 
 	///
 	pub fn any_work_pending(&self) -> bool {
-		self.git_diff.is_pending() || self.details.any_work_pending()
+		self.git_diff.is_pending()
+			|| self.details.any_work_pending()
+			|| self.diff.any_work_pending()
+			|| self.diff_files.any_work_pending()
+	}
+
+	///
+	pub fn update_async(&mut self, ev: AsyncNotification) {
+		self.diff.update_async(ev);
 	}
 
 	///
@@ -257,6 +282,9 @@ impl InspectCommitPopup {
 				self.update()?;
 			} else if ev == AsyncGitNotification::Diff {
 				self.update_diff()?;
+			} else {
+				self.details.update_git(ev);
+				self.diff_files.update_git(ev);
 			}
 		}
 
@@ -265,7 +293,21 @@ impl InspectCommitPopup {
 
 	/// called when any tree component changed selection
 	pub fn update_diff(&mut self) -> Result<()> {
-		if self.is_visible() {
+		if self.is_visible() && self.all_files() {
+			if let Some(request) = &self.open_request {
+				self.diff_files.request(
+					DiffFilesParams {
+						id: request.commit_id,
+						compare: None,
+						options: self.options.borrow().diff_options(),
+					},
+					self.details
+						.files()
+						.selection_file()
+						.map(|f| f.path),
+				);
+			}
+		} else if self.is_visible() {
 			if let Some(request) = &self.open_request {
 				if let Some(f) = self.details.files().selection_file()
 				{
@@ -314,6 +356,30 @@ impl InspectCommitPopup {
 		self.details.files().selection_file().is_some()
 	}
 
+	fn all_files(&self) -> bool {
+		self.options.borrow().diff_all_files()
+	}
+
+	fn diff_focused(&self) -> bool {
+		self.diff.focused() || self.diff_files.focused()
+	}
+
+	fn focus_diff(&mut self, focus: bool) {
+		let all_files = self.all_files();
+
+		self.details.focus(!focus);
+		self.diff.focus(focus && !all_files);
+		self.diff_files.focus(focus && all_files);
+	}
+
+	fn toggle_all_files(&mut self) -> Result<()> {
+		let focused = self.diff_focused();
+
+		self.options.borrow_mut().diff_toggle_all_files();
+		self.focus_diff(focused);
+		self.update_diff()
+	}
+
 	fn hide_stacked(&mut self, stack: bool) {
 		self.hide();
 
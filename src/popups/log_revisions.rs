@@ -0,0 +1,136 @@
+use crate::components::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, InputType, TextInputComponent,
+};
+use crate::{
+	app::Environment,
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, Queue},
+	strings,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use ratatui::{layout::Rect, Frame};
+
+pub struct LogRevisionsPopup {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for LogRevisionsPopup {
+	fn draw(&self, f: &mut Frame, rect: Rect) -> Result<()> {
+		if self.is_visible() {
+			self.input.draw(f, rect)?;
+		}
+		Ok(())
+	}
+}
+
+impl Component for LogRevisionsPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::log_revisions_confirm_msg(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys.enter) {
+					self.confirm();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl LogRevisionsPopup {
+	///
+	pub fn new(env: &Environment) -> Self {
+		Self {
+			repo: env.repo.clone(),
+			queue: env.queue.clone(),
+			input: TextInputComponent::new(
+				env,
+				&strings::log_revisions_popup_title(&env.key_config),
+				&strings::log_revisions_popup_msg(&env.key_config),
+				true,
+			)
+			.with_input_type(InputType::Singleline),
+			key_config: env.key_config.clone(),
+		}
+	}
+
+	///
+	pub fn open(&mut self, revisions: Option<String>) -> Result<()> {
+		self.input.set_text(revisions.unwrap_or_default());
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn confirm(&mut self) {
+		let revisions = self.input.get_text().trim().to_string();
+
+		if revisions.is_empty() {
+			self.queue.push(InternalEvent::SetLogRevisions(None));
+			self.hide();
+			return;
+		}
+
+		let tips = sync::log_tips(&self.repo.borrow(), &revisions);
+
+		match tips {
+			Ok(_) => {
+				self.queue.push(InternalEvent::SetLogRevisions(
+					Some(revisions),
+				));
+				self.hide();
+			}
+			Err(e) => {
+				log::error!("log revisions: {e}");
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					format!("invalid revisions:\n{e}"),
+				));
+			}
+		}
+	}
+}
@@ -1,6 +1,7 @@
 use crate::components::{
-	visibility_blocking, CommandBlocking, CommandInfo, Component,
-	DrawableComponent, EventState, InputType, TextInputComponent,
+	parse_date_range, visibility_blocking, CommandBlocking,
+	CommandInfo, Component, DrawableComponent, EventState, InputType,
+	TextInputComponent,
 };
 use crate::{
 	app::Environment,
@@ -11,9 +12,10 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::sync::{
-	CommitId, LogFilterSearchOptions, RepoPathRef, SearchFields,
-	SearchOptions,
+	CommitId, DateRange, LogFilterSearchOptions, RepoPathRef,
+	SearchFields, SearchOptions,
 };
+use chrono::Local;
 use crossterm::event::Event;
 use easy_cast::Cast;
 use ratatui::{
@@ -28,11 +30,16 @@ use ratatui::{
 enum Selection {
 	EnterText,
 	FuzzyOption,
+	RegexOption,
 	CaseOption,
+	PickaxeOption,
 	SummarySearch,
 	MessageBodySearch,
 	FilenameSearch,
 	AuthorsSearch,
+	CommitHashSearch,
+	DiffSearch,
+	EnterDates,
 }
 
 enum PopupMode {
@@ -48,6 +55,7 @@ pub struct LogSearchPopupPopup {
 	selection: Selection,
 	key_config: SharedKeyConfig,
 	find_text: TextInputComponent,
+	date_text: TextInputComponent,
 	options: (SearchFields, SearchOptions),
 	theme: SharedTheme,
 	jump_commit_id: Option<CommitId>,
@@ -62,6 +70,16 @@ impl LogSearchPopupPopup {
 		find_text.embed();
 		find_text.enabled(true);
 
+		let mut date_text = TextInputComponent::new(
+			env,
+			"",
+			"date range, e.g. tuesday..thursday",
+			false,
+		)
+		.with_input_type(InputType::Singleline);
+		date_text.embed();
+		date_text.enabled(false);
+
 		Self {
 			repo: env.repo.clone(),
 			queue: env.queue.clone(),
@@ -74,6 +92,7 @@ impl LogSearchPopupPopup {
 			),
 			theme: env.theme.clone(),
 			find_text,
+			date_text,
 			selection: Selection::EnterText,
 			jump_commit_id: None,
 		}
@@ -85,6 +104,9 @@ impl LogSearchPopupPopup {
 		self.find_text.show()?;
 		self.find_text.set_text(String::new());
 		self.find_text.enabled(true);
+		self.date_text.show()?;
+		self.date_text.set_text(String::new());
+		self.date_text.enabled(false);
 
 		self.set_mode(&PopupMode::Search);
 
@@ -123,14 +145,7 @@ impl LogSearchPopupPopup {
 		match self.mode {
 			PopupMode::Search => {
 				self.queue.push(InternalEvent::CommitSearch(
-					LogFilterSearchOptions {
-						fields: self.options.0,
-						options: self.options.1,
-						search_pattern: self
-							.find_text
-							.get_text()
-							.to_string(),
-					},
+					self.search_options(),
 				));
 			}
 			PopupMode::JumpCommitSha => {
@@ -143,15 +158,53 @@ impl LogSearchPopupPopup {
 		}
 	}
 
+	fn search_options(&self) -> LogFilterSearchOptions {
+		let date_range = self.date_range();
+
+		let mut fields = self.options.0;
+		fields.set(SearchFields::DATES, date_range.is_some());
+
+		LogFilterSearchOptions {
+			fields,
+			options: self.options.1,
+			search_pattern: self
+				.find_text
+				.get_text()
+				.trim()
+				.to_string(),
+			date_range: date_range.unwrap_or_default(),
+		}
+	}
+
+	/// last line of the regex error, the others repeat the pattern
+	fn regex_error(&self) -> Option<String> {
+		self.search_options().validate().err().map(|e| {
+			let msg = e.to_string();
+			msg.lines().last().unwrap_or_default().trim().to_string()
+		})
+	}
+
 	fn is_valid(&self) -> bool {
 		match self.mode {
 			PopupMode::Search => {
-				!self.find_text.get_text().trim().is_empty()
+				self.dates_valid()
+					&& self.regex_error().is_none()
+					&& (!self.find_text.get_text().trim().is_empty()
+						|| self.date_range().is_some())
 			}
 			PopupMode::JumpCommitSha => self.jump_commit_id.is_some(),
 		}
 	}
 
+	fn date_range(&self) -> Option<DateRange> {
+		parse_date_range(self.date_text.get_text(), Local::now())
+	}
+
+	fn dates_valid(&self) -> bool {
+		self.date_text.get_text().trim().is_empty()
+			|| self.date_range().is_some()
+	}
+
 	fn validate_commit_sha(&mut self) {
 		let path = self.repo.borrow();
 		if let Ok(commit_id) = CommitId::from_revision(
@@ -164,6 +217,7 @@ impl LogSearchPopupPopup {
 		}
 	}
 
+	#[allow(clippy::too_many_lines)]
 	fn get_text_options(&self) -> Vec<Line<'_>> {
 		let x_summary =
 			if self.options.0.contains(SearchFields::MESSAGE_SUMMARY)
@@ -194,6 +248,37 @@ impl LogSearchPopupPopup {
 				" "
 			};
 
+		let x_hashes =
+			if self.options.0.contains(SearchFields::COMMIT_HASHES) {
+				"X"
+			} else {
+				" "
+			};
+
+		let x_diffs = if self.options.0.contains(SearchFields::DIFFS)
+		{
+			"X"
+		} else {
+			" "
+		};
+
+		let x_opt_pickaxe = if self
+			.options
+			.1
+			.contains(SearchOptions::PICKAXE_COUNT)
+		{
+			"X"
+		} else {
+			" "
+		};
+
+		let x_opt_regex =
+			if self.options.1.contains(SearchOptions::REGEX) {
+				"X"
+			} else {
+				" "
+			};
+
 		let x_opt_fuzzy =
 			if self.options.1.contains(SearchOptions::FUZZY_SEARCH) {
 				"X"
@@ -217,6 +302,13 @@ impl LogSearchPopupPopup {
 					false,
 				),
 			)]),
+			Line::from(vec![Span::styled(
+				format!("[{x_opt_regex}] regex"),
+				self.theme.text(
+					matches!(self.selection, Selection::RegexOption),
+					false,
+				),
+			)]),
 			Line::from(vec![Span::styled(
 				format!("[{x_opt_casesensitive}] case sensitive"),
 				self.theme.text(
@@ -224,6 +316,18 @@ impl LogSearchPopupPopup {
 					false,
 				),
 			)]),
+			Line::from(vec![Span::styled(
+				format!(
+					"[{x_opt_pickaxe}] diff: occurrence count changed (-S)"
+				),
+				self.theme.text(
+					matches!(
+						self.selection,
+						Selection::PickaxeOption
+					),
+					false,
+				),
+			)]),
 			Line::from(vec![Span::styled(
 				format!("[{x_summary}] summary"),
 				self.theme.text(
@@ -264,22 +368,48 @@ impl LogSearchPopupPopup {
 					false,
 				),
 			)]),
+			Line::from(vec![Span::styled(
+				format!("[{x_hashes}] commit hashes"),
+				self.theme.text(
+					matches!(
+						self.selection,
+						Selection::CommitHashSearch
+					),
+					false,
+				),
+			)]),
+			Line::from(vec![Span::styled(
+				format!("[{x_diffs}] diff content (-G)"),
+				self.theme.text(
+					matches!(self.selection, Selection::DiffSearch),
+					false,
+				),
+			)]),
 		]
 	}
 
 	const fn option_selected(&self) -> bool {
-		!matches!(self.selection, Selection::EnterText)
+		!matches!(
+			self.selection,
+			Selection::EnterText | Selection::EnterDates
+		)
 	}
 
 	fn toggle_option(&mut self) {
 		match self.selection {
-			Selection::EnterText => (),
+			Selection::EnterText | Selection::EnterDates => (),
 			Selection::FuzzyOption => {
 				self.options.1.toggle(SearchOptions::FUZZY_SEARCH);
 			}
+			Selection::RegexOption => {
+				self.options.1.toggle(SearchOptions::REGEX);
+			}
 			Selection::CaseOption => {
 				self.options.1.toggle(SearchOptions::CASE_SENSITIVE);
 			}
+			Selection::PickaxeOption => {
+				self.options.1.toggle(SearchOptions::PICKAXE_COUNT);
+			}
 			Selection::SummarySearch => {
 				self.options.0.toggle(SearchFields::MESSAGE_SUMMARY);
 
@@ -306,6 +436,22 @@ impl LogSearchPopupPopup {
 			Selection::AuthorsSearch => {
 				self.options.0.toggle(SearchFields::AUTHORS);
 
+				if self.options.0.is_empty() {
+					self.options
+						.0
+						.set(SearchFields::COMMIT_HASHES, true);
+				}
+			}
+			Selection::CommitHashSearch => {
+				self.options.0.toggle(SearchFields::COMMIT_HASHES);
+
+				if self.options.0.is_empty() {
+					self.options.0.set(SearchFields::DIFFS, true);
+				}
+			}
+			Selection::DiffSearch => {
+				self.options.0.toggle(SearchFields::DIFFS);
+
 				if self.options.0.is_empty() {
 					self.options
 						.0
@@ -319,10 +465,12 @@ impl LogSearchPopupPopup {
 		if arg {
 			//up
 			self.selection = match self.selection {
-				Selection::EnterText => Selection::AuthorsSearch,
+				Selection::EnterText => Selection::EnterDates,
 				Selection::FuzzyOption => Selection::EnterText,
-				Selection::CaseOption => Selection::FuzzyOption,
-				Selection::SummarySearch => Selection::CaseOption,
+				Selection::RegexOption => Selection::FuzzyOption,
+				Selection::CaseOption => Selection::RegexOption,
+				Selection::PickaxeOption => Selection::CaseOption,
+				Selection::SummarySearch => Selection::PickaxeOption,
 				Selection::MessageBodySearch => {
 					Selection::SummarySearch
 				}
@@ -330,12 +478,19 @@ impl LogSearchPopupPopup {
 					Selection::MessageBodySearch
 				}
 				Selection::AuthorsSearch => Selection::FilenameSearch,
+				Selection::CommitHashSearch => {
+					Selection::AuthorsSearch
+				}
+				Selection::DiffSearch => Selection::CommitHashSearch,
+				Selection::EnterDates => Selection::DiffSearch,
 			};
 		} else {
 			self.selection = match self.selection {
 				Selection::EnterText => Selection::FuzzyOption,
-				Selection::FuzzyOption => Selection::CaseOption,
-				Selection::CaseOption => Selection::SummarySearch,
+				Selection::FuzzyOption => Selection::RegexOption,
+				Selection::RegexOption => Selection::CaseOption,
+				Selection::CaseOption => Selection::PickaxeOption,
+				Selection::PickaxeOption => Selection::SummarySearch,
 				Selection::SummarySearch => {
 					Selection::MessageBodySearch
 				}
@@ -343,12 +498,19 @@ impl LogSearchPopupPopup {
 					Selection::FilenameSearch
 				}
 				Selection::FilenameSearch => Selection::AuthorsSearch,
-				Selection::AuthorsSearch => Selection::EnterText,
+				Selection::AuthorsSearch => {
+					Selection::CommitHashSearch
+				}
+				Selection::CommitHashSearch => Selection::DiffSearch,
+				Selection::DiffSearch => Selection::EnterDates,
+				Selection::EnterDates => Selection::EnterText,
 			};
 		}
 
 		self.find_text
 			.enabled(matches!(self.selection, Selection::EnterText));
+		self.date_text
+			.enabled(matches!(self.selection, Selection::EnterDates));
 	}
 
 	fn draw_search_mode(
@@ -356,7 +518,7 @@ impl LogSearchPopupPopup {
 		f: &mut Frame,
 		area: Rect,
 	) -> Result<()> {
-		const SIZE: (u16, u16) = (60, 10);
+		const SIZE: (u16, u16) = (60, 16);
 		let area = ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
 
 		f.render_widget(Clear, area);
@@ -374,8 +536,12 @@ impl LogSearchPopupPopup {
 		let chunks = Layout::default()
 			.direction(Direction::Vertical)
 			.constraints(
-				[Constraint::Length(1), Constraint::Percentage(100)]
-					.as_ref(),
+				[
+					Constraint::Length(1),
+					Constraint::Percentage(100),
+					Constraint::Length(2),
+				]
+				.as_ref(),
 			)
 			.split(area.inner(Margin {
 				horizontal: 1,
@@ -395,6 +561,31 @@ impl LogSearchPopupPopup {
 			chunks[1],
 		);
 
+		let date_border_style = if self.dates_valid() {
+			self.theme.block(true)
+		} else {
+			self.theme.text_danger()
+		};
+
+		f.render_widget(
+			Block::default()
+				.borders(Borders::TOP)
+				.border_style(date_border_style),
+			chunks[2],
+		);
+
+		self.date_text.draw(
+			f,
+			chunks[2].inner(Margin {
+				horizontal: 0,
+				vertical: 1,
+			}),
+		)?;
+
+		if let Some(error) = self.regex_error() {
+			self.draw_invalid_input(f, &error);
+		}
+
 		Ok(())
 	}
 
@@ -438,16 +629,15 @@ impl LogSearchPopupPopup {
 		self.find_text.draw(f, chunks[0])?;
 
 		if show_invalid {
-			self.draw_invalid_sha(f);
+			self.draw_invalid_input(f, POPUP_COMMIT_SHA_INVALID);
 		}
 
 		Ok(())
 	}
 
-	fn draw_invalid_sha(&self, f: &mut Frame) {
-		let msg_length: u16 = POPUP_COMMIT_SHA_INVALID.len().cast();
-		let w = Paragraph::new(POPUP_COMMIT_SHA_INVALID)
-			.style(self.theme.text_danger());
+	fn draw_invalid_input(&self, f: &mut Frame, msg: &str) {
+		let msg_length: u16 = msg.len().cast();
+		let w = Paragraph::new(msg).style(self.theme.text_danger());
 
 		let rect = {
 			let mut rect = self.find_text.get_area();
@@ -491,6 +681,9 @@ impl LogSearchPopupPopup {
 			) && self.option_selected()
 			{
 				self.toggle_option();
+			} else if matches!(self.selection, Selection::EnterDates)
+			{
+				self.date_text.event(event)?;
 			} else if !self.option_selected() {
 				self.find_text.event(event)?;
 			}
@@ -1,9 +1,11 @@
 mod blame_file;
 mod branchlist;
 mod checkout_option;
+mod clone;
 mod commit;
 mod compare_commits;
 mod confirm;
+mod conflict;
 mod create_branch;
 mod create_remote;
 mod externaleditor;
@@ -13,17 +15,22 @@ mod fuzzy_find;
 mod goto_line;
 mod help;
 mod inspect_commit;
+mod log_revisions;
 mod log_search;
 mod msg;
+mod note;
 mod options;
 mod pull;
 mod push;
 mod push_tags;
+mod rebase_interactive;
+mod reflog;
 mod remotelist;
 mod rename_branch;
 mod rename_remote;
 mod reset;
 mod revision_files;
+mod stage_pathspec;
 mod stashmsg;
 mod submodules;
 mod tag_commit;
@@ -33,9 +40,11 @@ mod update_remote_url;
 pub use blame_file::{BlameFileOpen, BlameFilePopup};
 pub use branchlist::BranchListPopup;
 pub use checkout_option::CheckoutOptionPopup;
+pub use clone::ClonePopup;
 pub use commit::CommitPopup;
 pub use compare_commits::CompareCommitsPopup;
 pub use confirm::ConfirmPopup;
+pub use conflict::ConflictPopup;
 pub use create_branch::CreateBranchPopup;
 pub use create_remote::CreateRemotePopup;
 pub use externaleditor::ExternalEditorPopup;
@@ -45,17 +54,22 @@ pub use fuzzy_find::FuzzyFindPopup;
 pub use goto_line::GotoLinePopup;
 pub use help::HelpPopup;
 pub use inspect_commit::{InspectCommitOpen, InspectCommitPopup};
+pub use log_revisions::LogRevisionsPopup;
 pub use log_search::LogSearchPopupPopup;
 pub use msg::MsgPopup;
+pub use note::NotePopup;
 pub use options::{AppOption, OptionsPopup};
 pub use pull::PullPopup;
 pub use push::PushPopup;
 pub use push_tags::PushTagsPopup;
+pub use rebase_interactive::RebaseInteractivePopup;
+pub use reflog::ReflogPopup;
 pub use remotelist::RemoteListPopup;
 pub use rename_branch::RenameBranchPopup;
 pub use rename_remote::RenameRemotePopup;
 pub use reset::ResetPopup;
 pub use revision_files::{FileTreeOpen, RevisionFilesPopup};
+pub use stage_pathspec::StagePathspecPopup;
 pub use stashmsg::StashMsgPopup;
 pub use submodules::SubmodulesListPopup;
 pub use tag_commit::TagCommitPopup;
@@ -0,0 +1,159 @@
+use crate::components::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, InputType, TextInputComponent,
+};
+use crate::{
+	app::Environment,
+	keys::{key_match, SharedKeyConfig},
+	options::SharedOptions,
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings, try_or_popup,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitId, RepoPathRef};
+use crossterm::event::Event;
+use ratatui::{layout::Rect, Frame};
+
+pub struct NotePopup {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	commit_id: Option<CommitId>,
+	queue: Queue,
+	options: SharedOptions,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for NotePopup {
+	fn draw(&self, f: &mut Frame, rect: Rect) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for NotePopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::note_confirm_msg(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys.commit) {
+					try_or_popup!(self, "note error:", self.save());
+					return Ok(EventState::Consumed);
+				}
+			}
+
+			self.input.event(ev)?;
+			return Ok(EventState::Consumed);
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl NotePopup {
+	///
+	pub fn new(env: &Environment) -> Self {
+		Self {
+			queue: env.queue.clone(),
+			input: TextInputComponent::new(
+				env,
+				&strings::note_popup_title(None),
+				&strings::note_popup_msg(),
+				true,
+			)
+			.with_input_type(InputType::Multiline),
+			commit_id: None,
+			options: env.options.clone(),
+			key_config: env.key_config.clone(),
+			repo: env.repo.clone(),
+		}
+	}
+
+	///
+	pub fn open(&mut self, id: CommitId) -> Result<()> {
+		let notes_ref = self.options.borrow().notes_ref();
+
+		let note = sync::get_note(
+			&self.repo.borrow(),
+			notes_ref.as_deref(),
+			id,
+		)?;
+
+		self.commit_id = Some(id);
+		self.input.set_title(strings::note_popup_title(
+			notes_ref.as_deref(),
+		));
+		self.input.set_text(note.unwrap_or_default());
+		self.show()?;
+
+		Ok(())
+	}
+
+	/// an empty note removes the existing one
+	fn save(&mut self) -> Result<()> {
+		if let Some(commit_id) = self.commit_id {
+			let notes_ref = self.options.borrow().notes_ref();
+			let message = self.input.get_text().trim_end();
+
+			if message.is_empty() {
+				if sync::get_note(
+					&self.repo.borrow(),
+					notes_ref.as_deref(),
+					commit_id,
+				)?
+				.is_some()
+				{
+					sync::remove_note(
+						&self.repo.borrow(),
+						notes_ref.as_deref(),
+						commit_id,
+					)?;
+				}
+			} else {
+				sync::set_note(
+					&self.repo.borrow(),
+					notes_ref.as_deref(),
+					commit_id,
+					message,
+				)?;
+			}
+
+			self.input.clear();
+			self.hide();
+
+			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+		}
+
+		Ok(())
+	}
+}
@@ -3,6 +3,7 @@ use crate::{
 	components::{
 		string_width_align, visibility_blocking, CommandBlocking,
 		CommandInfo, Component, DrawableComponent, EventState,
+		InputType, TextInputComponent,
 	},
 	keys::{key_match, SharedKeyConfig},
 	options::SharedOptions,
@@ -11,7 +12,11 @@ use crate::{
 	ui::{self, style::SharedTheme},
 };
 use anyhow::Result;
-use asyncgit::sync::ShowUntrackedFilesConfig;
+use asyncgit::sync::{
+	diff::{DiffAlgorithm, RenameDetection},
+	get_notes_refs, notes_ref_name, RepoPathRef,
+	ShowUntrackedFilesConfig,
+};
 use crossterm::event::Event;
 use ratatui::{
 	layout::{Alignment, Rect},
@@ -25,29 +30,47 @@ use ratatui::{
 pub enum AppOption {
 	StatusShowUntracked,
 	DiffIgnoreWhitespaces,
+	DiffIgnoreWhitespaceChange,
+	DiffIgnoreBlankLines,
 	DiffContextLines,
 	DiffInterhunkLines,
+	DiffAlgorithm,
+	DiffRenames,
+	DiffSimilarity,
+	DiffSyntax,
+	LogNotesRef,
+	LogSignatures,
 }
 
 pub struct OptionsPopup {
+	repo: RepoPathRef,
 	selection: AppOption,
 	queue: Queue,
 	visible: bool,
 	key_config: SharedKeyConfig,
 	options: SharedOptions,
 	theme: SharedTheme,
+	notes_ref_input: TextInputComponent,
 }
 
 impl OptionsPopup {
 	///
 	pub fn new(env: &Environment) -> Self {
 		Self {
+			repo: env.repo.clone(),
 			selection: AppOption::StatusShowUntracked,
 			queue: env.queue.clone(),
 			visible: false,
 			key_config: env.key_config.clone(),
 			options: env.options.clone(),
 			theme: env.theme.clone(),
+			notes_ref_input: TextInputComponent::new(
+				env,
+				&strings::notes_ref_popup_title(),
+				&strings::notes_ref_popup_msg(),
+				false,
+			)
+			.with_input_type(InputType::Singleline),
 		}
 	}
 
@@ -55,6 +78,7 @@ impl OptionsPopup {
 		let mut txt: Vec<Line> = Vec::with_capacity(10);
 
 		self.add_status(&mut txt, width);
+		self.add_log(&mut txt, width);
 
 		txt
 	}
@@ -85,6 +109,20 @@ impl OptionsPopup {
 			&diff.ignore_whitespace.to_string(),
 			self.is_select(AppOption::DiffIgnoreWhitespaces),
 		);
+		self.add_entry(
+			txt,
+			width,
+			"Ignore whitespace change",
+			&diff.ignore_whitespace_change.to_string(),
+			self.is_select(AppOption::DiffIgnoreWhitespaceChange),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Ignore blank lines",
+			&diff.ignore_blank_lines.to_string(),
+			self.is_select(AppOption::DiffIgnoreBlankLines),
+		);
 		self.add_entry(
 			txt,
 			width,
@@ -99,6 +137,67 @@ impl OptionsPopup {
 			&diff.interhunk_lines.to_string(),
 			self.is_select(AppOption::DiffInterhunkLines),
 		);
+		self.add_entry(
+			txt,
+			width,
+			"Algorithm",
+			match diff.algorithm {
+				DiffAlgorithm::Myers => "Myers",
+				DiffAlgorithm::Minimal => "Minimal",
+				DiffAlgorithm::Patience => "Patience",
+			},
+			self.is_select(AppOption::DiffAlgorithm),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Detect renames",
+			match diff.renames {
+				RenameDetection::Off => "Off",
+				RenameDetection::Renames => "Renames",
+				RenameDetection::Copies => "Renames+Copies",
+			},
+			self.is_select(AppOption::DiffRenames),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Similarity",
+			&format!("{}%", diff.similarity_threshold),
+			self.is_select(AppOption::DiffSimilarity),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Syntax highlighting",
+			&self.options.borrow().diff_syntax().to_string(),
+			self.is_select(AppOption::DiffSyntax),
+		);
+	}
+
+	fn add_log(&self, txt: &mut Vec<Line>, width: u16) {
+		Self::add_header(txt, "");
+		Self::add_header(txt, "Log");
+
+		let notes_ref = self.options.borrow().notes_ref();
+		self.add_entry(
+			txt,
+			width,
+			"Notes ref",
+			notes_ref.as_deref().map_or("Gitconfig", |notes_ref| {
+				notes_ref
+					.strip_prefix("refs/notes/")
+					.unwrap_or(notes_ref)
+			}),
+			self.is_select(AppOption::LogNotesRef),
+		);
+		self.add_entry(
+			txt,
+			width,
+			"Verify signatures",
+			&self.options.borrow().log_signatures().to_string(),
+			self.is_select(AppOption::LogSignatures),
+		);
 	}
 
 	fn is_select(&self, kind: AppOption) -> bool {
@@ -138,17 +237,31 @@ impl OptionsPopup {
 		if up {
 			self.selection = match self.selection {
 				AppOption::StatusShowUntracked => {
-					AppOption::DiffInterhunkLines
+					AppOption::LogSignatures
 				}
 				AppOption::DiffIgnoreWhitespaces => {
 					AppOption::StatusShowUntracked
 				}
-				AppOption::DiffContextLines => {
+				AppOption::DiffIgnoreWhitespaceChange => {
 					AppOption::DiffIgnoreWhitespaces
 				}
+				AppOption::DiffIgnoreBlankLines => {
+					AppOption::DiffIgnoreWhitespaceChange
+				}
+				AppOption::DiffContextLines => {
+					AppOption::DiffIgnoreBlankLines
+				}
 				AppOption::DiffInterhunkLines => {
 					AppOption::DiffContextLines
 				}
+				AppOption::DiffAlgorithm => {
+					AppOption::DiffInterhunkLines
+				}
+				AppOption::DiffRenames => AppOption::DiffAlgorithm,
+				AppOption::DiffSimilarity => AppOption::DiffRenames,
+				AppOption::DiffSyntax => AppOption::DiffSimilarity,
+				AppOption::LogNotesRef => AppOption::DiffSyntax,
+				AppOption::LogSignatures => AppOption::LogNotesRef,
 			};
 		} else {
 			self.selection = match self.selection {
@@ -156,18 +269,33 @@ impl OptionsPopup {
 					AppOption::DiffIgnoreWhitespaces
 				}
 				AppOption::DiffIgnoreWhitespaces => {
+					AppOption::DiffIgnoreWhitespaceChange
+				}
+				AppOption::DiffIgnoreWhitespaceChange => {
+					AppOption::DiffIgnoreBlankLines
+				}
+				AppOption::DiffIgnoreBlankLines => {
 					AppOption::DiffContextLines
 				}
 				AppOption::DiffContextLines => {
 					AppOption::DiffInterhunkLines
 				}
 				AppOption::DiffInterhunkLines => {
+					AppOption::DiffAlgorithm
+				}
+				AppOption::DiffAlgorithm => AppOption::DiffRenames,
+				AppOption::DiffRenames => AppOption::DiffSimilarity,
+				AppOption::DiffSimilarity => AppOption::DiffSyntax,
+				AppOption::DiffSyntax => AppOption::LogNotesRef,
+				AppOption::LogNotesRef => AppOption::LogSignatures,
+				AppOption::LogSignatures => {
 					AppOption::StatusShowUntracked
 				}
 			};
 		}
 	}
 
+	#[allow(clippy::too_many_lines)]
 	fn switch_option(&self, right: bool) {
 		if right {
 			match self.selection {
@@ -197,6 +325,16 @@ impl OptionsPopup {
 						.borrow_mut()
 						.diff_toggle_whitespace();
 				}
+				AppOption::DiffIgnoreWhitespaceChange => {
+					self.options
+						.borrow_mut()
+						.diff_toggle_whitespace_change();
+				}
+				AppOption::DiffIgnoreBlankLines => {
+					self.options
+						.borrow_mut()
+						.diff_toggle_blank_lines();
+				}
 				AppOption::DiffContextLines => {
 					self.options
 						.borrow_mut()
@@ -207,6 +345,26 @@ impl OptionsPopup {
 						.borrow_mut()
 						.diff_hunk_lines_change(true);
 				}
+				AppOption::DiffAlgorithm => {
+					self.switch_diff_algorithm(true);
+				}
+				AppOption::DiffRenames => {
+					self.switch_diff_renames(true);
+				}
+				AppOption::DiffSimilarity => {
+					self.options
+						.borrow_mut()
+						.diff_similarity_change(true);
+				}
+				AppOption::DiffSyntax => {
+					self.options.borrow_mut().diff_toggle_syntax();
+				}
+				AppOption::LogNotesRef => {
+					self.switch_notes_ref(true);
+				}
+				AppOption::LogSignatures => {
+					self.options.borrow_mut().log_toggle_signatures();
+				}
 			}
 		} else {
 			match self.selection {
@@ -236,6 +394,16 @@ impl OptionsPopup {
 						.borrow_mut()
 						.diff_toggle_whitespace();
 				}
+				AppOption::DiffIgnoreWhitespaceChange => {
+					self.options
+						.borrow_mut()
+						.diff_toggle_whitespace_change();
+				}
+				AppOption::DiffIgnoreBlankLines => {
+					self.options
+						.borrow_mut()
+						.diff_toggle_blank_lines();
+				}
 				AppOption::DiffContextLines => {
 					self.options
 						.borrow_mut()
@@ -246,18 +414,144 @@ impl OptionsPopup {
 						.borrow_mut()
 						.diff_hunk_lines_change(false);
 				}
+				AppOption::DiffAlgorithm => {
+					self.switch_diff_algorithm(false);
+				}
+				AppOption::DiffRenames => {
+					self.switch_diff_renames(false);
+				}
+				AppOption::DiffSimilarity => {
+					self.options
+						.borrow_mut()
+						.diff_similarity_change(false);
+				}
+				AppOption::DiffSyntax => {
+					self.options.borrow_mut().diff_toggle_syntax();
+				}
+				AppOption::LogNotesRef => {
+					self.switch_notes_ref(false);
+				}
+				AppOption::LogSignatures => {
+					self.options.borrow_mut().log_toggle_signatures();
+				}
 			}
 		}
 
 		self.queue
 			.push(InternalEvent::OptionSwitched(self.selection));
 	}
+
+	fn switch_diff_algorithm(&self, right: bool) {
+		const ALGORITHMS: [DiffAlgorithm; 3] = [
+			DiffAlgorithm::Myers,
+			DiffAlgorithm::Minimal,
+			DiffAlgorithm::Patience,
+		];
+
+		let current = self.options.borrow().diff_options().algorithm;
+		self.options.borrow_mut().set_diff_algorithm(cycle(
+			&ALGORITHMS,
+			current,
+			right,
+		));
+	}
+
+	fn switch_diff_renames(&self, right: bool) {
+		const RENAMES: [RenameDetection; 3] = [
+			RenameDetection::Off,
+			RenameDetection::Renames,
+			RenameDetection::Copies,
+		];
+
+		let current = self.options.borrow().diff_options().renames;
+		self.options
+			.borrow_mut()
+			.set_diff_renames(cycle(&RENAMES, current, right));
+	}
+
+	/// cycles through the gitconfig default and the existing refs
+	/// under `refs/notes/`, new ones are entered by `open_notes_ref`
+	fn switch_notes_ref(&self, right: bool) {
+		let mut choices = vec![None];
+		choices.extend(
+			get_notes_refs(&self.repo.borrow())
+				.unwrap_or_default()
+				.into_iter()
+				.map(Some),
+		);
+
+		let current = self.options.borrow().notes_ref();
+		if !choices.contains(&current) {
+			choices.push(current.clone());
+		}
+
+		let idx = choices
+			.iter()
+			.position(|choice| *choice == current)
+			.unwrap_or_default();
+		let idx = if right {
+			(idx + 1) % choices.len()
+		} else {
+			(idx + choices.len() - 1) % choices.len()
+		};
+
+		self.options
+			.borrow_mut()
+			.set_notes_ref(choices.swap_remove(idx));
+	}
+
+	fn open_notes_ref(&mut self) -> Result<()> {
+		self.notes_ref_input.set_text(
+			self.options.borrow().notes_ref().unwrap_or_default(),
+		);
+		self.notes_ref_input.show()
+	}
+
+	fn confirm_notes_ref(&mut self) {
+		let name = self.notes_ref_input.get_text().trim();
+
+		let notes_ref = if name.is_empty() {
+			None
+		} else if let Some(notes_ref) = notes_ref_name(name) {
+			Some(notes_ref)
+		} else {
+			self.queue.push(InternalEvent::ShowErrorMsg(format!(
+				"invalid notes ref: {name}"
+			)));
+			return;
+		};
+
+		self.notes_ref_input.hide();
+		self.options.borrow_mut().set_notes_ref(notes_ref);
+		self.queue.push(InternalEvent::OptionSwitched(
+			AppOption::LogNotesRef,
+		));
+	}
+}
+
+/// the entry of `choices` next to `current`, wrapping around
+fn cycle<T: Copy + PartialEq>(
+	choices: &[T],
+	current: T,
+	right: bool,
+) -> T {
+	let idx = choices
+		.iter()
+		.position(|choice| *choice == current)
+		.unwrap_or_default();
+	let idx = if right {
+		(idx + 1) % choices.len()
+	} else {
+		(idx + choices.len() - 1) % choices.len()
+	};
+
+	choices[idx]
 }
 
 impl DrawableComponent for OptionsPopup {
 	fn draw(&self, f: &mut Frame, area: Rect) -> Result<()> {
 		if self.is_visible() {
-			const SIZE: (u16, u16) = (50, 10);
+			const SIZE: (u16, u16) = (50, 19);
 			let area =
 				ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
 
@@ -278,6 +572,8 @@ impl DrawableComponent for OptionsPopup {
 					.alignment(Alignment::Left),
 				area,
 			);
+
+			self.notes_ref_input.draw(f, area)?;
 		}
 
 		Ok(())
@@ -290,6 +586,20 @@ impl Component for OptionsPopup {
 		out: &mut Vec<CommandInfo>,
 		force_all: bool,
 	) -> CommandBlocking {
+		if self.notes_ref_input.is_visible() {
+			self.notes_ref_input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::notes_ref_confirm_msg(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+
+			return CommandBlocking::Blocking;
+		}
+
 		if self.is_visible() || force_all {
 			out.push(
 				CommandInfo::new(
@@ -309,6 +619,11 @@ impl Component for OptionsPopup {
 				)
 				.order(1),
 			);
+			out.push(CommandInfo::new(
+				strings::commands::notes_ref_edit(&self.key_config),
+				self.is_select(AppOption::LogNotesRef),
+				true,
+			));
 		}
 
 		visibility_blocking(self)
@@ -318,6 +633,18 @@ impl Component for OptionsPopup {
 		&mut self,
 		event: &crossterm::event::Event,
 	) -> Result<EventState> {
+		if self.notes_ref_input.is_visible() {
+			if !self.notes_ref_input.event(event)?.is_consumed() {
+				if let Event::Key(key) = &event {
+					if key_match(key, self.key_config.keys.enter) {
+						self.confirm_notes_ref();
+					}
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
 		if self.is_visible() {
 			if let Event::Key(key) = &event {
 				if key_match(key, self.key_config.keys.exit_popup) {
@@ -340,6 +667,10 @@ impl Component for OptionsPopup {
 					self.key_config.keys.move_left,
 				) {
 					self.switch_option(false);
+				} else if key_match(key, self.key_config.keys.enter)
+					&& self.is_select(AppOption::LogNotesRef)
+				{
+					self.open_notes_ref()?;
 				}
 			}
 
@@ -0,0 +1,383 @@
+use crate::{
+	app::Environment,
+	components::{
+		visibility_blocking, CommandBlocking, CommandInfo, Component,
+		DrawableComponent, EventState, InputType, ScrollType,
+		TextInputComponent, VerticalScroll,
+	},
+	keys::{key_match, SharedKeyConfig},
+	queue::{InternalEvent, NeedsUpdate, Queue},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme, Size},
+};
+use anyhow::Result;
+use asyncgit::sync::{
+	self, CommitId, RebaseAction, RebaseState, RebaseTodoItem,
+	RepoPathRef,
+};
+use crossterm::event::Event;
+use ratatui::{
+	layout::{Margin, Rect},
+	text::{Line, Span, Text},
+	widgets::{Block, BorderType, Borders, Clear, Paragraph},
+	Frame,
+};
+use std::cell::Cell;
+
+/// lets the user edit the todo list of an interactive rebase
+pub struct RebaseInteractivePopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	todo: Vec<RebaseTodoItem>,
+	selection: usize,
+	scroll: VerticalScroll,
+	current_height: Cell<usize>,
+	visible: bool,
+	input: TextInputComponent,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for RebaseInteractivePopup {
+	fn draw(&self, f: &mut Frame, rect: Rect) -> Result<()> {
+		if self.is_visible() {
+			const PERCENT_SIZE: Size = Size::new(60, 60);
+			const MIN_SIZE: Size = Size::new(60, 20);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				rect,
+			);
+			let area = ui::rect_inside(MIN_SIZE, rect.into(), area);
+			let area = area.intersection(rect);
+
+			f.render_widget(Clear, area);
+
+			f.render_widget(
+				Block::default()
+					.title(strings::POPUP_TITLE_REBASE_INTERACTIVE)
+					.border_type(BorderType::Thick)
+					.borders(Borders::ALL),
+				area,
+			);
+
+			let area = area.inner(Margin {
+				vertical: 1,
+				horizontal: 1,
+			});
+
+			let height = usize::from(area.height);
+			self.current_height.set(height);
+			self.scroll.update(
+				self.selection,
+				self.todo.len(),
+				height,
+			);
+
+			f.render_widget(
+				Paragraph::new(self.get_text(height)),
+				area,
+			);
+
+			self.scroll.draw(f, area, &self.theme);
+
+			self.input.draw(f, rect)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RebaseInteractivePopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			if self.input.is_visible() {
+				self.input.commands(out, force_all);
+
+				out.push(CommandInfo::new(
+					strings::commands::rebase_interactive_reword_confirm(
+						&self.key_config,
+					),
+					true,
+					true,
+				));
+
+				return visibility_blocking(self);
+			}
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::rebase_interactive_move(
+					&self.key_config,
+				),
+				self.todo.len() > 1,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::rebase_interactive_actions(
+					&self.key_config,
+				),
+				!self.todo.is_empty(),
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::rebase_interactive_start(
+					&self.key_config,
+				),
+				!self.todo.is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: &Event) -> Result<EventState> {
+		if !self.visible {
+			return Ok(EventState::NotConsumed);
+		}
+
+		if self.input.is_visible() {
+			if let Event::Key(e) = ev {
+				if key_match(e, self.key_config.keys.commit) {
+					self.reword_selected();
+					return Ok(EventState::Consumed);
+				}
+			}
+
+			self.input.event(ev)?;
+			return Ok(EventState::Consumed);
+		}
+
+		if let Event::Key(e) = ev {
+			if key_match(e, self.key_config.keys.exit_popup) {
+				self.hide();
+			} else if key_match(e, self.key_config.keys.move_down) {
+				self.move_selection(ScrollType::Down);
+			} else if key_match(e, self.key_config.keys.move_up) {
+				self.move_selection(ScrollType::Up);
+			} else if key_match(e, self.key_config.keys.page_down) {
+				self.move_selection(ScrollType::PageDown);
+			} else if key_match(e, self.key_config.keys.page_up) {
+				self.move_selection(ScrollType::PageUp);
+			} else if key_match(e, self.key_config.keys.home) {
+				self.move_selection(ScrollType::Home);
+			} else if key_match(e, self.key_config.keys.end) {
+				self.move_selection(ScrollType::End);
+			} else if key_match(e, self.key_config.keys.shift_down) {
+				self.move_item(false);
+			} else if key_match(e, self.key_config.keys.shift_up) {
+				self.move_item(true);
+			} else if key_match(e, self.key_config.keys.rebase_pick) {
+				self.set_action(RebaseAction::Pick);
+			} else if key_match(e, self.key_config.keys.rebase_reword)
+			{
+				self.open_reword()?;
+			} else if key_match(e, self.key_config.keys.rebase_edit) {
+				self.set_action(RebaseAction::Edit);
+			} else if key_match(e, self.key_config.keys.rebase_squash)
+			{
+				self.set_action(RebaseAction::Squash);
+			} else if key_match(e, self.key_config.keys.rebase_fixup)
+			{
+				self.set_action(RebaseAction::Fixup);
+			} else if key_match(e, self.key_config.keys.rebase_drop) {
+				self.set_action(RebaseAction::Drop);
+			} else if key_match(e, self.key_config.keys.enter) {
+				try_or_popup!(
+					self,
+					"interactive rebase error:",
+					self.rebase()
+				);
+			} else if key_match(
+				e,
+				self.key_config.keys.cmd_bar_toggle,
+			) {
+				//do not consume if its the more key
+				return Ok(EventState::NotConsumed);
+			}
+		}
+
+		Ok(EventState::Consumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl RebaseInteractivePopup {
+	///
+	pub fn new(env: &Environment) -> Self {
+		Self {
+			repo: env.repo.clone(),
+			queue: env.queue.clone(),
+			todo: Vec::new(),
+			selection: 0,
+			scroll: VerticalScroll::new(),
+			current_height: Cell::new(0),
+			visible: false,
+			input: TextInputComponent::new(
+				env,
+				&strings::rebase_interactive_reword_title(),
+				&strings::rebase_interactive_reword_msg(),
+				true,
+			)
+			.with_input_type(InputType::Multiline),
+			theme: env.theme.clone(),
+			key_config: env.key_config.clone(),
+		}
+	}
+
+	/// opens the todo list of all commits from `id` up to `HEAD`
+	pub fn open(&mut self, id: CommitId) -> Result<()> {
+		self.todo =
+			sync::rebase_interactive_todo(&self.repo.borrow(), id)?;
+		self.selection = 0;
+		self.show()?;
+
+		Ok(())
+	}
+
+	fn rebase(&mut self) -> Result<()> {
+		let state = sync::rebase_interactive(
+			&self.repo.borrow(),
+			&self.todo,
+		)?;
+
+		self.hide();
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		match state {
+			RebaseState::Finished => {}
+			RebaseState::Conflicted => {
+				self.queue.push(InternalEvent::TabSwitchStatus);
+			}
+			RebaseState::Stopped => {
+				self.queue.push(InternalEvent::ShowInfoMsg(
+					strings::rebase_interactive_stopped_msg(
+						&self.key_config,
+					),
+				));
+				self.queue.push(InternalEvent::TabSwitchStatus);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn move_selection(&mut self, scroll: ScrollType) {
+		let max = self.todo.len().saturating_sub(1);
+		let page = self.current_height.get().saturating_sub(1).max(1);
+
+		self.selection = match scroll {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			ScrollType::PageUp => self.selection.saturating_sub(page),
+			ScrollType::PageDown => {
+				self.selection.saturating_add(page)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max,
+		}
+		.min(max);
+	}
+
+	fn move_item(&mut self, up: bool) {
+		let other = if up {
+			self.selection.checked_sub(1)
+		} else {
+			Some(self.selection + 1)
+				.filter(|idx| *idx < self.todo.len())
+		};
+
+		if let Some(other) = other {
+			self.todo.swap(self.selection, other);
+			self.selection = other;
+		}
+	}
+
+	fn set_action(&mut self, action: RebaseAction) {
+		if let Some(item) = self.todo.get_mut(self.selection) {
+			item.action = action;
+		}
+	}
+
+	fn open_reword(&mut self) -> Result<()> {
+		if let Some(item) = self.todo.get(self.selection) {
+			self.input.set_text(item.message.clone());
+			self.input.show()?;
+		}
+
+		Ok(())
+	}
+
+	fn reword_selected(&mut self) {
+		if let Some(item) = self.todo.get_mut(self.selection) {
+			item.action = RebaseAction::Reword;
+			item.message = self.input.get_text().to_string();
+		}
+
+		self.input.hide();
+	}
+
+	fn get_text(&self, height: usize) -> Text<'_> {
+		let lines = self
+			.todo
+			.iter()
+			.enumerate()
+			.skip(self.scroll.get_top())
+			.take(height)
+			.map(|(idx, item)| {
+				let selected = idx == self.selection;
+				let active = item.action != RebaseAction::Drop;
+
+				Line::from(vec![
+					Span::styled(
+						format!("{:<7}", item.action.as_str()),
+						self.theme.text(active, selected),
+					),
+					Span::styled(
+						format!("{} ", item.id.get_short_string()),
+						self.theme.commit_hash(selected),
+					),
+					Span::styled(
+						item.summary().to_string(),
+						self.theme.text(active, selected),
+					),
+				])
+			})
+			.collect::<Vec<_>>();
+
+		Text::from(lines)
+	}
+}
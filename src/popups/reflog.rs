@@ -0,0 +1,377 @@
+use crate::components::{
+	time_to_string, visibility_blocking, CommandBlocking,
+	CommandInfo, Component, DrawableComponent, EventState,
+};
+use crate::{
+	app::Environment,
+	components::ScrollType,
+	keys::{key_match, SharedKeyConfig},
+	popups::InspectCommitOpen,
+	queue::{InternalEvent, NeedsUpdate, Queue, StackablePopupOpen},
+	strings, try_or_popup,
+	ui::{self, Size},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitId, ReflogEntry, RepoPathRef};
+use crossterm::event::Event;
+use ratatui::{
+	layout::{Constraint, Margin, Rect},
+	text::Span,
+	widgets::{
+		Block, BorderType, Borders, Cell, Clear, Row, Table,
+		TableState,
+	},
+	Frame,
+};
+use ui::style::SharedTheme;
+
+/// lists the reflog of `HEAD` or a branch
+pub struct ReflogPopup {
+	repo: RepoPathRef,
+	theme: SharedTheme,
+	queue: Queue,
+	refname: String,
+	entries: Vec<ReflogEntry>,
+	visible: bool,
+	table_state: std::cell::Cell<TableState>,
+	current_height: std::cell::Cell<usize>,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for ReflogPopup {
+	fn draw(&self, f: &mut Frame, rect: Rect) -> Result<()> {
+		if self.visible {
+			const PERCENT_SIZE: Size = Size::new(80, 50);
+			const MIN_SIZE: Size = Size::new(60, 20);
+
+			let area = ui::centered_rect(
+				PERCENT_SIZE.width,
+				PERCENT_SIZE.height,
+				f.area(),
+			);
+			let area =
+				ui::rect_inside(MIN_SIZE, f.area().into(), area);
+			let area = area.intersection(rect);
+
+			let selector_width = self
+				.entries
+				.len()
+				.saturating_sub(1)
+				.to_string()
+				.len() + 3;
+
+			let constraints = [
+				// selector like `@{12}`
+				Constraint::Length(selector_width.try_into()?),
+				// commit id
+				Constraint::Length(7),
+				// date
+				Constraint::Length(10),
+				// message
+				Constraint::Percentage(100),
+			];
+
+			let rows = self.get_rows();
+			let number_of_rows = rows.len();
+
+			let table = Table::new(rows, constraints)
+				.column_spacing(1)
+				.row_highlight_style(self.theme.text(true, true))
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::title_reflog(&self.refname),
+							self.theme.title(true),
+						))
+						.border_style(self.theme.block(true))
+						.border_type(BorderType::Thick),
+				);
+
+			let mut table_state = self.table_state.take();
+
+			f.render_widget(Clear, area);
+			f.render_stateful_widget(table, area, &mut table_state);
+
+			let area = area.inner(Margin {
+				vertical: 1,
+				horizontal: 0,
+			});
+
+			ui::draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				number_of_rows,
+				table_state.selected().unwrap_or(0),
+				ui::Orientation::Vertical,
+			);
+
+			self.table_state.set(table_state);
+			self.current_height.set(area.height.into());
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for ReflogPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.visible || force_all {
+			if !force_all {
+				out.clear();
+			}
+
+			let selected = self.selected_commit().is_some();
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::commit_details_open(
+					&self.key_config,
+				),
+				selected,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::log_checkout_commit(
+					&self.key_config,
+				),
+				selected,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::open_branch_create_popup(
+					&self.key_config,
+				),
+				selected,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::log_reset_commit(&self.key_config),
+				selected,
+				true,
+			));
+		}
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.visible {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys.exit_popup) {
+					self.hide();
+				} else if key_match(key, self.key_config.keys.move_up)
+				{
+					self.move_selection(ScrollType::Up);
+				} else if key_match(
+					key,
+					self.key_config.keys.move_down,
+				) {
+					self.move_selection(ScrollType::Down);
+				} else if key_match(
+					key,
+					self.key_config.keys.shift_up,
+				) || key_match(
+					key,
+					self.key_config.keys.home,
+				) {
+					self.move_selection(ScrollType::Home);
+				} else if key_match(
+					key,
+					self.key_config.keys.shift_down,
+				) || key_match(
+					key,
+					self.key_config.keys.end,
+				) {
+					self.move_selection(ScrollType::End);
+				} else if key_match(
+					key,
+					self.key_config.keys.page_down,
+				) {
+					self.move_selection(ScrollType::PageDown);
+				} else if key_match(key, self.key_config.keys.page_up)
+				{
+					self.move_selection(ScrollType::PageUp);
+				} else if key_match(key, self.key_config.keys.enter)
+					|| key_match(key, self.key_config.keys.move_right)
+				{
+					self.inspect_commit();
+				} else if key_match(
+					key,
+					self.key_config.keys.log_checkout_commit,
+				) {
+					self.checkout_commit();
+				} else if key_match(
+					key,
+					self.key_config.keys.create_branch,
+				) {
+					if let Some(id) = self.selected_commit() {
+						self.queue
+							.push(InternalEvent::CreateBranchAt(id));
+					}
+				} else if key_match(
+					key,
+					self.key_config.keys.log_reset_commit,
+				) {
+					if let Some(id) = self.selected_commit() {
+						self.hide();
+						self.queue
+							.push(InternalEvent::OpenResetPopup(id));
+					}
+				}
+			}
+
+			Ok(EventState::Consumed)
+		} else {
+			Ok(EventState::NotConsumed)
+		}
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+impl ReflogPopup {
+	pub fn new(env: &Environment) -> Self {
+		Self {
+			theme: env.theme.clone(),
+			queue: env.queue.clone(),
+			refname: String::new(),
+			entries: Vec::new(),
+			visible: false,
+			table_state: std::cell::Cell::new(TableState::default()),
+			current_height: std::cell::Cell::new(0),
+			key_config: env.key_config.clone(),
+			repo: env.repo.clone(),
+		}
+	}
+
+	/// open the reflog of `refname` (`HEAD` or a full branch ref)
+	pub fn open(&mut self, refname: String) -> Result<()> {
+		self.entries =
+			sync::get_reflog(&self.repo.borrow(), &refname)?;
+		self.refname = refname;
+		self.table_state.get_mut().select(Some(0));
+		self.show()?;
+
+		Ok(())
+	}
+
+	///
+	fn move_selection(&self, scroll_type: ScrollType) -> bool {
+		let mut table_state = self.table_state.take();
+
+		let old_selection = table_state.selected().unwrap_or(0);
+		let max_selection = self.entries.len().saturating_sub(1);
+
+		let new_selection = match scroll_type {
+			ScrollType::Up => old_selection.saturating_sub(1),
+			ScrollType::Down => {
+				old_selection.saturating_add(1).min(max_selection)
+			}
+			ScrollType::Home => 0,
+			ScrollType::End => max_selection,
+			ScrollType::PageUp => old_selection.saturating_sub(
+				self.current_height.get().saturating_sub(1),
+			),
+			ScrollType::PageDown => old_selection
+				.saturating_add(
+					self.current_height.get().saturating_sub(1),
+				)
+				.min(max_selection),
+		};
+
+		let needs_update = new_selection != old_selection;
+
+		table_state.select(Some(new_selection));
+		self.table_state.set(table_state);
+
+		needs_update
+	}
+
+	fn inspect_commit(&mut self) {
+		if let Some(id) = self.selected_commit() {
+			self.hide();
+			self.queue.push(InternalEvent::OpenPopup(
+				StackablePopupOpen::InspectCommit(
+					InspectCommitOpen::new(id),
+				),
+			));
+		}
+	}
+
+	fn checkout_commit(&mut self) {
+		if let Some(id) = self.selected_commit() {
+			try_or_popup!(
+				self,
+				"checkout error:",
+				sync::checkout_commit(&self.repo.borrow(), id)
+			);
+
+			self.hide();
+			self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+		}
+	}
+
+	///
+	fn get_rows(&self) -> Vec<Row<'_>> {
+		self.entries
+			.iter()
+			.enumerate()
+			.map(|(idx, entry)| {
+				Row::new(vec![
+					Cell::from(format!("@{{{idx}}}"))
+						.style(self.theme.commit_author(false)),
+					Cell::from(entry.id.get_short_string())
+						.style(self.theme.commit_hash(false)),
+					Cell::from(time_to_string(entry.time, true))
+						.style(self.theme.commit_time(false)),
+					Cell::from(entry.message.clone())
+						.style(self.theme.text(true, false)),
+				])
+			})
+			.collect()
+	}
+
+	/// commit of the selected entry, `None` for entries that do not
+	/// point to a commit (e.g. a deleted ref)
+	fn selected_commit(&self) -> Option<CommitId> {
+		let table_state = self.table_state.take();
+
+		let entry = table_state
+			.selected()
+			.and_then(|selected| self.entries.get(selected));
+
+		self.table_state.set(table_state);
+
+		entry
+			.map(|entry| entry.id)
+			.filter(|id| *id != CommitId::default())
+	}
+}
@@ -0,0 +1,347 @@
+use crate::components::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, InputType, StatusTreeComponent,
+	TextInputComponent,
+};
+use crate::{
+	app::Environment,
+	keys::{key_match, SharedKeyConfig},
+	options::SharedOptions,
+	queue::{Action, InternalEvent, NeedsUpdate, Queue, ResetItem},
+	strings, try_or_popup,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::{
+	sync::{
+		self,
+		status::{get_status, StatusType},
+		RepoPathRef,
+	},
+	StatusItem, StatusItemType,
+};
+use crossterm::event::Event;
+use ratatui::{
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Line, Span},
+	widgets::{Block, Borders, Clear},
+	Frame,
+};
+use std::path::Path;
+
+/// stages, unstages or resets all changed files matching a pathspec
+pub struct StagePathspecPopup {
+	repo: RepoPathRef,
+	queue: Queue,
+	theme: SharedTheme,
+	key_config: SharedKeyConfig,
+	options: SharedOptions,
+	visible: bool,
+	input: TextInputComponent,
+	pathspec: String,
+	unstaged: Vec<StatusItem>,
+	staged: Vec<StatusItem>,
+	unstaged_matches: Vec<StatusItem>,
+	staged_matches: Vec<StatusItem>,
+	error: Option<String>,
+}
+
+impl StagePathspecPopup {
+	///
+	pub fn new(env: &Environment) -> Self {
+		let mut input = TextInputComponent::new(
+			env,
+			"",
+			"pathspec, e.g. :(glob)src/**/*.rs :!*.lock 'my file'",
+			false,
+		)
+		.with_input_type(InputType::Singleline);
+		input.embed();
+
+		Self {
+			repo: env.repo.clone(),
+			queue: env.queue.clone(),
+			theme: env.theme.clone(),
+			key_config: env.key_config.clone(),
+			options: env.options.clone(),
+			visible: false,
+			input,
+			pathspec: String::new(),
+			unstaged: Vec::new(),
+			staged: Vec::new(),
+			unstaged_matches: Vec::new(),
+			staged_matches: Vec::new(),
+			error: None,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		let show_untracked =
+			self.options.borrow().status_show_untracked();
+
+		self.unstaged = get_status(
+			&self.repo.borrow(),
+			StatusType::WorkingDir,
+			show_untracked,
+		)?;
+		self.staged = get_status(
+			&self.repo.borrow(),
+			StatusType::Stage,
+			show_untracked,
+		)?;
+
+		self.input.set_text(String::new());
+		self.pathspec.clear();
+		self.update_matches();
+
+		self.show()?;
+		self.input.show()?;
+
+		Ok(())
+	}
+
+	fn update_matches(&mut self) {
+		let filter = |items: &[StatusItem]| {
+			sync::filter_by_pathspec(&self.pathspec, items.to_vec())
+		};
+
+		match filter(&self.unstaged).and_then(|unstaged| {
+			Ok((unstaged, filter(&self.staged)?))
+		}) {
+			Ok((unstaged, staged)) => {
+				self.unstaged_matches = unstaged;
+				self.staged_matches = staged;
+				self.error = None;
+			}
+			Err(e) => {
+				self.unstaged_matches.clear();
+				self.staged_matches.clear();
+				self.error = Some(e.to_string());
+			}
+		}
+	}
+
+	fn stage(&mut self) -> Result<()> {
+		for item in &self.unstaged_matches {
+			let path = Path::new(&item.path);
+
+			if item.status == StatusItemType::Deleted {
+				sync::stage_addremoved(&self.repo.borrow(), path)?;
+			} else {
+				sync::stage_add_file(&self.repo.borrow(), path)?;
+			}
+		}
+
+		self.hide();
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		Ok(())
+	}
+
+	fn unstage(&mut self) -> Result<()> {
+		for item in &self.staged_matches {
+			sync::reset_stage(&self.repo.borrow(), &item.path)?;
+		}
+
+		self.hide();
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		Ok(())
+	}
+
+	fn reset(&mut self) {
+		if self.unstaged_matches.is_empty() {
+			return;
+		}
+
+		self.hide();
+		self.queue.push(InternalEvent::ConfirmAction(
+			Action::ResetItems(
+				self.unstaged_matches
+					.iter()
+					.map(|item| ResetItem {
+						path: item.path.clone(),
+					})
+					.collect(),
+			),
+		));
+	}
+
+	fn get_lines(&self) -> Vec<Line<'_>> {
+		let section = |title: String, items: &[StatusItem]| {
+			std::iter::once(Line::from(Span::styled(
+				title,
+				self.theme.title(true),
+			)))
+			.chain(items.iter().map(|item| {
+				Line::from(Span::styled(
+					format!(
+						"{} {}",
+						StatusTreeComponent::item_status_char(
+							item.status
+						),
+						item.path
+					),
+					self.theme.item(item.status, false),
+				))
+			}))
+			.collect::<Vec<_>>()
+		};
+
+		if let Some(error) = &self.error {
+			return vec![Line::from(Span::styled(
+				error.as_str(),
+				self.theme.text_danger(),
+			))];
+		}
+
+		let mut lines = section(
+			strings::pathspec_unstaged(self.unstaged_matches.len()),
+			&self.unstaged_matches,
+		);
+		lines.extend(section(
+			strings::pathspec_staged(self.staged_matches.len()),
+			&self.staged_matches,
+		));
+
+		lines
+	}
+}
+
+impl DrawableComponent for StagePathspecPopup {
+	fn draw(&self, f: &mut Frame, area: Rect) -> Result<()> {
+		if self.is_visible() {
+			const MAX_SIZE: (u16, u16) = (70, 25);
+
+			let area = ui::centered_rect_absolute(
+				MAX_SIZE.0, MAX_SIZE.1, area,
+			);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.style(self.theme.title(true))
+					.title(Span::styled(
+						strings::POPUP_TITLE_STAGE_PATHSPEC,
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Length(1),
+						Constraint::Percentage(100),
+					]
+					.as_ref(),
+				)
+				.split(area.inner(Margin {
+					horizontal: 1,
+					vertical: 1,
+				}));
+
+			self.input.draw(f, chunks[0])?;
+
+			ui::draw_list_block(
+				f,
+				chunks[1],
+				Block::default().borders(Borders::TOP),
+				self.get_lines().into_iter(),
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for StagePathspecPopup {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(CommandInfo::new(
+				strings::commands::close_popup(&self.key_config),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::pathspec_stage(&self.key_config),
+				!self.unstaged_matches.is_empty(),
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::pathspec_unstage(&self.key_config),
+				!self.staged_matches.is_empty(),
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::pathspec_reset(&self.key_config),
+				!self.unstaged_matches.is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: &Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = event {
+				if key_match(key, self.key_config.keys.exit_popup) {
+					self.hide();
+					return Ok(EventState::Consumed);
+				} else if key_match(key, self.key_config.keys.enter) {
+					try_or_popup!(self, "stage error:", self.stage());
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					key,
+					self.key_config.keys.pathspec_unstage,
+				) {
+					try_or_popup!(
+						self,
+						"unstage error:",
+						self.unstage()
+					);
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					key,
+					self.key_config.keys.pathspec_reset,
+				) {
+					self.reset();
+					return Ok(EventState::Consumed);
+				}
+			}
+
+			if self.input.event(event)?.is_consumed()
+				&& self.input.get_text() != self.pathspec
+			{
+				self.pathspec = self.input.get_text().to_string();
+				self.update_matches();
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
@@ -43,6 +43,7 @@ pub struct ResetItem {
 ///
 pub enum Action {
 	Reset(ResetItem),
+	ResetItems(Vec<ResetItem>),
 	ResetHunk(String, u64),
 	ResetLines(String, Vec<DiffLinePosition>),
 	StashDrop(Vec<CommitId>),
@@ -57,7 +58,10 @@ pub enum Action {
 	AbortMerge,
 	AbortRebase,
 	AbortRevert,
+	AbortCherryPick,
+	AbortBisect,
 	UndoCommit,
+	RemoveNote(CommitId),
 }
 
 #[derive(Debug)]
@@ -109,10 +113,20 @@ pub enum InternalEvent {
 	///
 	TagCommit(CommitId),
 	///
+	EditNote(CommitId),
+	///
 	Tags,
 	///
+	ViewReflog(String),
+	///
+	OpenConflict(String),
+	///
+	StagePathspec,
+	///
 	CreateBranch,
 	///
+	CreateBranchAt(CommitId),
+	///
 	RenameRemote(String),
 	///
 	UpdateRemoteUrl(String, String),
@@ -122,6 +136,8 @@ pub enum InternalEvent {
 	SelectBranch,
 	///
 	OpenExternalEditor(Option<String>),
+	/// path and hash of the hunk to edit
+	OpenHunkEditor(String, u64),
 	///
 	Push(String, PushType, bool, bool),
 	///
@@ -135,6 +151,10 @@ pub enum InternalEvent {
 	///
 	OpenLogSearchPopup,
 	///
+	OpenLogRevisionsPopup(Option<String>),
+	///
+	SetLogRevisions(Option<String>),
+	///
 	FuzzyFinderChanged(usize, String, FuzzyFinderTarget),
 	///
 	FetchRemotes,
@@ -157,6 +177,8 @@ pub enum InternalEvent {
 	///
 	RewordCommit(CommitId),
 	///
+	OpenRebaseInteractive(CommitId),
+	///
 	CommitSearch(LogFilterSearchOptions),
 	///
 	OpenGotoLinePopup(usize),
@@ -34,6 +34,10 @@ pub static POPUP_TITLE_REMOTES: &str = "Remotes";
 pub static POPUP_SUBTITLE_REMOTES: &str = "Details";
 pub static POPUP_TITLE_FUZZY_FIND: &str = "Fuzzy Finder";
 pub static POPUP_TITLE_LOG_SEARCH: &str = "Search";
+pub static POPUP_TITLE_STAGE_PATHSPEC: &str = "Stage Pathspec";
+pub static POPUP_TITLE_CLONE: &str = "Clone Repository";
+pub static POPUP_TITLE_REBASE_INTERACTIVE: &str =
+	"Interactive Rebase";
 
 pub static POPUP_FAIL_COPY: &str = "Failed to copy text";
 pub static POPUP_SUCCESS_COPY: &str = "Copied Text";
@@ -47,6 +51,17 @@ pub mod symbol {
 	pub const FOLDER_ICON_EXPANDED: &str = "\u{25be}"; //▾
 	pub const EMPTY_STR: &str = "";
 	pub const ELLIPSIS: char = '\u{2026}'; // …
+	pub const GRAPH_COMMIT: &str = "\u{25cf}"; //●
+	pub const GRAPH_MERGE_COMMIT: &str = "\u{25c9}"; //◉
+	pub const GRAPH_VERTICAL: &str = "\u{2502}"; //│
+	pub const GRAPH_HORIZONTAL: &str = "\u{2500}"; //─
+	pub const GRAPH_CROSSING: &str = "\u{253c}"; //┼
+	pub const GRAPH_UP_RIGHT: &str = "\u{2570}"; //╰
+	pub const GRAPH_UP_LEFT: &str = "\u{256f}"; //╯
+	pub const GRAPH_DOWN_RIGHT: &str = "\u{256d}"; //╭
+	pub const GRAPH_DOWN_LEFT: &str = "\u{256e}"; //╮
+	pub const GRAPH_VERTICAL_RIGHT: &str = "\u{251c}"; //├
+	pub const GRAPH_VERTICAL_LEFT: &str = "\u{2524}"; //┤
 }
 
 pub fn title_branches() -> String {
@@ -55,6 +70,28 @@ pub fn title_branches() -> String {
 pub fn title_tags() -> String {
 	"Tags".to_string()
 }
+pub fn title_reflog(refname: &str) -> String {
+	format!("Reflog: {refname}")
+}
+pub fn title_conflict(
+	path: &str,
+	resolved: usize,
+	total: usize,
+) -> String {
+	format!("Conflicts: {path} ({resolved}/{total} resolved)")
+}
+pub fn clone_recurse_submodules(recurse: bool) -> String {
+	format!(
+		"[{}] recurse submodules",
+		if recurse { symbol::CHECKMARK } else { " " }
+	)
+}
+pub fn pathspec_unstaged(matches: usize) -> String {
+	format!("Unstaged matches: {matches}")
+}
+pub fn pathspec_staged(matches: usize) -> String {
+	format!("Staged matches: {matches}")
+}
 pub fn title_status(_key_config: &SharedKeyConfig) -> String {
 	"Unstaged Changes".to_string()
 }
@@ -137,6 +174,14 @@ pub fn commit_editor_msg(_key_config: &SharedKeyConfig) -> String {
 # Lines starting with '#' will be ignored"
 		.to_string()
 }
+pub fn edit_hunk_msg(_key_config: &SharedKeyConfig) -> String {
+	r"# Edit the hunk above, it is staged when the editor closes
+# To remove '-' lines, make them ' ' lines (context)
+# To remove '+' lines, delete them
+# Lines starting with '#' will be ignored
+# Deleting everything aborts the edit"
+		.to_string()
+}
 pub fn stash_popup_title(_key_config: &SharedKeyConfig) -> String {
 	"Stash".to_string()
 }
@@ -192,6 +237,26 @@ pub fn confirm_msg_revertchanges() -> String {
 	"This will revert all uncommitted changes. Are you sure?"
 		.to_string()
 }
+pub fn confirm_title_abortcherrypick() -> String {
+	"Abort cherry-pick?".to_string()
+}
+pub fn confirm_msg_abortcherrypick() -> String {
+	"This will revert all uncommitted changes and drop already picked commits. Are you sure?"
+		.to_string()
+}
+pub fn confirm_title_remove_note() -> String {
+	"Remove note?".to_string()
+}
+pub fn confirm_msg_remove_note() -> String {
+	"Remove the note of this commit?".to_string()
+}
+pub fn confirm_title_abortbisect() -> String {
+	"Reset bisect?".to_string()
+}
+pub fn confirm_msg_abortbisect() -> String {
+	"This will end bisecting and checkout the commit you started from. Are you sure?"
+		.to_string()
+}
 pub fn confirm_title_abortrebase() -> String {
 	"Abort rebase?".to_string()
 }
@@ -202,6 +267,9 @@ pub fn confirm_msg_abortrebase() -> String {
 pub fn confirm_msg_reset() -> String {
 	"confirm file reset?".to_string()
 }
+pub fn confirm_msg_reset_items(items: usize) -> String {
+	format!("confirm reset of {items} marked files?")
+}
 pub fn confirm_msg_reset_lines(lines: usize) -> String {
 	format!(
 		"are you sure you want to discard {lines} selected lines?"
@@ -325,6 +393,21 @@ pub fn tag_popup_annotation_title(name: &str) -> String {
 pub fn tag_popup_annotation_msg() -> String {
 	"type tag annotation".to_string()
 }
+pub fn note_popup_title(notes_ref: Option<&str>) -> String {
+	notes_ref.map_or_else(
+		|| "Note".to_string(),
+		|notes_ref| format!("Note ({notes_ref})"),
+	)
+}
+pub fn note_popup_msg() -> String {
+	"type note, leave empty to remove it".to_string()
+}
+pub fn notes_ref_popup_title() -> String {
+	"Notes ref".to_string()
+}
+pub fn notes_ref_popup_msg() -> String {
+	"e.g. deploy, leave empty for the gitconfig default".to_string()
+}
 pub fn stashlist_title(_key_config: &SharedKeyConfig) -> String {
 	"Stashes".to_string()
 }
@@ -339,6 +422,9 @@ pub fn stashing_options_title(
 ) -> String {
 	"Options".to_string()
 }
+pub fn diff_files(files: usize) -> String {
+	format!("{files} files")
+}
 pub fn loading_text(_key_config: &SharedKeyConfig) -> String {
 	"Loading ...".to_string()
 }
@@ -408,6 +494,33 @@ pub fn password_popup_msg(_key_config: &SharedKeyConfig) -> String {
 	"type password".to_string()
 }
 
+pub fn rebase_interactive_reword_title() -> String {
+	"Reword Commit".to_string()
+}
+pub fn rebase_interactive_reword_msg() -> String {
+	"new commit message".to_string()
+}
+pub fn rebase_interactive_stopped_msg(
+	key_config: &SharedKeyConfig,
+) -> String {
+	format!(
+		"rebase stopped for editing.\nstage changes to amend the commit and continue with [{}].",
+		key_config.get_hint(key_config.keys.rebase_branch),
+	)
+}
+pub fn bisect_start_msg() -> String {
+	"mark the bad commit and the good commit in the log to start bisecting"
+		.to_string()
+}
+pub fn bisect_found_msg(id: &str, message: &str) -> String {
+	format!("first bad commit:\n{id} {message}")
+}
+pub fn bisect_only_skipped_msg(ids: &[String]) -> String {
+	format!(
+		"only skipped commits left to test.\nthe first bad commit is one of:\n{}",
+		ids.join("\n")
+	)
+}
 pub fn rename_branch_popup_title(
 	_key_config: &SharedKeyConfig,
 ) -> String {
@@ -419,6 +532,30 @@ pub fn rename_branch_popup_msg(
 	"new branch name".to_string()
 }
 
+pub fn log_revisions_popup_title(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"Log Revisions".to_string()
+}
+pub fn log_revisions_popup_msg(
+	_key_config: &SharedKeyConfig,
+) -> String {
+	"e.g. main..feature, main...feature, v1.0, --all (empty: HEAD)"
+		.to_string()
+}
+pub fn log_title_revisions(revisions: &str) -> String {
+	format!("Commit [{revisions}]")
+}
+pub fn log_title_modes(title: &str, modes: &[&str]) -> String {
+	if modes.is_empty() {
+		title.to_string()
+	} else {
+		format!("{title} ({})", modes.join(", "))
+	}
+}
+pub const LOG_MODE_FIRST_PARENT: &str = "first-parent";
+pub const LOG_MODE_HIDE_MERGES: &str = "no merges";
+
 pub fn copy_success(s: &str) -> String {
 	format!("{POPUP_SUCCESS_COPY} \"{s}\"")
 }
@@ -497,6 +634,27 @@ pub mod commit {
 	pub fn details_sha() -> String {
 		"Sha: ".to_string()
 	}
+	pub fn details_notes() -> String {
+		"Notes:".to_string()
+	}
+	pub fn details_signature() -> String {
+		"Signature: ".to_string()
+	}
+	pub fn details_signature_status(
+		status: &asyncgit::sync::SignatureStatus,
+	) -> String {
+		use asyncgit::sync::SignatureStatus;
+		match status {
+			SignatureStatus::Unsigned => "unsigned".to_string(),
+			SignatureStatus::Good(signer) => {
+				format!("good signature by {signer}")
+			}
+			SignatureStatus::Bad(msg) => format!("bad: {msg}"),
+			SignatureStatus::Unknown(msg) => {
+				format!("unknown: {msg}")
+			}
+		}
+	}
 	pub fn details_date() -> String {
 		"Date: ".to_string()
 	}
@@ -739,6 +897,81 @@ pub mod commands {
 			CMD_GROUP_DIFF,
 		)
 	}
+	pub fn diff_toggle_split(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Split view [{}]",
+				key_config
+					.get_hint(key_config.keys.diff_toggle_split),
+			),
+			"toggle between unified and side-by-side diff (line actions there always take both sides of a row)",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_toggle_all_files(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"All files [{}]",
+				key_config
+					.get_hint(key_config.keys.diff_toggle_all_files),
+			),
+			"toggle between one file and all files in one diff",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_file_next(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Next file [{}]",
+				key_config.get_hint(key_config.keys.diff_file_next),
+			),
+			"move cursor to next file",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_file_prev(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Prev file [{}]",
+				key_config.get_hint(key_config.keys.diff_file_prev),
+			),
+			"move cursor to previous file",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_fold_file(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Fold file [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"collapse or expand the diff of the selected file",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn diff_show_whitespace(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Whitespace [{}]",
+				key_config
+					.get_hint(key_config.keys.diff_show_whitespace),
+			),
+			"show tabs, trailing whitespace and line endings",
+			CMD_GROUP_DIFF,
+		)
+	}
 	pub fn diff_hunk_prev(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
@@ -841,6 +1074,18 @@ pub mod commands {
 			CMD_GROUP_DIFF,
 		)
 	}
+	pub fn diff_hunk_edit(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Edit hunk [{}]",
+				key_config.get_hint(key_config.keys.diff_edit_hunk),
+			),
+			"edit selected hunk in external editor and stage the result",
+			CMD_GROUP_DIFF,
+		)
+	}
 	pub fn close_fuzzy_finder(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
@@ -909,6 +1154,56 @@ pub mod commands {
 		)
 	}
 
+	pub fn continue_cherry_pick(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Continue cherry-pick [{}]",
+				key_config.get_hint(key_config.keys.rebase_branch),
+			),
+			"commit resolved changes and continue cherry-pick",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn skip_cherry_pick(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Skip commit [{}]",
+				key_config.get_hint(key_config.keys.skip_cherry_pick),
+			),
+			"drop current commit and continue cherry-pick",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn abort_bisect(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reset bisect [{}]",
+				key_config.get_hint(key_config.keys.abort_merge),
+			),
+			"end bisect and checkout the original HEAD",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
+	pub fn abort_cherry_pick(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Abort cherry-pick [{}]",
+				key_config.get_hint(key_config.keys.abort_merge),
+			),
+			"abort ongoing cherry-pick",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
 	pub fn abort_revert(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
@@ -1296,6 +1591,165 @@ pub mod commands {
 			CMD_GROUP_CHANGES,
 		)
 	}
+	pub fn intent_to_add_item(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Intent to add [{}]",
+				key_config
+					.get_hint(key_config.keys.status_intent_to_add),
+			),
+			"Track untracked file without content to stage its lines",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn mark_item(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Mark [{}]",
+				key_config.get_hint(key_config.keys.status_mark_item),
+			),
+			"mark file or path for batch stage, unstage, reset and ignore",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn mark_range(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Mark range [{}]",
+				key_config
+					.get_hint(key_config.keys.status_mark_range),
+			),
+			"mark all files between last marked item and selection",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn stage_pathspec(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Pathspec [{}]",
+				key_config
+					.get_hint(key_config.keys.status_stage_pathspec),
+			),
+			"stage, unstage or reset all files matching a pathspec",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn pathspec_stage(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Stage [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"stage unstaged matches",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn pathspec_unstage(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Unstage [{}]",
+				key_config.get_hint(key_config.keys.pathspec_unstage),
+			),
+			"unstage staged matches",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn pathspec_reset(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reset [{}]",
+				key_config.get_hint(key_config.keys.pathspec_reset),
+			),
+			"revert changes in unstaged matches",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn resolve_conflict_item(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Resolve conflicts [{}]",
+				key_config.get_hint(
+					key_config.keys.status_resolve_conflict
+				),
+			),
+			"pick ours, theirs or both for each conflict region",
+			CMD_GROUP_CHANGES,
+		)
+	}
+	pub fn conflict_take_ours(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Ours [{}]",
+				key_config
+					.get_hint(key_config.keys.conflict_take_ours),
+			),
+			"resolve selected conflict with our lines",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn conflict_take_theirs(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Theirs [{}]",
+				key_config
+					.get_hint(key_config.keys.conflict_take_theirs),
+			),
+			"resolve selected conflict with their lines",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn conflict_take_both(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Both [{}]",
+				key_config.get_hint(key_config.keys.conflict_take_both),
+			),
+			"resolve selected conflict with our lines followed by theirs",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn conflict_edit(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Edit [{}]",
+				key_config.get_hint(key_config.keys.edit_file),
+			),
+			"write picked resolutions and edit the file manually",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn conflict_mark_resolved(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Mark resolved [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"write the file and stage it as resolved",
+			CMD_GROUP_GENERAL,
+		)
+	}
 
 	pub fn diff_focus_left(
 		key_config: &SharedKeyConfig,
@@ -1583,6 +2037,231 @@ pub mod commands {
 			CMD_GROUP_LOG,
 		)
 	}
+	pub fn log_cherry_pick(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Cherry-pick [{}]",
+				key_config.get_hint(key_config.keys.log_cherry_pick),
+			),
+			"cherry-pick marked or selected commits onto HEAD",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_bisect_start(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Bisect [{}]",
+				key_config.get_hint(key_config.keys.log_bisect_bad),
+			),
+			"start bisect between the marked bad and good commits",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_bisect_mark(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Bad/Good/Skip [{}/{}/{}]",
+				key_config.get_hint(key_config.keys.log_bisect_bad),
+				key_config.get_hint(key_config.keys.log_bisect_good),
+				key_config.get_hint(key_config.keys.log_bisect_skip),
+			),
+			"mark the checked out commit and continue bisecting",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn view_reflog(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reflog [{}]",
+				key_config.get_hint(key_config.keys.view_reflog),
+			),
+			"show the reflog to restore earlier states",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_revisions(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Revisions [{}]",
+				key_config.get_hint(key_config.keys.log_revisions),
+			),
+			"choose the revisions or range shown in the log",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_first_parent(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"First parent [{}]",
+				key_config.get_hint(key_config.keys.log_first_parent),
+			),
+			"only follow the first parent of merge commits",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_hide_merges(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Hide merges [{}]",
+				key_config.get_hint(key_config.keys.log_hide_merges),
+			),
+			"leave merge commits out of the log",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_edit_note(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Note [{}]",
+				key_config.get_hint(key_config.keys.log_edit_note),
+			),
+			"add or edit the note of the selected commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_remove_note(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Remove note [{}]",
+				key_config.get_hint(key_config.keys.log_remove_note),
+			),
+			"remove the note of the selected commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn note_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Save [{}]",
+				key_config.get_hint(key_config.keys.commit),
+			),
+			"save note",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn notes_ref_edit(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Enter notes ref [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"enter the name of a new notes ref",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn notes_ref_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Set [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"show notes of this ref",
+			CMD_GROUP_GENERAL,
+		)
+		.hide_help()
+	}
+	pub fn log_revisions_confirm_msg(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Show [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"show these revisions in the log",
+			CMD_GROUP_LOG,
+		)
+		.hide_help()
+	}
+	pub fn log_rebase_interactive(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Rebase Interactive [{}]",
+				key_config
+					.get_hint(key_config.keys.log_rebase_interactive),
+			),
+			"interactively rebase commits from selected up to HEAD",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn rebase_interactive_move(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Move [{}{}]",
+				key_config.get_hint(key_config.keys.shift_up),
+				key_config.get_hint(key_config.keys.shift_down),
+			),
+			"move commit up or down in the todo list",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn rebase_interactive_actions(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Pick/Reword/Edit/Squash/Fixup/Drop [{}{}{}{}{}{}]",
+				key_config.get_hint(key_config.keys.rebase_pick),
+				key_config.get_hint(key_config.keys.rebase_reword),
+				key_config.get_hint(key_config.keys.rebase_edit),
+				key_config.get_hint(key_config.keys.rebase_squash),
+				key_config.get_hint(key_config.keys.rebase_fixup),
+				key_config.get_hint(key_config.keys.rebase_drop),
+			),
+			"change action of selected commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn rebase_interactive_start(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Rebase [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"start interactive rebase",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn rebase_interactive_reword_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Confirm [{}]",
+				key_config.get_hint(key_config.keys.commit),
+			),
+			"set new commit message",
+			CMD_GROUP_LOG,
+		)
+	}
 	pub fn log_find_commit(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
@@ -1930,6 +2609,55 @@ pub mod commands {
 		)
 	}
 
+	pub fn open_clone(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Clone [{}]",
+				key_config.get_hint(key_config.keys.open_clone),
+			),
+			"clone a repository and open it",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn clone_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Clone [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"start cloning",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn clone_next_field(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Next field [{}]",
+				key_config.get_hint(key_config.keys.tab_toggle),
+			),
+			"switch between url, directory, branch and depth",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn clone_recurse_submodules(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Submodules [{}]",
+				key_config.get_hint(
+					key_config.keys.clone_recurse_submodules
+				),
+			),
+			"toggle cloning submodules recursively",
+			CMD_GROUP_GENERAL,
+		)
+	}
+
 	pub fn goto_line(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
@@ -6,8 +6,11 @@ use crate::{
 		DrawableComponent, EventState,
 	},
 	keys::{key_match, SharedKeyConfig},
+	options::SharedOptions,
 	popups::{FileTreeOpen, InspectCommitOpen},
-	queue::{InternalEvent, Queue, StackablePopupOpen},
+	queue::{
+		Action, InternalEvent, NeedsUpdate, Queue, StackablePopupOpen,
+	},
 	strings::{self, order},
 	try_or_popup,
 	ui::style::{SharedTheme, Theme},
@@ -16,12 +19,13 @@ use anyhow::Result;
 use asyncgit::{
 	asyncjob::AsyncSingleJob,
 	sync::{
-		self, filter_commit_by_search, CommitId, LogFilterSearch,
+		self, filter_commit_by_search, BisectMark, BisectState,
+		CherryPickState, CommitId, LogFilterSearch,
 		LogFilterSearchOptions, RepoPathRef,
 	},
 	AsyncBranchesJob, AsyncCommitFilterJob, AsyncGitNotification,
-	AsyncLog, AsyncTags, CommitFilesParams, FetchStatus,
-	ProgressPercent,
+	AsyncLog, AsyncSignaturesJob, AsyncTags, CommitFilesParams,
+	FetchStatus, ProgressPercent,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
@@ -66,20 +70,25 @@ pub struct Revlog {
 	list: CommitList,
 	git_log: AsyncLog,
 	search: LogSearch,
+	/// commits that can still be the first bad one while bisecting
+	bisect: Option<Rc<IndexSet<CommitId>>>,
+	bisect_refs: Option<Vec<(String, CommitId)>>,
 	git_tags: AsyncTags,
 	git_local_branches: AsyncSingleJob<AsyncBranchesJob>,
 	git_remote_branches: AsyncSingleJob<AsyncBranchesJob>,
+	git_signatures: AsyncSingleJob<AsyncSignaturesJob>,
 	queue: Queue,
 	visible: bool,
 	key_config: SharedKeyConfig,
 	sender: Sender<AsyncGitNotification>,
 	theme: SharedTheme,
+	options: SharedOptions,
 }
 
 impl Revlog {
 	///
 	pub fn new(env: &Environment) -> Self {
-		Self {
+		let mut revlog = Self {
 			repo: env.repo.clone(),
 			queue: env.queue.clone(),
 			commit_details: CommitDetailsComponent::new(env),
@@ -93,6 +102,8 @@ impl Revlog {
 				None,
 			),
 			search: LogSearch::Off,
+			bisect: None,
+			bisect_refs: None,
 			git_tags: AsyncTags::new(
 				env.repo.borrow().clone(),
 				&env.sender_git,
@@ -103,11 +114,22 @@ impl Revlog {
 			git_remote_branches: AsyncSingleJob::new(
 				env.sender_git.clone(),
 			),
+			git_signatures: AsyncSingleJob::new(
+				env.sender_git.clone(),
+			),
 			visible: false,
 			key_config: env.key_config.clone(),
 			sender: env.sender_git.clone(),
 			theme: env.theme.clone(),
-		}
+			options: env.options.clone(),
+		};
+
+		revlog
+			.git_log
+			.set_options(env.options.borrow().log_options());
+		revlog.update_title();
+
+		revlog
 	}
 
 	///
@@ -117,9 +139,29 @@ impl Revlog {
 			|| self.git_tags.is_pending()
 			|| self.git_local_branches.is_pending()
 			|| self.git_remote_branches.is_pending()
+			|| self.git_signatures.is_pending()
 			|| self.commit_details.any_work_pending()
 	}
 
+	/// verifies the signatures of the visible commits in the
+	/// background, if enabled in the options
+	fn update_signatures(&mut self) {
+		let enabled = self.options.borrow().log_signatures();
+		self.list.set_show_signatures(enabled);
+
+		if !enabled || self.git_signatures.is_pending() {
+			return;
+		}
+
+		let commits = self.list.unverified_visible_commits();
+		if !commits.is_empty() {
+			self.git_signatures.spawn(AsyncSignaturesJob::new(
+				self.repo.borrow().clone(),
+				commits,
+			));
+		}
+	}
+
 	const fn is_search_pending(&self) -> bool {
 		matches!(self.search, LogSearch::Searching(_, _, _, _))
 	}
@@ -131,11 +173,16 @@ impl Revlog {
 				self.list.clear();
 			}
 
+			self.list.extend_graph(self.git_log.extract_graph()?);
 			self.list
 				.refresh_extend_data(self.git_log.extract_items()?);
 
 			self.git_tags.request(Duration::from_secs(3), false)?;
 
+			self.update_bisect()?;
+
+			self.update_signatures();
+
 			if self.commit_details.is_visible() {
 				let commit = self.selected_commit();
 				let tags = self.selected_commit_tags(commit.as_ref());
@@ -168,6 +215,17 @@ impl Revlog {
 						self.update()?;
 					}
 				}
+				AsyncGitNotification::Signatures => {
+					self.commit_details.update_git(ev);
+
+					if let Some(job) = self.git_signatures.take_last()
+					{
+						if let Some(signatures) = job.result() {
+							self.list.extend_signatures(signatures);
+							self.update()?;
+						}
+					}
+				}
 				AsyncGitNotification::Branches => {
 					if let Some(local_branches) =
 						self.git_local_branches.take_last()
@@ -229,6 +287,111 @@ impl Revlog {
 		Ok(())
 	}
 
+	fn cherry_pick(&mut self) -> Result<()> {
+		let mut commits = self.list.marked_commits();
+		if commits.is_empty() {
+			commits.extend(self.selected_commit());
+		}
+
+		// marked commits are in log order, pick the oldest first
+		commits.reverse();
+
+		let state = sync::cherry_pick(&self.repo.borrow(), &commits)?;
+
+		self.list.clear_marked();
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		if state == CherryPickState::Conflicted {
+			self.queue.push(InternalEvent::TabSwitchStatus);
+		}
+
+		Ok(())
+	}
+
+	/// the range is only walked again once the marked commits change
+	fn update_bisect(&mut self) -> Result<()> {
+		let repo = self.repo.borrow().clone();
+
+		let refs = sync::bisect_refs(&repo)?;
+		if refs == self.bisect_refs {
+			return Ok(());
+		}
+
+		self.bisect = refs.as_ref().and_then(|_| {
+			sync::bisect_range(&repo).ok().map(|range| {
+				Rc::new(range.into_iter().collect::<IndexSet<_>>())
+			})
+		});
+		self.bisect_refs = refs;
+
+		if matches!(self.search, LogSearch::Off) {
+			self.list.set_highlighting(self.bisect.clone());
+		}
+
+		Ok(())
+	}
+
+	const fn is_bisecting(&self) -> bool {
+		self.bisect.is_some()
+	}
+
+	fn bisect_start(&mut self) -> Result<()> {
+		let marked = self.list.marked_commits();
+
+		if marked.len() != 2 {
+			self.queue.push(InternalEvent::ShowInfoMsg(
+				strings::bisect_start_msg(),
+			));
+			return Ok(());
+		}
+
+		// marked commits are in log order, the newer one is bad
+		let state = sync::bisect_start(
+			&self.repo.borrow(),
+			marked[0],
+			marked[1],
+		)?;
+
+		self.list.clear_marked();
+		self.bisect_progress(state)
+	}
+
+	fn bisect_mark(&self, mark: BisectMark) -> Result<()> {
+		let state = sync::bisect_mark(&self.repo.borrow(), mark)?;
+
+		self.bisect_progress(state)
+	}
+
+	fn bisect_progress(&self, state: BisectState) -> Result<()> {
+		self.queue.push(InternalEvent::Update(NeedsUpdate::ALL));
+
+		match state {
+			BisectState::Testing { .. } => {}
+			BisectState::Found(id) => {
+				let info =
+					sync::get_commit_info(&self.repo.borrow(), &id)?;
+
+				self.queue.push(InternalEvent::ShowInfoMsg(
+					strings::bisect_found_msg(
+						&id.get_short_string(),
+						&info.message,
+					),
+				));
+			}
+			BisectState::OnlySkipped(ids) => {
+				self.queue.push(InternalEvent::ShowInfoMsg(
+					strings::bisect_only_skipped_msg(
+						&ids.iter()
+							.map(CommitId::get_short_string)
+							.collect::<Vec<_>>(),
+					),
+				));
+			}
+		}
+
+		Ok(())
+	}
+
 	fn inspect_commit(&self) {
 		if let Some(commit_id) = self.selected_commit() {
 			let tags =
@@ -277,6 +440,46 @@ impl Revlog {
 		}
 	}
 
+	/// show `revisions` instead of `HEAD`, see [`sync::log_tips`]
+	pub fn set_revisions(&mut self, revisions: Option<String>) {
+		self.git_log.set_revisions(revisions);
+		self.update_title();
+	}
+
+	fn toggle_log_option(&mut self, first_parent: bool) {
+		{
+			let mut options = self.options.borrow_mut();
+			if first_parent {
+				options.log_toggle_first_parent();
+			} else {
+				options.log_toggle_hide_merges();
+			}
+		}
+
+		self.git_log
+			.set_options(self.options.borrow().log_options());
+		self.update_title();
+	}
+
+	fn update_title(&mut self) {
+		let title = self.git_log.revisions().map_or_else(
+			|| strings::log_title(&self.key_config),
+			strings::log_title_revisions,
+		);
+
+		let options = self.git_log.options();
+		let modes = [
+			(options.first_parent, strings::LOG_MODE_FIRST_PARENT),
+			(options.hide_merges, strings::LOG_MODE_HIDE_MERGES),
+		]
+		.into_iter()
+		.filter_map(|(enabled, mode)| enabled.then_some(mode))
+		.collect::<Vec<_>>();
+
+		self.list
+			.set_title(&strings::log_title_modes(&title, &modes));
+	}
+
 	fn cancel_search(&mut self) -> bool {
 		if let LogSearch::Searching(_, _, _, cancellation_flag) =
 			&self.search
@@ -468,7 +671,8 @@ impl Component for Revlog {
 					if self.is_search_pending() {
 						self.cancel_search();
 					} else if self.can_close_search() {
-						self.list.set_highlighting(None);
+						self.list
+							.set_highlighting(self.bisect.clone());
 						self.search = LogSearch::Off;
 					}
 					return Ok(EventState::Consumed);
@@ -541,6 +745,64 @@ impl Component for Revlog {
 				} else if key_match(k, self.key_config.keys.tags) {
 					self.queue.push(InternalEvent::Tags);
 					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.view_reflog,
+				) {
+					self.queue.push(InternalEvent::ViewReflog(
+						String::from("HEAD"),
+					));
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_bisect_bad,
+				) && !self.is_search_pending()
+				{
+					if self.is_bisecting() {
+						try_or_popup!(
+							self,
+							"bisect error:",
+							self.bisect_mark(BisectMark::Bad)
+						);
+					} else {
+						try_or_popup!(
+							self,
+							"bisect error:",
+							self.bisect_start()
+						);
+					}
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_bisect_good,
+				) && self.is_bisecting()
+				{
+					try_or_popup!(
+						self,
+						"bisect error:",
+						self.bisect_mark(BisectMark::Good)
+					);
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_bisect_skip,
+				) && self.is_bisecting()
+				{
+					try_or_popup!(
+						self,
+						"bisect error:",
+						self.bisect_mark(BisectMark::Skip)
+					);
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.abort_merge,
+				) && self.is_bisecting()
+				{
+					self.queue.push(InternalEvent::ConfirmAction(
+						Action::AbortBisect,
+					));
+					return Ok(EventState::Consumed);
 				} else if key_match(
 					k,
 					self.key_config.keys.log_reset_commit,
@@ -569,12 +831,98 @@ impl Component for Revlog {
 							Ok(EventState::Consumed)
 						},
 					);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_cherry_pick,
+				) && !self.is_search_pending()
+				{
+					try_or_popup!(
+						self,
+						"cherry-pick error:",
+						self.cherry_pick()
+					);
+
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_rebase_interactive,
+				) && !self.is_search_pending()
+				{
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue.push(
+								InternalEvent::OpenRebaseInteractive(
+									id,
+								),
+							);
+							Ok(EventState::Consumed)
+						},
+					);
 				} else if key_match(k, self.key_config.keys.log_find)
 					&& self.can_start_search()
 				{
 					self.queue
 						.push(InternalEvent::OpenLogSearchPopup);
 					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_revisions,
+				) && !self.is_search_pending()
+				{
+					self.queue.push(
+						InternalEvent::OpenLogRevisionsPopup(
+							self.git_log
+								.revisions()
+								.map(ToString::to_string),
+						),
+					);
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_edit_note,
+				) && !self.is_search_pending()
+				{
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue
+								.push(InternalEvent::EditNote(id));
+							Ok(EventState::Consumed)
+						},
+					);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_remove_note,
+				) && !self.is_search_pending()
+				{
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue.push(
+								InternalEvent::ConfirmAction(
+									Action::RemoveNote(id),
+								),
+							);
+							Ok(EventState::Consumed)
+						},
+					);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_first_parent,
+				) && !self.is_search_pending()
+				{
+					self.toggle_log_option(true);
+					self.update()?;
+					return Ok(EventState::Consumed);
+				} else if key_match(
+					k,
+					self.key_config.keys.log_hide_merges,
+				) && !self.is_search_pending()
+				{
+					self.toggle_log_option(false);
+					self.update()?;
+					return Ok(EventState::Consumed);
 				} else if key_match(
 					k,
 					self.key_config.keys.compare_commits,
@@ -612,6 +960,7 @@ impl Component for Revlog {
 		Ok(EventState::NotConsumed)
 	}
 
+	#[allow(clippy::too_many_lines)]
 	fn commands(
 		&self,
 		out: &mut Vec<CommandInfo>,
@@ -696,6 +1045,12 @@ impl Component for Revlog {
 			self.visible || force_all,
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::view_reflog(&self.key_config),
+			true,
+			self.visible || force_all,
+		));
+
 		out.push(CommandInfo::new(
 			strings::commands::push_tags(&self.key_config),
 			true,
@@ -724,11 +1079,63 @@ impl Component for Revlog {
 			self.selected_commit().is_some(),
 			(self.visible && !self.is_search_pending()) || force_all,
 		));
+		out.push(CommandInfo::new(
+			strings::commands::log_cherry_pick(&self.key_config),
+			self.selected_commit().is_some(),
+			(self.visible && !self.is_search_pending()) || force_all,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::log_bisect_start(&self.key_config),
+			self.list.marked_count() == 2,
+			(self.visible && !self.is_bisecting()) || force_all,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::log_bisect_mark(&self.key_config),
+			true,
+			(self.visible && self.is_bisecting()) || force_all,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::abort_bisect(&self.key_config),
+			true,
+			(self.visible && self.is_bisecting()) || force_all,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::log_rebase_interactive(
+				&self.key_config,
+			),
+			self.selected_commit().is_some(),
+			(self.visible && !self.is_search_pending()) || force_all,
+		));
 		out.push(CommandInfo::new(
 			strings::commands::log_find_commit(&self.key_config),
 			self.can_start_search(),
 			self.visible || force_all,
 		));
+		out.push(CommandInfo::new(
+			strings::commands::log_revisions(&self.key_config),
+			!self.is_search_pending(),
+			self.visible || force_all,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::log_edit_note(&self.key_config),
+			self.selected_commit().is_some(),
+			(self.visible && !self.is_search_pending()) || force_all,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::log_remove_note(&self.key_config),
+			self.selected_commit().is_some(),
+			(self.visible && !self.is_search_pending()) || force_all,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::log_first_parent(&self.key_config),
+			!self.is_search_pending(),
+			self.visible || force_all,
+		));
+		out.push(CommandInfo::new(
+			strings::commands::log_hide_merges(&self.key_config),
+			!self.is_search_pending(),
+			self.visible || force_all,
+		));
 
 		visibility_blocking(self)
 	}
@@ -12,6 +12,7 @@ use crate::{
 	queue::{Action, InternalEvent, NeedsUpdate, Queue, ResetItem},
 	strings, try_or_popup,
 	ui::style::Theme,
+	AsyncNotification,
 };
 use anyhow::Result;
 use asyncgit::{
@@ -19,7 +20,7 @@ use asyncgit::{
 	sync::{
 		self, status::StatusType, RepoPath, RepoPathRef, RepoState,
 	},
-	sync::{BranchCompare, CommitId},
+	sync::{BisectState, BranchCompare, CommitId},
 	AsyncDiff, AsyncGitNotification, AsyncStatus, DiffParams,
 	DiffType, PushType, StatusItem, StatusParams,
 };
@@ -270,6 +271,18 @@ impl Status {
 						)
 					},
 				),
+			RepoState::CherryPick => {
+				format!(
+					"Cherry-pick {} Remaining: {}",
+					sync::cherry_pick_head(repo)
+						.ok()
+						.as_ref()
+						.map(CommitId::get_short_string)
+						.unwrap_or_default(),
+					sync::cherry_pick_remaining(repo)
+						.unwrap_or_default(),
+				)
+			}
 			RepoState::Revert => {
 				format!(
 					"Revert {}",
@@ -280,6 +293,30 @@ impl Status {
 						.unwrap_or_default(),
 				)
 			}
+			RepoState::Bisect => sync::bisect_state(repo)
+				.map_or_else(
+					|_| String::new(),
+					|state| match state {
+						BisectState::Testing {
+							commit,
+							remaining,
+							steps,
+						} => format!(
+							"Testing: {} Remaining: {remaining} (roughly {steps} steps)",
+							commit.get_short_string(),
+						),
+						BisectState::Found(id) => format!(
+							"First bad commit: {}",
+							id.get_short_string()
+						),
+						BisectState::OnlySkipped(ids) => format!(
+							"Only skipped commits left: {}",
+							ids.iter()
+								.map(CommitId::get_short_string)
+								.join(",")
+						),
+					},
+				),
 			_ => format!("{state:?}"),
 		}
 	}
@@ -410,6 +447,7 @@ impl Status {
 	///
 	pub fn anything_pending(&self) -> bool {
 		self.git_diff.is_pending()
+			|| self.diff.any_work_pending()
 			|| self.git_status_stage.is_pending()
 			|| self.git_status_workdir.is_pending()
 	}
@@ -427,6 +465,11 @@ impl Status {
 			.is_ok();
 	}
 
+	///
+	pub fn update_async(&mut self, ev: AsyncNotification) {
+		self.diff.update_async(ev);
+	}
+
 	///
 	pub fn update_git(
 		&mut self,
@@ -555,6 +598,15 @@ impl Status {
 		}
 	}
 
+	/// resets `items` until the first failure
+	pub fn reset_all(&self, items: &[ResetItem]) {
+		for item in items {
+			if !self.reset(item) {
+				break;
+			}
+		}
+	}
+
 	pub fn last_file_moved(&mut self) -> Result<()> {
 		if !self.is_focus_on_diff() && self.is_visible() {
 			self.switch_focus(self.focus.toggled_focus())?;
@@ -635,6 +687,14 @@ impl Status {
 		self.git_state == RepoState::Revert
 	}
 
+	fn pending_cherry_pick(&self) -> bool {
+		self.git_state == RepoState::CherryPick
+	}
+
+	fn pending_bisect(&self) -> bool {
+		self.git_state == RepoState::Bisect
+	}
+
 	pub fn revert_pending_state(&self) {
 		try_or_popup!(
 			self,
@@ -651,6 +711,38 @@ impl Status {
 		);
 	}
 
+	pub fn abort_cherry_pick(&self) {
+		try_or_popup!(
+			self,
+			"abort cherry-pick",
+			sync::abort_cherry_pick(&self.repo.borrow())
+		);
+	}
+
+	pub fn abort_bisect(&self) {
+		try_or_popup!(
+			self,
+			"reset bisect",
+			sync::bisect_reset(&self.repo.borrow())
+		);
+	}
+
+	fn continue_cherry_pick(&self) {
+		try_or_popup!(
+			self,
+			"continue cherry-pick",
+			sync::continue_cherry_pick(&self.repo.borrow())
+		);
+	}
+
+	fn skip_cherry_pick(&self) {
+		try_or_popup!(
+			self,
+			"skip cherry-pick",
+			sync::skip_cherry_pick(&self.repo.borrow())
+		);
+	}
+
 	fn continue_rebase(&self) {
 		try_or_popup!(
 			self,
@@ -713,6 +805,7 @@ impl Status {
 }
 
 impl Component for Status {
+	#[allow(clippy::too_many_lines)]
 	fn commands(
 		&self,
 		out: &mut Vec<CommandInfo>,
@@ -793,6 +886,34 @@ impl Component for Status {
 				self.pending_rebase() || force_all,
 			));
 
+			out.push(CommandInfo::new(
+				strings::commands::continue_cherry_pick(
+					&self.key_config,
+				),
+				true,
+				self.pending_cherry_pick() || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::skip_cherry_pick(&self.key_config),
+				true,
+				self.pending_cherry_pick() || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::abort_cherry_pick(
+					&self.key_config,
+				),
+				true,
+				self.pending_cherry_pick() || force_all,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::abort_bisect(&self.key_config),
+				true,
+				self.pending_bisect() || force_all,
+			));
+
 			out.push(CommandInfo::new(
 				strings::commands::abort_revert(&self.key_config),
 				true,
@@ -928,6 +1049,18 @@ impl Component for Status {
 								Action::AbortRevert,
 							),
 						);
+					} else if self.pending_cherry_pick() {
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::AbortCherryPick,
+							),
+						);
+					} else if self.pending_bisect() {
+						self.queue.push(
+							InternalEvent::ConfirmAction(
+								Action::AbortBisect,
+							),
+						);
 					}
 
 					Ok(EventState::Consumed)
@@ -941,6 +1074,26 @@ impl Component for Status {
 						NeedsUpdate::ALL,
 					));
 					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys.rebase_branch,
+				) && self.pending_cherry_pick()
+				{
+					self.continue_cherry_pick();
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL,
+					));
+					Ok(EventState::Consumed)
+				} else if key_match(
+					k,
+					self.key_config.keys.skip_cherry_pick,
+				) && self.pending_cherry_pick()
+				{
+					self.skip_cherry_pick();
+					self.queue.push(InternalEvent::Update(
+						NeedsUpdate::ALL,
+					));
+					Ok(EventState::Consumed)
 				} else if key_match(
 					k,
 					self.key_config.keys.view_submodules,
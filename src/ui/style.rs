@@ -1,6 +1,6 @@
 use crate::ui::syntax_text::DEFAULT_SYNTAX_THEME;
 use anyhow::Result;
-use asyncgit::{DiffLineType, StatusItemType};
+use asyncgit::{sync::SignatureStatus, DiffLineType, StatusItemType};
 use ratatui::style::{Color, Modifier, Style};
 use ron::ser::{to_string_pretty, PrettyConfig};
 use serde::{Deserialize, Serialize};
@@ -21,6 +21,10 @@ pub struct Theme {
 	disabled_fg: Color,
 	diff_line_add: Color,
 	diff_line_delete: Color,
+	diff_line_add_bg: Color,
+	diff_line_delete_bg: Color,
+	diff_whitespace: Color,
+	diff_eofnl: Color,
 	diff_file_added: Color,
 	diff_file_removed: Color,
 	diff_file_moved: Color,
@@ -33,6 +37,7 @@ pub struct Theme {
 	push_gauge_fg: Color,
 	tag_fg: Color,
 	branch_fg: Color,
+	commit_graph: Vec<Color>,
 	line_break: String,
 	block_title_focused: Color,
 	syntax: String,
@@ -189,6 +194,9 @@ impl Theme {
 			DiffLineType::Delete => {
 				Style::default().fg(self.diff_line_delete)
 			}
+			DiffLineType::AddEOFNL | DiffLineType::DeleteEOFNL => {
+				Style::default().fg(self.diff_eofnl)
+			}
 			DiffLineType::Header => Style::default()
 				.fg(self.disabled_fg)
 				.add_modifier(Modifier::BOLD),
@@ -202,6 +210,62 @@ impl Theme {
 		self.apply_select(style, selected)
 	}
 
+	/// syntax highlighted content of a diff line, added and deleted
+	/// lines are told apart by their background and keep the plain
+	/// `diff_line` style while it is not set
+	pub fn diff_line_syntax(
+		&self,
+		typ: DiffLineType,
+		syntax: Style,
+		selected: bool,
+	) -> Style {
+		let changed_bg = |bg: Color| {
+			if bg == Color::Reset {
+				self.diff_line(typ, false)
+			} else {
+				syntax.bg(bg)
+			}
+		};
+
+		let style = match typ {
+			DiffLineType::Add => changed_bg(self.diff_line_add_bg),
+			DiffLineType::Delete => {
+				changed_bg(self.diff_line_delete_bg)
+			}
+			DiffLineType::Header
+			| DiffLineType::None
+			| DiffLineType::AddEOFNL
+			| DiffLineType::DeleteEOFNL => syntax,
+		};
+
+		self.apply_select(style, selected)
+	}
+
+	/// header of a file in a diff of several files
+	pub fn diff_file_header(&self, selected: bool) -> Style {
+		self.apply_select(
+			Style::default()
+				.fg(self.diff_file_modified)
+				.add_modifier(Modifier::BOLD),
+			selected,
+		)
+	}
+
+	/// glyphs standing in for tabs, trailing whitespace and carriage
+	/// returns in a diff line
+	pub fn diff_whitespace(&self, selected: bool) -> Style {
+		self.apply_select(
+			Style::default().fg(self.diff_whitespace),
+			selected,
+		)
+	}
+
+	/// changed words inside an added or deleted line, on top of
+	/// `diff_line` or `diff_line_syntax`
+	pub fn diff_word_change(style: Style) -> Style {
+		style.add_modifier(Modifier::REVERSED | Modifier::BOLD)
+	}
+
 	pub fn text_danger(&self) -> Style {
 		Style::default().fg(self.danger_fg)
 	}
@@ -254,6 +318,42 @@ impl Theme {
 		)
 	}
 
+	/// style of a lane in the commit graph, lanes cycle through
+	/// the `commit_graph` colours
+	pub fn commit_graph(&self, lane: u8, selected: bool) -> Style {
+		let color = if self.commit_graph.is_empty() {
+			self.commit_hash
+		} else {
+			self.commit_graph
+				[usize::from(lane) % self.commit_graph.len()]
+		};
+
+		self.apply_select(Style::default().fg(color), selected)
+	}
+
+	pub fn commit_signature(
+		&self,
+		status: &SignatureStatus,
+		selected: bool,
+	) -> Style {
+		let style = match status {
+			SignatureStatus::Good(_) => {
+				Style::default().fg(self.diff_line_add)
+			}
+			SignatureStatus::Bad(_) => Style::default()
+				.fg(self.danger_fg)
+				.add_modifier(Modifier::BOLD),
+			SignatureStatus::Unknown(_) => {
+				Style::default().fg(self.diff_file_modified)
+			}
+			SignatureStatus::Unsigned => {
+				Style::default().fg(self.disabled_fg)
+			}
+		};
+
+		self.apply_select(style, selected)
+	}
+
 	pub fn commit_hash_in_blame(
 		&self,
 		is_blamed_commit: bool,
@@ -339,6 +439,10 @@ impl Default for Theme {
 			disabled_fg: Color::DarkGray,
 			diff_line_add: Color::Green,
 			diff_line_delete: Color::Red,
+			diff_line_add_bg: Color::Reset,
+			diff_line_delete_bg: Color::Reset,
+			diff_whitespace: Color::Cyan,
+			diff_eofnl: Color::LightMagenta,
 			diff_file_added: Color::LightGreen,
 			diff_file_removed: Color::LightRed,
 			diff_file_moved: Color::LightMagenta,
@@ -351,6 +455,14 @@ impl Default for Theme {
 			push_gauge_fg: Color::Reset,
 			tag_fg: Color::LightMagenta,
 			branch_fg: Color::LightYellow,
+			commit_graph: vec![
+				Color::LightBlue,
+				Color::LightGreen,
+				Color::LightMagenta,
+				Color::LightYellow,
+				Color::LightCyan,
+				Color::LightRed,
+			],
 			line_break: "¶".to_string(),
 			block_title_focused: Color::Reset,
 			// Available themes can be found in:
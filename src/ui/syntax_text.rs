@@ -1,11 +1,12 @@
 use asyncgit::{
 	asyncjob::{AsyncJob, RunParams},
-	ProgressPercent,
+	DiffLineType, ProgressPercent,
 };
 use once_cell::sync::{Lazy, OnceCell};
 use ratatui::text::{Line, Span};
 use scopetime::scope_time;
 use std::{
+	ffi::OsStr,
 	ops::Range,
 	path::{Path, PathBuf},
 	sync::{Arc, Mutex},
@@ -16,7 +17,7 @@ use syntect::{
 		FontStyle, HighlightState, Highlighter,
 		RangedHighlightIterator, Style, Theme, ThemeSet,
 	},
-	parsing::{ParseState, ScopeStack, SyntaxSet},
+	parsing::{ParseState, ScopeStack, SyntaxReference, SyntaxSet},
 };
 
 use crate::{AsyncAppNotification, SyntaxHighlightProgress};
@@ -77,35 +78,10 @@ impl SyntaxText {
 		scope_time!("syntax_highlighting");
 		let mut state = {
 			scope_time!("syntax_highlighting.0");
-			let plain_text = || SYNTAX_SET.find_syntax_plain_text();
-			let syntax = SYNTAX_SET
-				.find_syntax_for_file(file_path)
-				.unwrap_or_else(|e| {
-					log::error!("Could not read the file to detect its syntax: {e}");
-					Some(plain_text())
-				})
-				.unwrap_or_else(plain_text);
-
-			ParseState::new(syntax)
+			ParseState::new(syntax_for_file(file_path))
 		};
 
-		let theme = THEME.get_or_try_init(|| -> Result<Theme, asyncgit::Error> {
-			let theme_path = crate::args::get_app_config_path()
-				.map_err(|e| asyncgit::Error::Generic(e.to_string()))?.join(format!("{syntax}.tmTheme"));
-
-			match ThemeSet::get_theme(&theme_path) {
-				Ok(t) => return Ok(t),
-			    Err(e) => log::info!("could not load '{}': {e}, trying from the set of default themes", theme_path.display()),
-			}
-
-			let mut theme_set = ThemeSet::load_defaults();
-			if let Some(t) = theme_set.themes.remove(syntax) {
-			    return Ok(t);
-			}
-
-			log::error!("the syntax theme '{syntax}' cannot be found. Using default theme ('{DEFAULT_SYNTAX_THEME}') instead");
-			Ok(theme_set.themes.remove(DEFAULT_SYNTAX_THEME).expect("the default theme should be there"))
-		})?;
+		let theme = syntax_theme(syntax)?;
 
 		let highlighter = Highlighter::new(theme);
 		let mut syntax_lines: Vec<SyntaxLine> = Vec::new();
@@ -173,6 +149,167 @@ impl SyntaxText {
 	}
 }
 
+fn syntax_for_file(file_path: &Path) -> &'static SyntaxReference {
+	let plain_text = || SYNTAX_SET.find_syntax_plain_text();
+	SYNTAX_SET
+		.find_syntax_for_file(file_path)
+		.unwrap_or_else(|e| {
+			log::error!(
+				"Could not read the file to detect its syntax: {e}"
+			);
+			Some(plain_text())
+		})
+		.unwrap_or_else(plain_text)
+}
+
+/// like `syntax_for_file` without looking at the first line, diffed
+/// paths do not have to exist in the working directory
+fn syntax_for_path(path: &Path) -> &'static SyntaxReference {
+	let name = |name: Option<&OsStr>| {
+		name.and_then(OsStr::to_str).and_then(|name| {
+			SYNTAX_SET.find_syntax_by_extension(name)
+		})
+	};
+
+	name(path.file_name())
+		.or_else(|| name(path.extension()))
+		.unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text())
+}
+
+fn syntax_theme(syntax: &str) -> asyncgit::Result<&'static Theme> {
+	THEME.get_or_try_init(|| -> Result<Theme, asyncgit::Error> {
+		let theme_path = crate::args::get_app_config_path()
+			.map_err(|e| asyncgit::Error::Generic(e.to_string()))?.join(format!("{syntax}.tmTheme"));
+
+		match ThemeSet::get_theme(&theme_path) {
+			Ok(t) => return Ok(t),
+		    Err(e) => log::info!("could not load '{}': {e}, trying from the set of default themes", theme_path.display()),
+		}
+
+		let mut theme_set = ThemeSet::load_defaults();
+		if let Some(t) = theme_set.themes.remove(syntax) {
+		    return Ok(t);
+		}
+
+		log::error!("the syntax theme '{syntax}' cannot be found. Using default theme ('{DEFAULT_SYNTAX_THEME}') instead");
+		Ok(theme_set.themes.remove(DEFAULT_SYNTAX_THEME).expect("the default theme should be there"))
+	})
+}
+
+/// highlights consecutive lines of one side of a diff
+struct LineHighlighter<'a> {
+	syntax: &'static SyntaxReference,
+	highlighter: &'a Highlighter<'a>,
+	parse_state: ParseState,
+	highlight_state: HighlightState,
+}
+
+impl<'a> LineHighlighter<'a> {
+	fn new(
+		syntax: &'static SyntaxReference,
+		highlighter: &'a Highlighter<'a>,
+	) -> Self {
+		Self {
+			syntax,
+			highlighter,
+			parse_state: ParseState::new(syntax),
+			highlight_state: HighlightState::new(
+				highlighter,
+				ScopeStack::new(),
+			),
+		}
+	}
+
+	/// hunks are not contiguous, start over at each of them
+	fn reset(&mut self) {
+		*self = Self::new(self.syntax, self.highlighter);
+	}
+
+	fn highlight(
+		&mut self,
+		line: &str,
+	) -> asyncgit::Result<Vec<(ratatui::style::Style, Range<usize>)>>
+	{
+		let ops = self
+			.parse_state
+			.parse_line(line, &SYNTAX_SET)
+			.map_err(|e| {
+				log::error!("syntax error: {e:?}");
+				asyncgit::Error::Generic("syntax error".to_string())
+			})?;
+
+		Ok(RangedHighlightIterator::new(
+			&mut self.highlight_state,
+			&ops[..],
+			line,
+			self.highlighter,
+		)
+		.map(|(style, _, range)| {
+			(syntact_style_to_tui(&style), range)
+		})
+		.collect())
+	}
+}
+
+/// syntax highlighting of all lines of a diff, see
+/// `AsyncDiffSyntaxJob`
+pub struct DiffSyntax {
+	hash: u64,
+	lines: Vec<Vec<(ratatui::style::Style, Range<usize>)>>,
+}
+
+impl DiffSyntax {
+	fn new(
+		hash: u64,
+		path: &Path,
+		lines: &[(DiffLineType, String)],
+		syntax: &str,
+	) -> asyncgit::Result<Self> {
+		scope_time!("diff_syntax_highlighting");
+
+		let syntax_ref = syntax_for_path(path);
+		let highlighter = Highlighter::new(syntax_theme(syntax)?);
+
+		// deleted lines continue the old file, added ones the new file
+		let mut old = LineHighlighter::new(syntax_ref, &highlighter);
+		let mut new = LineHighlighter::new(syntax_ref, &highlighter);
+
+		let mut res = Vec::with_capacity(lines.len());
+		for (line_type, content) in lines {
+			res.push(match line_type {
+				DiffLineType::Header => {
+					old.reset();
+					new.reset();
+					Vec::new()
+				}
+				DiffLineType::None => {
+					old.highlight(content)?;
+					new.highlight(content)?
+				}
+				DiffLineType::Delete => old.highlight(content)?,
+				DiffLineType::Add => new.highlight(content)?,
+				DiffLineType::AddEOFNL
+				| DiffLineType::DeleteEOFNL => Vec::new(),
+			});
+		}
+
+		Ok(Self { hash, lines: res })
+	}
+
+	/// hash of the highlighted `FileDiff`
+	pub const fn hash(&self) -> u64 {
+		self.hash
+	}
+
+	/// styled byte ranges of a line, indexed over all hunks
+	pub fn line(
+		&self,
+		idx: usize,
+	) -> &[(ratatui::style::Style, Range<usize>)] {
+		self.lines.get(idx).map_or(&[], Vec::as_slice)
+	}
+}
+
 impl<'a> From<&'a SyntaxText> for ratatui::text::Text<'a> {
 	fn from(v: &'a SyntaxText) -> Self {
 		let mut result_lines: Vec<Line> =
@@ -292,3 +429,79 @@ impl AsyncJob for AsyncSyntaxJob {
 		))
 	}
 }
+
+enum DiffJobState {
+	Request {
+		hash: u64,
+		path: String,
+		lines: Vec<(DiffLineType, String)>,
+	},
+	Response(DiffSyntax),
+}
+
+#[derive(Clone, Default)]
+pub struct AsyncDiffSyntaxJob {
+	state: Arc<Mutex<Option<DiffJobState>>>,
+	syntax: String,
+}
+
+impl AsyncDiffSyntaxJob {
+	/// `lines` of all hunks of the diff with the given `hash`
+	pub fn new(
+		hash: u64,
+		path: String,
+		lines: Vec<(DiffLineType, String)>,
+		syntax: String,
+	) -> Self {
+		Self {
+			state: Arc::new(Mutex::new(Some(
+				DiffJobState::Request { hash, path, lines },
+			))),
+			syntax,
+		}
+	}
+
+	///
+	pub fn result(&self) -> Option<DiffSyntax> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					DiffJobState::Request { .. } => None,
+					DiffJobState::Response(syntax) => Some(syntax),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncDiffSyntaxJob {
+	type Notification = AsyncAppNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> asyncgit::Result<Self::Notification> {
+		let mut state_mutex = self.state.lock()?;
+
+		if let Some(state) = state_mutex.take() {
+			*state_mutex = Some(match state {
+				DiffJobState::Request { hash, path, lines } => {
+					DiffJobState::Response(DiffSyntax::new(
+						hash,
+						Path::new(&path),
+						&lines,
+						&self.syntax,
+					)?)
+				}
+				DiffJobState::Response(res) => {
+					DiffJobState::Response(res)
+				}
+			});
+		}
+
+		Ok(AsyncAppNotification::DiffHighlighting)
+	}
+}